@@ -1,3 +1,147 @@
-fn main() {
-    println!("Hello, world!");
+use clap::{Parser, Subcommand};
+use resume_ats_optimizer::resume::importer::ResumeImporter;
+use resume_ats_optimizer::resume::markdown_import::MarkdownImporter;
+use resume_ats_optimizer::resume::model::Resume;
+use resume_ats_optimizer::resume::tailor::ResumeTailor;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "resume-ats-optimizer", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Tailor a resume to a job description and write the result to a new file
+    Tailor {
+        /// Resume in JSON format
+        #[arg(long)]
+        resume: PathBuf,
+        /// Text file with the job description
+        #[arg(long)]
+        job: PathBuf,
+        /// Where to write the tailored resume as JSON
+        #[arg(short, long)]
+        output: PathBuf,
+        /// Also write the change summary to this file
+        #[arg(long)]
+        changes: Option<PathBuf>,
+    },
+    /// Import an existing plain-text, Markdown, PDF or Word resume into JSON
+    Import {
+        /// Resume to import, read as Markdown, PDF or Word by its .md, .pdf or
+        /// .docx extension and as plain text otherwise
+        #[arg(long)]
+        input: PathBuf,
+        /// Where to write the imported resume as JSON
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    match cli.command {
+        Command::Tailor {
+            resume,
+            job,
+            output,
+            changes,
+        } => tailor(&resume, &job, &output, changes.as_deref()),
+        Command::Import { input, output } => import(&input, &output),
+    }
+}
+
+fn tailor(
+    resume_path: &Path,
+    job_path: &Path,
+    output: &Path,
+    changes: Option<&Path>,
+) -> Result<(), String> {
+    if is_same_file(resume_path, output) {
+        return Err("The output file must differ from the input resume".to_string());
+    }
+
+    let resume = read_resume(resume_path)?;
+    let job_description = fs::read_to_string(job_path)
+        .map_err(|e| format!("Failed to read job description: {}", e))?;
+
+    let result = ResumeTailor::new().tailor(&resume, &job_description);
+
+    let json = serde_json::to_string_pretty(&result.resume)
+        .map_err(|e| format!("Failed to serialize resume: {}", e))?;
+    fs::write(output, json).map_err(|e| format!("Failed to write tailored resume: {}", e))?;
+
+    let summary = result.summary();
+    if let Some(path) = changes {
+        fs::write(path, &summary).map_err(|e| format!("Failed to write change summary: {}", e))?;
+    }
+    print!("{}", summary);
+    println!("Tailored resume written to {}", output.display());
+
+    Ok(())
+}
+
+fn import(input: &Path, output: &Path) -> Result<(), String> {
+    if is_same_file(input, output) {
+        return Err("The output file must differ from the input resume".to_string());
+    }
+
+    let extension = input.extension().and_then(|ext| ext.to_str());
+    let resume = if extension == Some("md") {
+        MarkdownImporter::new().import(&read_text(input)?)?
+    } else {
+        let importer = ResumeImporter::new();
+        let result = match extension {
+            Some("pdf") => importer.import_pdf(&read_bytes(input)?)?,
+            Some("docx") => importer.import_docx(&read_bytes(input)?)?,
+            _ => importer.import_text(&read_text(input)?),
+        };
+        print!("{}", result.report.summary());
+        result.resume
+    };
+
+    let json = serde_json::to_string_pretty(&resume)
+        .map_err(|e| format!("Failed to serialize resume: {}", e))?;
+    fs::write(output, json).map_err(|e| format!("Failed to write imported resume: {}", e))?;
+
+    println!("Imported resume written to {}", output.display());
+
+    Ok(())
+}
+
+/// Whether `output` names the existing `input` file, however either path
+/// is spelled, e.g. "resume.json" and "./resume.json".
+fn is_same_file(input: &Path, output: &Path) -> bool {
+    match (input.canonicalize(), output.canonicalize()) {
+        (Ok(input), Ok(output)) => input == output,
+        // A missing output can't overwrite the input
+        _ => false,
+    }
+}
+
+fn read_text(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Failed to read resume: {}", e))
+}
+
+fn read_bytes(path: &Path) -> Result<Vec<u8>, String> {
+    fs::read(path).map_err(|e| format!("Failed to read resume: {}", e))
+}
+
+fn read_resume(path: &Path) -> Result<Resume, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("Failed to read resume: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse resume: {}", e))
 }
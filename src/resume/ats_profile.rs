@@ -0,0 +1,353 @@
+use crate::resume::docx::DocxReader;
+use crate::resume::formatter::{FormattingOptions, FormattingResult, OutputFormat};
+use crate::resume::parser::SectionKind;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// What an applicant tracking system can and cannot read.
+///
+/// Empty lists mean "no restriction": a profile without `section_headings`
+/// accepts any heading, one without `date_formats` accepts any date format.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AtsProfile {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Content in page headers and footers is dropped.
+    #[serde(default)]
+    pub ignores_headers_footers: bool,
+    /// The only section headings that are recognized, compared ignoring case.
+    #[serde(default)]
+    pub section_headings: Vec<String>,
+    #[serde(default = "default_true")]
+    pub supports_tables: bool,
+    #[serde(default = "default_true")]
+    pub supports_multi_column: bool,
+    /// Accepted `chrono` format strings, e.g. "%m/%Y".
+    #[serde(default)]
+    pub date_formats: Vec<String>,
+    /// Templates known to parse correctly.
+    #[serde(default)]
+    pub templates: Vec<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+const STANDARD_HEADINGS: [&str; 12] = [
+    "Contact Information",
+    "Summary",
+    "Professional Summary",
+    "Experience",
+    "Work Experience",
+    "Education",
+    "Skills",
+    "Projects",
+    "Certifications",
+    "Languages",
+    "Publications",
+    "Volunteer Experience",
+];
+
+impl AtsProfile {
+    /// Profiles describing common classes of ATS, from strict legacy parsers
+    /// to lenient modern ones.
+    pub fn builtin() -> Vec<AtsProfile> {
+        let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect();
+
+        vec![
+            AtsProfile {
+                name: "legacy".to_string(),
+                description: "Older parsers that read text line by line".to_string(),
+                ignores_headers_footers: true,
+                section_headings: strings(&STANDARD_HEADINGS),
+                supports_tables: false,
+                supports_multi_column: false,
+                date_formats: strings(&["%m/%Y", "%B %Y", "%b %Y"]),
+                templates: strings(&["classic", "minimal"]),
+            },
+            AtsProfile {
+                name: "standard".to_string(),
+                description: "Most current parsers".to_string(),
+                ignores_headers_footers: true,
+                section_headings: vec![],
+                supports_tables: true,
+                supports_multi_column: false,
+                date_formats: strings(&["%m/%Y", "%B %Y", "%b %Y", "%Y-%m", "%m/%d/%Y"]),
+                templates: vec![],
+            },
+            AtsProfile {
+                name: "modern".to_string(),
+                description: "Layout-aware parsers".to_string(),
+                ignores_headers_footers: false,
+                section_headings: vec![],
+                supports_tables: true,
+                supports_multi_column: true,
+                date_formats: vec![],
+                templates: vec![],
+            },
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtsConstraint {
+    HeaderFooter,
+    SectionHeading,
+    Table,
+    MultiColumn,
+    DateFormat,
+    Template,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtsWarning {
+    pub profile: String,
+    pub constraint: AtsConstraint,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProfileFile {
+    #[serde(default, alias = "profiles")]
+    profile: Vec<AtsProfile>,
+}
+
+/// Evaluates rendered resumes against a set of ATS profiles.
+#[derive(Debug, Clone)]
+pub struct AtsChecker {
+    profiles: Vec<AtsProfile>,
+    header_footer_re: Regex,
+    html_heading_re: Regex,
+    tag_re: Regex,
+    table_re: Regex,
+    multi_column_re: Regex,
+}
+
+impl Default for AtsChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AtsChecker {
+    pub fn new() -> Self {
+        Self::with_profiles(AtsProfile::builtin())
+    }
+
+    pub fn with_profiles(profiles: Vec<AtsProfile>) -> Self {
+        Self {
+            profiles,
+            header_footer_re: Regex::new(r"(?is)<(header|footer)\b[^>]*>(.*?)</(?:header|footer)>")
+                .unwrap(),
+            // Headings run to the closing tag or, if it's missing, the end of the line
+            html_heading_re: Regex::new(r"(?i)<h([12])\b[^>]*>([^<\n]*)").unwrap(),
+            tag_re: Regex::new(r"<[^>]*>").unwrap(),
+            table_re: Regex::new(r"(?im)<table\b|^\s*\|?\s*:?-{3,}:?\s*\|").unwrap(),
+            multi_column_re: Regex::new(
+                r"(?i)column-count\s*:|columns\s*:|grid-template-columns\s*:|float\s*:\s*(left|right)",
+            )
+            .unwrap(),
+        }
+    }
+
+    pub fn from_toml_str(content: &str) -> Result<Self, String> {
+        let file: ProfileFile =
+            toml::from_str(content).map_err(|e| format!("Failed to parse ATS profiles: {}", e))?;
+        Ok(Self::with_profiles(file.profile))
+    }
+
+    pub fn from_yaml_str(content: &str) -> Result<Self, String> {
+        let file: ProfileFile = serde_yaml::from_str(content)
+            .map_err(|e| format!("Failed to parse ATS profiles: {}", e))?;
+        Ok(Self::with_profiles(file.profile))
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read ATS profiles file: {}", e))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml_str(&content),
+            Some("yaml") | Some("yml") => Self::from_yaml_str(&content),
+            _ => Err(format!(
+                "Unsupported ATS profiles file format: {}",
+                path.display()
+            )),
+        }
+    }
+
+    pub fn add_profile(&mut self, profile: AtsProfile) {
+        self.profiles.push(profile);
+    }
+
+    pub fn profiles(&self) -> &[AtsProfile] {
+        &self.profiles
+    }
+
+    /// Checks a rendered resume, and the options it was rendered with,
+    /// against every profile.
+    pub fn check(
+        &self,
+        rendered: &FormattingResult,
+        options: &FormattingOptions,
+    ) -> Vec<AtsWarning> {
+        self.profiles
+            .iter()
+            .flat_map(|profile| self.check_profile(profile, rendered, options))
+            .collect()
+    }
+
+    pub fn check_profile(
+        &self,
+        profile: &AtsProfile,
+        rendered: &FormattingResult,
+        options: &FormattingOptions,
+    ) -> Vec<AtsWarning> {
+        let mut warnings = vec![];
+        let mut warn = |constraint, message: String| {
+            warnings.push(AtsWarning {
+                profile: profile.name.clone(),
+                constraint,
+                message,
+            })
+        };
+        let content = &rendered.content;
+        let is_html = rendered.format == OutputFormat::HTML;
+
+        if profile.ignores_headers_footers && is_html {
+            for caps in self.header_footer_re.captures_iter(content) {
+                let text = self.tag_re.replace_all(&caps[2], " ");
+                if !text.trim().is_empty() {
+                    warn(
+                        AtsConstraint::HeaderFooter,
+                        format!(
+                            "{} ignores page headers and footers; move the content of <{}> into the body",
+                            profile.name, &caps[1]
+                        ),
+                    );
+                }
+            }
+        }
+
+        if !profile.section_headings.is_empty() {
+            for heading in self.section_headings(rendered) {
+                let recognized = profile
+                    .section_headings
+                    .iter()
+                    .any(|standard| standard.eq_ignore_ascii_case(&heading));
+                if !recognized {
+                    warn(
+                        AtsConstraint::SectionHeading,
+                        format!(
+                            "{} may not recognize the section '{}'; use a standard heading such as {}",
+                            profile.name,
+                            heading,
+                            profile.section_headings[..profile.section_headings.len().min(3)]
+                                .join(", ")
+                        ),
+                    );
+                }
+            }
+        }
+
+        if !profile.supports_tables && self.table_re.is_match(content) {
+            warn(
+                AtsConstraint::Table,
+                format!(
+                    "{} cannot read tables; list the content as plain lines instead",
+                    profile.name
+                ),
+            );
+        }
+
+        if !profile.supports_multi_column && is_html && self.multi_column_re.is_match(content) {
+            warn(
+                AtsConstraint::MultiColumn,
+                format!(
+                    "{} reads multi-column layouts across columns, mixing their content; use a single column",
+                    profile.name
+                ),
+            );
+        }
+
+        if !profile.date_formats.is_empty() && !profile.date_formats.contains(&options.date_format)
+        {
+            warn(
+                AtsConstraint::DateFormat,
+                format!(
+                    "{} may not understand dates formatted as '{}'; use one of {}",
+                    profile.name,
+                    options.date_format,
+                    profile.date_formats.join(", ")
+                ),
+            );
+        }
+
+        if !profile.templates.is_empty() && !profile.templates.contains(&options.template) {
+            warn(
+                AtsConstraint::Template,
+                format!(
+                    "The '{}' template is not known to parse well in {}; try {}",
+                    options.template,
+                    profile.name,
+                    profile.templates.join(" or ")
+                ),
+            );
+        }
+
+        warnings
+    }
+
+    /// Top-level section headings, for the formats that mark them up. The
+    /// professional title rendered right below the name is part of the
+    /// header, not a section, unless it reads as one.
+    fn section_headings(&self, rendered: &FormattingResult) -> Vec<String> {
+        // (level, text), where the name is level 0 and sections level 1
+        let headings: Vec<(usize, String)> = match rendered.format {
+            OutputFormat::Markdown => rendered
+                .content
+                .lines()
+                .filter_map(|line| {
+                    if let Some(heading) = line.strip_prefix("# ") {
+                        Some((0, heading))
+                    } else {
+                        line.strip_prefix("## ").map(|heading| (1, heading))
+                    }
+                })
+                .map(|(level, heading)| (level, heading.trim().to_string()))
+                .collect(),
+            OutputFormat::HTML => self
+                .html_heading_re
+                .captures_iter(&rendered.content)
+                .map(|caps| {
+                    let level = if &caps[1] == "1" { 0 } else { 1 };
+                    (level, caps[2].trim().to_string())
+                })
+                .collect(),
+            OutputFormat::DOCX => DocxReader::new()
+                .parse_document(&rendered.content)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|paragraph| match paragraph.heading_level() {
+                    Some(level @ (0 | 1)) => Some((level, paragraph.text)),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        let mut previous = None;
+        let mut sections = vec![];
+        for (level, heading) in headings {
+            let is_title = previous == Some(0) && SectionKind::from_heading(&heading).is_none();
+            if level == 1 && !is_title {
+                sections.push(heading);
+            }
+            previous = Some(level);
+        }
+        sections
+    }
+}
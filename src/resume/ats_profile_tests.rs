@@ -0,0 +1,164 @@
+use crate::resume::ats_profile::*;
+use crate::resume::formatter::{
+    FormattingOptions, FormattingResult, OutputFormat, ResumeFormatter,
+};
+use crate::resume::model::{Profile, Resume};
+use pretty_assertions::assert_eq;
+
+const TOML_PROFILES: &str = r#"
+[[profile]]
+name = "strict"
+ignores_headers_footers = true
+supports_tables = false
+supports_multi_column = false
+section_headings = ["Experience", "Education", "Skills"]
+date_formats = ["%m/%Y"]
+templates = ["classic"]
+
+[[profile]]
+name = "lenient"
+"#;
+
+fn rendered(content: &str, format: OutputFormat) -> FormattingResult {
+    FormattingResult {
+        content: content.to_string(),
+        format,
+        warnings: vec![],
+        bytes: None,
+        pages: None,
+    }
+}
+
+fn constraints(warnings: &[AtsWarning]) -> Vec<AtsConstraint> {
+    warnings.iter().map(|warning| warning.constraint).collect()
+}
+
+#[test]
+fn test_load_profiles_from_toml() {
+    let checker = AtsChecker::from_toml_str(TOML_PROFILES).unwrap();
+    let names: Vec<&str> = checker.profiles().iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["strict", "lenient"]);
+
+    let lenient = &checker.profiles()[1];
+    assert!(lenient.supports_tables);
+    assert!(lenient.supports_multi_column);
+    assert!(!lenient.ignores_headers_footers);
+}
+
+#[test]
+fn test_load_profiles_from_yaml() {
+    let yaml = "profiles:\n  - name: yaml-ats\n    supports_tables: false\n";
+    let checker = AtsChecker::from_yaml_str(yaml).unwrap();
+    assert_eq!(checker.profiles()[0].name, "yaml-ats");
+    assert!(!checker.profiles()[0].supports_tables);
+}
+
+#[test]
+fn test_invalid_profiles() {
+    assert!(
+        AtsChecker::from_toml_str("[[profile]]\nsupports_tables = true")
+            .unwrap_err()
+            .starts_with("Failed to parse ATS profiles")
+    );
+}
+
+#[test]
+fn test_html_layout_constraints() {
+    let checker = AtsChecker::from_toml_str(TOML_PROFILES).unwrap();
+    let html = r#"<html><head><style>.main { column-count: 2; }</style></head><body>
+        <header><p>jane@example.com</p></header>
+        <h2>Experience</h2>
+        <table><tr><td>Rust</td></tr></table>
+        <h2>Career Highlights</h2>
+        <footer></footer>
+    </body></html>"#;
+    let options = FormattingOptions {
+        template: "classic".to_string(),
+        date_format: "%m/%Y".to_string(),
+        ..FormattingOptions::default()
+    };
+
+    let warnings = checker.check(&rendered(html, OutputFormat::HTML), &options);
+    assert_eq!(
+        constraints(&warnings),
+        vec![
+            AtsConstraint::HeaderFooter,
+            AtsConstraint::SectionHeading,
+            AtsConstraint::Table,
+            AtsConstraint::MultiColumn,
+        ]
+    );
+    assert!(warnings.iter().all(|warning| warning.profile == "strict"));
+    assert!(warnings[0].message.contains("<header>"));
+    assert!(warnings[1].message.contains("'Career Highlights'"));
+}
+
+#[test]
+fn test_formatting_options_constraints() {
+    let checker = AtsChecker::from_toml_str(TOML_PROFILES).unwrap();
+    let warnings = checker.check(
+        &rendered(
+            "## Experience\n| Skill | Years |\n|---|---|\n",
+            OutputFormat::Markdown,
+        ),
+        &FormattingOptions::default(),
+    );
+
+    assert_eq!(
+        constraints(&warnings),
+        vec![
+            AtsConstraint::Table,
+            AtsConstraint::DateFormat,
+            AtsConstraint::Template,
+        ]
+    );
+    assert_eq!(
+        warnings[1].message,
+        "strict may not understand dates formatted as '%B %Y'; use one of %m/%Y"
+    );
+}
+
+#[test]
+fn test_builtin_profiles_on_generated_resume() {
+    let resume = Resume::new(Profile {
+        name: "Jane Smith".to_string(),
+        title: "Software Engineer".to_string(),
+        email: "jane@example.com".to_string(),
+        ..Profile::default()
+    });
+    let options = FormattingOptions::default();
+    let formatter = ResumeFormatter::with_options(options.clone());
+    let checker = AtsChecker::new();
+
+    let markdown = formatter.format(&resume, OutputFormat::Markdown).unwrap();
+    let warnings = checker.check(&markdown, &options);
+    // The profile title below the name isn't a section heading
+    assert_eq!(constraints(&warnings), vec![AtsConstraint::Template]);
+    assert!(warnings.iter().all(|w| w.profile == "legacy"));
+
+    for format in [OutputFormat::HTML, OutputFormat::DOCX] {
+        let rendered = formatter.format(&resume, format).unwrap();
+        assert_eq!(
+            constraints(&checker.check(&rendered, &options)),
+            constraints(&warnings)
+        );
+    }
+}
+
+#[test]
+fn test_unusual_heading_after_title_is_reported() {
+    let checker = AtsChecker::from_toml_str(TOML_PROFILES).unwrap();
+    let markdown = "# Jane Smith\n## Software Engineer\n\n## Career Highlights\n\n## Experience\n";
+    let warnings = checker.check(
+        &rendered(markdown, OutputFormat::Markdown),
+        &FormattingOptions::default(),
+    );
+
+    let headings: Vec<&str> = warnings
+        .iter()
+        .filter(|w| w.constraint == AtsConstraint::SectionHeading)
+        .map(|w| w.message.as_str())
+        .collect();
+    assert_eq!(headings.len(), 1);
+    assert!(headings[0].contains("'Career Highlights'"));
+}
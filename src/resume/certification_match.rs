@@ -0,0 +1,238 @@
+use crate::resume::model::{Certification, Resume};
+use chrono::NaiveDate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CertificationStatus {
+    Held,
+    Expired,
+    Missing,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CertificationRequirementMatch {
+    /// Canonical name of the certification asked for by the job description.
+    pub certification: String,
+    /// Name of the matching entry in `Resume::certifications`, if any.
+    pub matched: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub status: CertificationStatus,
+}
+
+#[derive(Debug, Clone)]
+struct CatalogEntry {
+    name: String,
+    aliases: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CertificationMatcher {
+    catalog: Vec<CatalogEntry>,
+}
+
+impl Default for CertificationMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CertificationMatcher {
+    pub fn new() -> Self {
+        let mut matcher = Self { catalog: vec![] };
+        matcher.load_default_certifications();
+        matcher
+    }
+
+    fn load_default_certifications(&mut self) {
+        let defaults: [(&str, &[&str]); 18] = [
+            (
+                "AWS Certified Solutions Architect",
+                &[
+                    "AWS Solutions Architect",
+                    "AWS SAA",
+                    "AWS Certified Solutions Architect - Associate",
+                    "AWS Certified Solutions Architect - Professional",
+                ],
+            ),
+            (
+                "AWS Certified Developer",
+                &[
+                    "AWS Developer Associate",
+                    "AWS Certified Developer - Associate",
+                ],
+            ),
+            (
+                "AWS Certified Cloud Practitioner",
+                &["AWS Cloud Practitioner"],
+            ),
+            (
+                "Google Professional Cloud Architect",
+                &[
+                    "GCP Professional Cloud Architect",
+                    "Professional Cloud Architect",
+                ],
+            ),
+            (
+                "Azure Solutions Architect Expert",
+                &["AZ-305", "Azure Solutions Architect"],
+            ),
+            (
+                "Azure Administrator Associate",
+                &["AZ-104", "Azure Administrator"],
+            ),
+            ("Certified Kubernetes Administrator", &["CKA"]),
+            ("Certified Kubernetes Application Developer", &["CKAD"]),
+            (
+                "CISSP",
+                &["Certified Information Systems Security Professional"],
+            ),
+            ("CISM", &["Certified Information Security Manager"]),
+            ("CompTIA Security+", &["Security+", "Security Plus"]),
+            ("OSCP", &["Offensive Security Certified Professional"]),
+            ("CCNA", &["Cisco Certified Network Associate"]),
+            ("PMP", &["Project Management Professional"]),
+            (
+                "Certified ScrumMaster",
+                &[
+                    "CSM",
+                    "Certified Scrum Master",
+                    "Scrum Master Certification",
+                ],
+            ),
+            ("CPA", &["Certified Public Accountant"]),
+            ("CFA", &["Chartered Financial Analyst"]),
+            ("ITIL Foundation", &["ITIL", "ITIL v4"]),
+        ];
+
+        for (name, aliases) in defaults {
+            self.add_certification(name, aliases);
+        }
+    }
+
+    /// Registers a certification to look for in job descriptions, or adds
+    /// aliases to one that is already known.
+    pub fn add_certification(&mut self, name: &str, aliases: &[&str]) {
+        let aliases = aliases.iter().map(|alias| alias.to_string());
+        match self
+            .catalog
+            .iter_mut()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+        {
+            Some(entry) => entry.aliases.extend(aliases),
+            None => self.catalog.push(CatalogEntry {
+                name: name.to_string(),
+                aliases: aliases.collect(),
+            }),
+        }
+    }
+
+    /// Canonical names of the known certifications mentioned in the job description.
+    pub fn extract_requirements(&self, job_description: &str) -> Vec<String> {
+        self.catalog
+            .iter()
+            .filter(|entry| Self::names(entry).any(|name| Self::mentions(job_description, name)))
+            .map(|entry| entry.name.clone())
+            .collect()
+    }
+
+    pub fn match_requirements(
+        &self,
+        resume: &Resume,
+        job_description: &str,
+        today: NaiveDate,
+    ) -> Vec<CertificationRequirementMatch> {
+        self.extract_requirements(job_description)
+            .into_iter()
+            .map(|name| {
+                let entry = self
+                    .catalog
+                    .iter()
+                    .find(|entry| entry.name == name)
+                    .unwrap();
+
+                let held: Vec<&Certification> = resume
+                    .certifications
+                    .iter()
+                    .filter(|cert| {
+                        Self::names(entry).any(|alias| Self::same_cert(&cert.name, alias))
+                    })
+                    .collect();
+
+                // Prefer a valid certification over an expired copy of the same one
+                let best = held
+                    .iter()
+                    .find(|cert| !Self::is_expired(cert, today))
+                    .or_else(|| held.first());
+
+                match best {
+                    Some(cert) => CertificationRequirementMatch {
+                        certification: name,
+                        matched: Some(cert.name.clone()),
+                        expiry_date: cert.expiry_date,
+                        status: if Self::is_expired(cert, today) {
+                            CertificationStatus::Expired
+                        } else {
+                            CertificationStatus::Held
+                        },
+                    },
+                    None => CertificationRequirementMatch {
+                        certification: name,
+                        matched: None,
+                        expiry_date: None,
+                        status: CertificationStatus::Missing,
+                    },
+                }
+            })
+            .collect()
+    }
+
+    fn names(entry: &CatalogEntry) -> impl Iterator<Item = &str> {
+        std::iter::once(entry.name.as_str()).chain(entry.aliases.iter().map(String::as_str))
+    }
+
+    fn mentions(text: &str, name: &str) -> bool {
+        // Acronyms must match case-sensitively so "csm" in a URL or "cpa" in a
+        // word doesn't count, longer names are matched case-insensitively
+        let is_acronym = name.len() <= 5 && name.chars().all(|c| !c.is_lowercase());
+        let (text, name) = if is_acronym {
+            (text.to_string(), name.to_string())
+        } else {
+            (text.to_lowercase(), name.to_lowercase())
+        };
+
+        let is_boundary =
+            |c: Option<char>| c.is_none_or(|c| !c.is_alphanumeric() && c != '_' && c != '+');
+        text.match_indices(&name).any(|(start, matched)| {
+            is_boundary(text[..start].chars().next_back())
+                && is_boundary(text[start + matched.len()..].chars().next())
+        })
+    }
+
+    fn same_cert(held: &str, name: &str) -> bool {
+        let normalize = |s: &str| {
+            s.to_lowercase()
+                .chars()
+                .filter(|c| c.is_alphanumeric() || *c == '+')
+                .collect::<String>()
+        };
+        let held = normalize(held);
+        let name = normalize(name);
+        // Only long names may match as a substring, so "CKA" doesn't satisfy "CKAD"
+        !held.is_empty() && (held == name || (name.len() >= 8 && held.contains(&name)))
+    }
+
+    fn is_expired(cert: &Certification, today: NaiveDate) -> bool {
+        cert.expiry_date.is_some_and(|expiry| expiry < today)
+    }
+}
+
+pub fn requirements_score(matches: &[CertificationRequirementMatch]) -> Option<u8> {
+    if matches.is_empty() {
+        return None;
+    }
+
+    let held = matches
+        .iter()
+        .filter(|m| m.status == CertificationStatus::Held)
+        .count();
+    Some(((held as f32 / matches.len() as f32) * 100.0).round() as u8)
+}
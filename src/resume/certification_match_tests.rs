@@ -0,0 +1,146 @@
+use crate::resume::certification_match::*;
+use crate::resume::model::{Certification, Resume};
+use crate::resume::optimizer::ResumeOptimizer;
+use crate::resume::test_support::{date, empty_resume};
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+
+fn certification(name: &str, expiry_date: Option<NaiveDate>) -> Certification {
+    Certification {
+        name: name.to_string(),
+        issuer: "Issuer".to_string(),
+        date_obtained: date(2019, 1),
+        expiry_date,
+        credential_id: Some("ID-1".to_string()),
+        url: None,
+    }
+}
+
+fn create_test_resume() -> Resume {
+    let mut resume = empty_resume();
+    resume.certifications.push(certification(
+        "AWS Certified Solutions Architect - Associate",
+        date(2030, 1),
+    ));
+    resume.certifications.push(certification(
+        "Certified Information Systems Security Professional",
+        date(2021, 6),
+    ));
+    resume.certifications.push(certification("CKA", None));
+    resume
+}
+
+#[test]
+fn test_extract_requirements() {
+    let matcher = CertificationMatcher::new();
+    let job = "Must hold an AWS Solutions Architect certification. CISSP or Security+ preferred.";
+
+    let requirements = matcher.extract_requirements(job);
+    assert_eq!(
+        requirements,
+        vec![
+            "AWS Certified Solutions Architect".to_string(),
+            "CISSP".to_string(),
+            "CompTIA Security+".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_acronyms_need_exact_case_and_boundaries() {
+    let matcher = CertificationMatcher::new();
+    assert!(matcher
+        .extract_requirements("visit https://example.com/cka and CKAD-ready teams")
+        .iter()
+        .all(|name| name != "Certified Kubernetes Administrator"));
+    assert_eq!(
+        matcher.extract_requirements("CKAD required"),
+        vec!["Certified Kubernetes Application Developer".to_string()]
+    );
+}
+
+#[test]
+fn test_match_held_expired_and_missing() {
+    let matcher = CertificationMatcher::new();
+    let resume = create_test_resume();
+    let job = "AWS Solutions Architect, CISSP, PMP and CKAD required.";
+
+    let matches = matcher.match_requirements(&resume, job, date(2024, 1).unwrap());
+    let statuses: Vec<(&str, CertificationStatus)> = matches
+        .iter()
+        .map(|m| (m.certification.as_str(), m.status))
+        .collect();
+
+    assert_eq!(
+        statuses,
+        vec![
+            (
+                "AWS Certified Solutions Architect",
+                CertificationStatus::Held
+            ),
+            (
+                "Certified Kubernetes Application Developer",
+                CertificationStatus::Missing
+            ),
+            ("CISSP", CertificationStatus::Expired),
+            ("PMP", CertificationStatus::Missing),
+        ]
+    );
+    assert_eq!(requirements_score(&matches), Some(25));
+}
+
+#[test]
+fn test_valid_copy_preferred_over_expired() {
+    let matcher = CertificationMatcher::new();
+    let mut resume = create_test_resume();
+    resume
+        .certifications
+        .push(certification("CISSP", date(2027, 6)));
+
+    let matches = matcher.match_requirements(&resume, "CISSP required", date(2024, 1).unwrap());
+    assert_eq!(matches[0].status, CertificationStatus::Held);
+    assert_eq!(matches[0].expiry_date, date(2027, 6));
+}
+
+#[test]
+fn test_custom_certification_aliases() {
+    let mut matcher = CertificationMatcher::new();
+    let resume = create_test_resume();
+    matcher.add_certification("Certified Kubernetes Administrator", &["Kubernetes Admin"]);
+
+    let matches = matcher.match_requirements(
+        &resume,
+        "Kubernetes Admin certification is a must",
+        date(2024, 1).unwrap(),
+    );
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].status, CertificationStatus::Held);
+    assert_eq!(matches[0].matched.as_deref(), Some("CKA"));
+}
+
+#[test]
+fn test_optimizer_reports_missing_and_expired_separately() {
+    let optimizer = ResumeOptimizer::new();
+    let resume = create_test_resume();
+
+    let result = optimizer.optimize(&resume, "CISSP and PMP certifications required.");
+
+    assert_eq!(result.expired_certifications, vec!["CISSP".to_string()]);
+    assert_eq!(result.missing_certifications, vec!["PMP".to_string()]);
+    assert_eq!(result.score_breakdown.get("certifications"), Some(&0));
+    assert!(result.suggestions.iter().any(|s| s.contains("renew")));
+}
+
+#[test]
+fn test_missing_certifications_lower_score() {
+    let optimizer = ResumeOptimizer::new();
+    let mut resume = create_test_resume();
+    resume.profile.summary = "Rust engineer running Kubernetes clusters.".to_string();
+    let job = "Rust engineer for Kubernetes clusters. Rust and Kubernetes experience.";
+
+    let without = optimizer.optimize(&resume, job);
+    let with = optimizer.optimize(&resume, &format!("{} PMP certification required.", job));
+
+    assert_eq!(with.score_breakdown.get("certifications"), Some(&0));
+    assert!(with.score < without.score);
+}
@@ -0,0 +1,323 @@
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use regex::Regex;
+use std::io::{Cursor, Read, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+const WORD_NAMESPACE: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+<Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/>
+<Override PartName="/word/numbering.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml"/>
+</Types>"#;
+
+const PACKAGE_RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>
+</Relationships>"#;
+
+const DOCUMENT_RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>
+<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/numbering" Target="numbering.xml"/>
+</Relationships>"#;
+
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:docDefaults><w:rPrDefault><w:rPr><w:rFonts w:ascii="Calibri" w:hAnsi="Calibri" w:cs="Calibri"/><w:sz w:val="22"/></w:rPr></w:rPrDefault><w:pPrDefault><w:pPr><w:spacing w:after="80"/></w:pPr></w:pPrDefault></w:docDefaults>
+<w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/><w:qFormat/></w:style>
+<w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:rPr><w:b/><w:sz w:val="40"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="heading 1"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:spacing w:before="240"/><w:outlineLvl w:val="0"/></w:pPr><w:rPr><w:b/><w:sz w:val="28"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading2"><w:name w:val="heading 2"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:spacing w:before="160"/><w:outlineLvl w:val="1"/></w:pPr><w:rPr><w:b/><w:sz w:val="24"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="ListBullet"><w:name w:val="List Bullet"/><w:basedOn w:val="Normal"/><w:qFormat/><w:pPr><w:numPr><w:numId w:val="1"/></w:numPr><w:spacing w:after="0"/></w:pPr></w:style>
+</w:styles>"#;
+
+const NUMBERING: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:abstractNum w:abstractNumId="0"><w:multiLevelType w:val="singleLevel"/><w:lvl w:ilvl="0"><w:start w:val="1"/><w:numFmt w:val="bullet"/><w:lvlText w:val="&#8226;"/><w:lvlJc w:val="left"/><w:pPr><w:ind w:left="360" w:hanging="360"/></w:pPr></w:lvl></w:abstractNum>
+<w:num w:numId="1"><w:abstractNumId w:val="0"/></w:num>
+</w:numbering>"#;
+
+/// A paragraph of a Word document, with the style it was written in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocxParagraph {
+    /// The paragraph style id, e.g. "Heading1".
+    pub style: Option<String>,
+    /// Whether the paragraph is an item of a bullet or numbered list.
+    pub list: bool,
+    pub text: String,
+}
+
+impl DocxParagraph {
+    /// Heading level for "Title" (0) and "HeadingN" (N) styles.
+    pub fn heading_level(&self) -> Option<usize> {
+        let style = self.style.as_deref()?;
+        if style == "Title" {
+            return Some(0);
+        }
+        style
+            .strip_prefix("Heading")
+            .and_then(|level| level.trim().parse().ok())
+    }
+}
+
+/// Writes resumes rendered as Markdown into single-column Word documents,
+/// using built-in heading styles and real bullet lists rather than
+/// tables or text boxes.
+pub struct DocxWriter {
+    link_re: Regex,
+}
+
+impl Default for DocxWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DocxWriter {
+    pub fn new() -> Self {
+        Self {
+            link_re: Regex::new(r"\[(.*?)\]\((.*?)\)").unwrap(),
+        }
+    }
+
+    /// Builds `word/document.xml` from Markdown: "#" becomes the Title
+    /// style, "##" and "###" Heading1 and Heading2, "-" items bullet lists.
+    pub fn document_xml(&self, markdown: &str) -> String {
+        let mut body = String::new();
+
+        for line in markdown.lines().map(str::trim_end) {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (style, text) = if let Some(text) = line.strip_prefix("### ") {
+                (Some("Heading2"), text)
+            } else if let Some(text) = line.strip_prefix("## ") {
+                (Some("Heading1"), text)
+            } else if let Some(text) = line.strip_prefix("# ") {
+                (Some("Title"), text)
+            } else if let Some(text) = line.strip_prefix("- ") {
+                (Some("ListBullet"), text)
+            } else {
+                (None, line)
+            };
+            let text = self.link_re.replace_all(text, "$1 ($2)");
+
+            body.push_str("<w:p>");
+            if let Some(style) = style {
+                body.push_str(&format!("<w:pPr><w:pStyle w:val=\"{}\"/>", style));
+                if style == "ListBullet" {
+                    body.push_str("<w:numPr><w:ilvl w:val=\"0\"/><w:numId w:val=\"1\"/></w:numPr>");
+                }
+                body.push_str("</w:pPr>");
+            }
+            body.push_str(&runs(&text));
+            body.push_str("</w:p>\n");
+        }
+
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+             <w:document xmlns:w=\"{}\">\n<w:body>\n{}<w:sectPr><w:pgSz w:w=\"12240\" w:h=\"15840\"/>\
+             <w:pgMar w:top=\"1080\" w:right=\"1080\" w:bottom=\"1080\" w:left=\"1080\" w:header=\"720\" w:footer=\"720\" w:gutter=\"0\"/>\
+             <w:cols w:space=\"720\"/></w:sectPr>\n</w:body>\n</w:document>",
+            WORD_NAMESPACE, body
+        )
+    }
+
+    /// Packages a document part with its styles and numbering into a .docx file.
+    pub fn package(&self, document_xml: &str) -> Result<Vec<u8>, String> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        let parts = [
+            ("[Content_Types].xml", CONTENT_TYPES),
+            ("_rels/.rels", PACKAGE_RELATIONSHIPS),
+            ("word/_rels/document.xml.rels", DOCUMENT_RELATIONSHIPS),
+            ("word/styles.xml", STYLES),
+            ("word/numbering.xml", NUMBERING),
+            ("word/document.xml", document_xml),
+        ];
+        for (name, content) in parts {
+            zip.start_file(name, options)
+                .and_then(|_| zip.write_all(content.as_bytes()).map_err(Into::into))
+                .map_err(|e| format!("Failed to write DOCX part {}: {}", name, e))?;
+        }
+
+        let cursor = zip
+            .finish()
+            .map_err(|e| format!("Failed to write DOCX: {}", e))?;
+        Ok(cursor.into_inner())
+    }
+}
+
+/// Splits a line on "**" into bold and regular runs; a line wrapped in
+/// underscores, like the date lines, becomes a single italic run.
+fn runs(text: &str) -> String {
+    if text.len() > 2 && text.starts_with('_') && text.ends_with('_') {
+        return run(&text[1..text.len() - 1], false, true);
+    }
+
+    text.split("**")
+        .enumerate()
+        .filter(|(_, part)| !part.is_empty())
+        .map(|(index, part)| run(part, index % 2 == 1, false))
+        .collect()
+}
+
+fn run(text: &str, bold: bool, italic: bool) -> String {
+    let mut properties = String::new();
+    if bold {
+        properties.push_str("<w:b/>");
+    }
+    if italic {
+        properties.push_str("<w:i/>");
+    }
+    let properties = if properties.is_empty() {
+        properties
+    } else {
+        format!("<w:rPr>{}</w:rPr>", properties)
+    };
+    format!(
+        "<w:r>{}<w:t xml:space=\"preserve\">{}</w:t></w:r>",
+        properties,
+        escape(text)
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Reads the paragraphs of Word documents.
+pub struct DocxReader;
+
+impl Default for DocxReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DocxReader {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn paragraphs(&self, bytes: &[u8]) -> Result<Vec<DocxParagraph>, String> {
+        let mut archive = ZipArchive::new(Cursor::new(bytes))
+            .map_err(|e| format!("Failed to read DOCX: {}", e))?;
+        let mut document = String::new();
+        archive
+            .by_name("word/document.xml")
+            .map_err(|e| format!("Failed to read DOCX: {}", e))?
+            .read_to_string(&mut document)
+            .map_err(|e| format!("Failed to read DOCX: {}", e))?;
+
+        self.parse_document(&document)
+    }
+
+    /// Reads the paragraphs of a `word/document.xml` part.
+    pub fn parse_document(&self, xml: &str) -> Result<Vec<DocxParagraph>, String> {
+        let mut reader = Reader::from_str(xml);
+        let mut paragraphs = vec![];
+        let mut current: Option<DocxParagraph> = None;
+        let mut in_text = false;
+        // Tab stops in paragraph properties are also "tab" elements
+        let mut in_properties = false;
+
+        loop {
+            let event = reader
+                .read_event()
+                .map_err(|e| format!("Failed to parse DOCX document: {}", e))?;
+            match event {
+                Event::Start(element) => match element.local_name().as_ref() {
+                    b"p" => {
+                        current = Some(DocxParagraph {
+                            style: None,
+                            list: false,
+                            text: String::new(),
+                        })
+                    }
+                    b"t" => in_text = true,
+                    b"pPr" => in_properties = true,
+                    _ => apply(&element, current.as_mut(), in_properties),
+                },
+                Event::Empty(element) => apply(&element, current.as_mut(), in_properties),
+                Event::Text(text) if in_text => {
+                    if let Some(paragraph) = current.as_mut() {
+                        let text = text
+                            .unescape()
+                            .map_err(|e| format!("Failed to parse DOCX document: {}", e))?;
+                        paragraph.text.push_str(&text);
+                    }
+                }
+                Event::End(element) => match element.local_name().as_ref() {
+                    b"p" => {
+                        if let Some(mut paragraph) = current.take() {
+                            paragraph.text = paragraph.text.trim().to_string();
+                            if paragraph.style.as_deref() == Some("ListBullet") {
+                                paragraph.list = true;
+                            }
+                            paragraphs.push(paragraph);
+                        }
+                    }
+                    b"t" => in_text = false,
+                    b"pPr" => in_properties = false,
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        Ok(paragraphs)
+    }
+
+    /// The document as plain text, one paragraph per line. Headings are
+    /// set apart by blank lines and list items start with "- ".
+    pub fn read_text(&self, bytes: &[u8]) -> Result<String, String> {
+        let mut text = String::new();
+        for paragraph in self.paragraphs(bytes)? {
+            if paragraph.text.is_empty() {
+                continue;
+            }
+            if paragraph.heading_level().is_some() && !text.is_empty() {
+                text.push('\n');
+            }
+            if paragraph.list {
+                text.push_str("- ");
+            }
+            text.push_str(&paragraph.text);
+            text.push('\n');
+        }
+        Ok(text)
+    }
+}
+
+/// Applies the paragraph properties and inline elements that matter for
+/// reading the text back.
+fn apply(element: &BytesStart, paragraph: Option<&mut DocxParagraph>, in_properties: bool) {
+    let Some(paragraph) = paragraph else {
+        return;
+    };
+    match element.local_name().as_ref() {
+        b"pStyle" => {
+            paragraph.style = element
+                .attributes()
+                .flatten()
+                .find(|attribute| attribute.key.local_name().as_ref() == b"val")
+                .map(|attribute| String::from_utf8_lossy(&attribute.value).to_string());
+        }
+        b"numPr" => paragraph.list = true,
+        b"tab" if !in_properties => paragraph.text.push('\t'),
+        b"br" if !in_properties => paragraph.text.push(' '),
+        _ => {}
+    }
+}
@@ -0,0 +1,176 @@
+use crate::resume::docx::{DocxParagraph, DocxReader, DocxWriter};
+use crate::resume::formatter::{OutputFormat, ResumeFormatter};
+use crate::resume::importer::ResumeImporter;
+use crate::resume::test_support::{date, sample_resume};
+use pretty_assertions::assert_eq;
+use std::io::{Cursor, Read};
+use zip::ZipArchive;
+
+fn paragraph(style: Option<&str>, list: bool, text: &str) -> DocxParagraph {
+    DocxParagraph {
+        style: style.map(str::to_string),
+        list,
+        text: text.to_string(),
+    }
+}
+
+fn part(bytes: &[u8], name: &str) -> String {
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut content = String::new();
+    archive
+        .by_name(name)
+        .unwrap()
+        .read_to_string(&mut content)
+        .unwrap();
+    content
+}
+
+#[test]
+fn test_format_docx_package() {
+    let result = ResumeFormatter::new()
+        .format(&sample_resume(), OutputFormat::DOCX)
+        .unwrap();
+    assert_eq!(result.format.extension(), "docx");
+    let bytes = result.bytes.unwrap();
+
+    let archive = ZipArchive::new(Cursor::new(bytes.as_slice())).unwrap();
+    let mut names: Vec<&str> = archive.file_names().collect();
+    names.sort();
+    assert_eq!(
+        names,
+        vec![
+            "[Content_Types].xml",
+            "_rels/.rels",
+            "word/_rels/document.xml.rels",
+            "word/document.xml",
+            "word/numbering.xml",
+            "word/styles.xml",
+        ]
+    );
+    assert_eq!(part(&bytes, "word/document.xml"), result.content);
+    let styles = part(&bytes, "word/styles.xml");
+    for style in ["Title", "Heading1", "Heading2", "ListBullet"] {
+        assert!(styles.contains(&format!("w:styleId=\"{}\"", style)));
+    }
+    assert!(part(&bytes, "word/numbering.xml").contains("<w:numFmt w:val=\"bullet\"/>"));
+}
+
+#[test]
+fn test_document_structure() {
+    let result = ResumeFormatter::new()
+        .format(&sample_resume(), OutputFormat::DOCX)
+        .unwrap();
+    let paragraphs = DocxReader::new().parse_document(&result.content).unwrap();
+
+    assert_eq!(paragraphs[0], paragraph(Some("Title"), false, "Jane Smith"));
+    let headings: Vec<&str> = paragraphs
+        .iter()
+        .filter(|p| p.heading_level() == Some(1))
+        .map(|p| p.text.as_str())
+        .collect();
+    assert_eq!(
+        headings,
+        vec![
+            "Software Engineer",
+            "Contact Information",
+            "Professional Summary",
+            "Work Experience",
+            "Education",
+            "Skills",
+        ]
+    );
+    assert!(paragraphs.contains(&paragraph(
+        Some("Heading2"),
+        false,
+        "Senior Engineer at Acme Corp"
+    )));
+    assert!(paragraphs.contains(&paragraph(
+        Some("ListBullet"),
+        true,
+        "Led the billing migration"
+    )));
+    assert!(paragraphs.contains(&paragraph(
+        None,
+        false,
+        "Builds payment systems & mentors engineers."
+    )));
+
+    // Single column, no tables or text boxes
+    assert!(!result.content.contains("<w:tbl"));
+    assert!(!result.content.contains("<w:txbxContent"));
+    assert!(!result.content.contains("w:num=\""));
+}
+
+#[test]
+fn test_inline_formatting() {
+    let xml = DocxWriter::new()
+        .document_xml("**Location:** Remote\n_March 2020 - Present_\n**GitHub:** [Repository](https://github.com/jane)\n");
+
+    assert!(xml.contains(
+        "<w:r><w:rPr><w:b/></w:rPr><w:t xml:space=\"preserve\">Location:</w:t></w:r>\
+         <w:r><w:t xml:space=\"preserve\"> Remote</w:t></w:r>"
+    ));
+    assert!(xml.contains(
+        "<w:r><w:rPr><w:i/></w:rPr><w:t xml:space=\"preserve\">March 2020 - Present</w:t></w:r>"
+    ));
+    assert!(xml.contains("Repository (https://github.com/jane)"));
+}
+
+#[test]
+fn test_read_styles_and_runs() {
+    let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+<w:p><w:pPr><w:pStyle w:val="Heading1"/><w:tabs><w:tab w:val="left" w:pos="720"/></w:tabs></w:pPr><w:r><w:t>Experience</w:t></w:r></w:p>
+<w:p><w:pPr><w:numPr><w:ilvl w:val="0"/><w:numId w:val="3"/></w:numPr></w:pPr><w:r><w:t>Shipped </w:t></w:r><w:r><w:rPr><w:b/></w:rPr><w:t>v2</w:t></w:r></w:p>
+<w:p><w:r><w:t>Acme</w:t><w:tab/><w:t>2019 - 2021</w:t></w:r></w:p>
+</w:body></w:document>"#;
+
+    assert_eq!(
+        DocxReader::new().parse_document(xml).unwrap(),
+        vec![
+            paragraph(Some("Heading1"), false, "Experience"),
+            paragraph(None, true, "Shipped v2"),
+            paragraph(None, false, "Acme\t2019 - 2021"),
+        ]
+    );
+}
+
+#[test]
+fn test_read_invalid_docx() {
+    assert!(DocxReader::new()
+        .read_text(b"not a zip file")
+        .unwrap_err()
+        .starts_with("Failed to read DOCX"));
+}
+
+#[test]
+fn test_import_docx_round_trip() {
+    let resume = sample_resume();
+    let bytes = ResumeFormatter::new()
+        .format(&resume, OutputFormat::DOCX)
+        .unwrap()
+        .bytes
+        .unwrap();
+
+    let result = ResumeImporter::new().import_docx(&bytes).unwrap();
+    let imported = result.resume;
+
+    assert_eq!(imported.profile.name, "Jane Smith");
+    assert_eq!(imported.profile.email, "jane@example.com");
+    assert_eq!(imported.profile.summary, resume.profile.summary);
+    let experience = &imported.experiences[0];
+    assert_eq!(
+        (experience.title.as_str(), experience.company.as_str()),
+        ("Senior Engineer", "Acme Corp")
+    );
+    assert_eq!(experience.start_date, date(2020, 3));
+    assert!(experience.current);
+    assert_eq!(experience.achievements, resume.experiences[0].achievements);
+    assert_eq!(imported.education[0].institution, "MIT");
+    let skills: Vec<&str> = imported
+        .skills
+        .technical
+        .iter()
+        .map(|skill| skill.name.as_str())
+        .collect();
+    assert_eq!(skills, vec!["Rust", "SQL"]);
+}
@@ -0,0 +1,447 @@
+use crate::resume::model::{Education, Resume};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const DEGREE_PATTERNS: [(DegreeLevel, &str); 4] = [
+    (
+        DegreeLevel::Associate,
+        r"(?i)\bassociate'?s?\s+degree\b|\bassociate of\b|\bA\.A\.S?\b",
+    ),
+    (
+        DegreeLevel::Bachelor,
+        r"(?i)\bbachelor'?s?\b|\bundergraduate degree\b|\bB\.?Sc?\b\.?|\bB\.?A\b\.?|\bB\.?Eng\b|\bB\.?Tech\b",
+    ),
+    (
+        DegreeLevel::Master,
+        r"(?i)\bmaster'?s?\b|\bM\.?Sc?\b\.?|\bM\.?A\b\.?|\bMBA\b|\bM\.?Eng\b|\bM\.?Tech\b|\bgraduate degree\b",
+    ),
+    (
+        DegreeLevel::Doctorate,
+        r"(?i)\bph\.?\s?d\b\.?|\bdoctorate\b|\bdoctoral\b",
+    ),
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DegreeLevel {
+    Associate,
+    Bachelor,
+    Master,
+    Doctorate,
+}
+
+impl DegreeLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Associate => "Associate's degree",
+            Self::Bachelor => "Bachelor's degree",
+            Self::Master => "Master's degree",
+            Self::Doctorate => "Doctorate",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DegreeRequirement {
+    pub level: DegreeLevel,
+    pub fields: Vec<String>,
+    pub accepts_related: bool,
+    pub preferred: bool,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldMatch {
+    NotRequired,
+    Exact,
+    Related,
+    Unrelated,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EducationRequirementMatch {
+    pub requirement: DegreeRequirement,
+    /// The education entry that best satisfies the requirement, formatted as
+    /// "degree, institution".
+    pub best_match: Option<String>,
+    pub candidate_level: Option<DegreeLevel>,
+    pub level_met: bool,
+    pub field_match: FieldMatch,
+    pub met: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct EducationMatcher {
+    related_fields: HashMap<String, Vec<String>>,
+    degree_res: Vec<(DegreeLevel, Regex)>,
+    sentence_re: Regex,
+    preferred_re: Regex,
+    context_re: Regex,
+    field_re: Regex,
+    related_re: Regex,
+    phd_re: Regex,
+    abbreviation_re: Regex,
+    field_separator_re: Regex,
+    degree_joiner_re: Regex,
+    clause_separator_re: Regex,
+}
+
+impl Default for EducationMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EducationMatcher {
+    pub fn new() -> Self {
+        let mut matcher = Self {
+            related_fields: HashMap::new(),
+            degree_res: DEGREE_PATTERNS
+                .iter()
+                .map(|(level, pattern)| (*level, Regex::new(pattern).unwrap()))
+                .collect(),
+            sentence_re: Regex::new(r"[^.\n;•!?]+").unwrap(),
+            preferred_re: Regex::new(
+                r"(?i)\b(preferred|a plus|nice to have|bonus|ideally|desirable)\b",
+            )
+            .unwrap(),
+            // Abbreviations like "MS" or "BA" only count next to degree wording, so
+            // "MS Office" or "Boston, MA" aren't read as degrees
+            context_re: Regex::new(
+                r"(?i)\b(?:degree|bachelor'?s?|master'?s?|ph\.?\s?d|doctorate|doctoral|mba)\b|\b[BM]\.?(?:Sc?|A|Eng|Tech)\b\.?\s*(?:/|in\b)|/\s*[BM]\.?(?:Sc?|A|Eng|Tech)\b",
+            )
+            .unwrap(),
+            field_re: Regex::new(r"(?i)\bin\s+([A-Za-z][A-Za-z ,/&-]+)").unwrap(),
+            related_re: Regex::new(
+                r"(?i)\b(?:or\s+)?(?:a\s+)?(?:related|equivalent|similar)(?:\s+(?:technical\s+)?(?:field|discipline|area|degree)s?)?",
+            )
+            .unwrap(),
+            phd_re: Regex::new(r"(?i)\bph\.\s?d\b\.?").unwrap(),
+            abbreviation_re: Regex::new(r"(?i)\b([a-z])\.([a-z])\.(?:([a-z])\.)?").unwrap(),
+            field_separator_re: Regex::new(r"(?i),|/|\bor\b|\band\b").unwrap(),
+            // What may stand between alternative degrees, as in "BS/MS" or
+            // "Bachelor's or Master's"
+            degree_joiner_re: Regex::new(r"(?i)^[\s,/]*(?:(?:or|and)\b[\s,/]*)?(?:a\s+)?$")
+                .unwrap(),
+            clause_separator_re: Regex::new(r"(?i),|\b(?:and|but|while|with)\b").unwrap(),
+        };
+        matcher.load_default_related_fields();
+        matcher
+    }
+
+    fn load_default_related_fields(&mut self) {
+        self.add_related_fields(
+            "computer science",
+            &[
+                "software engineering",
+                "computer engineering",
+                "information technology",
+                "information systems",
+                "electrical engineering",
+                "mathematics",
+                "data science",
+                "statistics",
+                "physics",
+            ],
+        );
+        self.add_related_fields(
+            "data science",
+            &[
+                "statistics",
+                "mathematics",
+                "computer science",
+                "applied mathematics",
+                "economics",
+                "physics",
+            ],
+        );
+        self.add_related_fields(
+            "electrical engineering",
+            &["computer engineering", "physics", "mechanical engineering"],
+        );
+        self.add_related_fields(
+            "business administration",
+            &[
+                "economics",
+                "finance",
+                "management",
+                "marketing",
+                "accounting",
+            ],
+        );
+    }
+
+    /// Finds every degree level mentioned in `text`, e.g. "BS/MS" yields both
+    /// `Bachelor` and `Master`.
+    pub fn degree_levels(&self, text: &str) -> Vec<DegreeLevel> {
+        self.degree_res
+            .iter()
+            .filter(|(_, re)| re.is_match(text))
+            .map(|(level, _)| *level)
+            .collect()
+    }
+
+    /// The highest degree level mentioned in `text`.
+    pub fn degree_level(&self, text: &str) -> Option<DegreeLevel> {
+        self.degree_levels(text).into_iter().max()
+    }
+
+    /// Registers `related` as acceptable substitutes for `field`. The relation is
+    /// symmetric.
+    pub fn add_related_fields(&mut self, field: &str, related: &[&str]) {
+        let field = field.to_lowercase();
+        for other in related {
+            let other = other.to_lowercase();
+            let entry = self.related_fields.entry(field.clone()).or_default();
+            if !entry.contains(&other) {
+                entry.push(other.clone());
+            }
+            let entry = self.related_fields.entry(other).or_default();
+            if !entry.contains(&field) {
+                entry.push(field.clone());
+            }
+        }
+    }
+
+    pub fn is_related(&self, field: &str, other: &str) -> bool {
+        let field = field.to_lowercase();
+        let other = other.to_lowercase();
+        self.related_fields
+            .get(&field)
+            .map(|related| related.iter().any(|r| other.contains(r.as_str())))
+            .unwrap_or(false)
+    }
+
+    /// Each group of alternative degrees in a sentence ("BS/MS in ...")
+    /// becomes one requirement, read from its own clause: in "BS in Computer
+    /// Science or related field, MS preferred" only the master's is
+    /// preferred.
+    pub fn extract_requirements(&self, job_description: &str) -> Vec<DegreeRequirement> {
+        // Collapse dotted abbreviations ("B.S.", "Ph.D.") so they don't end a sentence
+        let text = self.phd_re.replace_all(job_description, "PhD");
+        let text = self.abbreviation_re.replace_all(&text, "$1$2$3");
+
+        let mut requirements: Vec<DegreeRequirement> = Vec::new();
+
+        for sentence in self.sentence_re.find_iter(&text) {
+            let sentence = sentence.as_str();
+            if !self.context_re.is_match(sentence) {
+                continue;
+            }
+
+            for (index, (clause, levels)) in self.degree_clauses(sentence).into_iter().enumerate() {
+                // Later mentions need their own degree wording or a preference,
+                // so "BS in Physics, MS Office" has no master's requirement
+                if index > 0
+                    && !self.context_re.is_match(clause)
+                    && !self.preferred_re.is_match(clause)
+                {
+                    continue;
+                }
+                let Some(level) = levels.into_iter().min() else {
+                    continue;
+                };
+
+                let requirement = DegreeRequirement {
+                    level,
+                    fields: self.fields(clause),
+                    accepts_related: self.related_re.is_match(clause),
+                    preferred: self.preferred_re.is_match(clause),
+                    text: clause.trim().to_string(),
+                };
+
+                if !requirements
+                    .iter()
+                    .any(|r| r.level == requirement.level && r.preferred == requirement.preferred)
+                {
+                    requirements.push(requirement);
+                }
+            }
+        }
+
+        requirements
+    }
+
+    /// Splits a sentence into one clause per group of alternative degrees,
+    /// with the levels the group names. Clauses break at the last comma or
+    /// conjunction between two groups, so a leading "ideally" stays with the
+    /// degree it qualifies.
+    fn degree_clauses<'a>(&self, sentence: &'a str) -> Vec<(&'a str, Vec<DegreeLevel>)> {
+        let mut mentions: Vec<(usize, usize, DegreeLevel)> = self
+            .degree_res
+            .iter()
+            .flat_map(|(level, re)| re.find_iter(sentence).map(|m| (m.start(), m.end(), *level)))
+            .collect();
+        mentions.sort();
+
+        let mut groups: Vec<(usize, usize, Vec<DegreeLevel>)> = Vec::new();
+        for (start, end, level) in mentions {
+            match groups.last_mut() {
+                Some(group) if start < group.1 => {
+                    group.1 = group.1.max(end);
+                    group.2.push(level);
+                }
+                Some(group) if self.degree_joiner_re.is_match(&sentence[group.1..start]) => {
+                    group.1 = end;
+                    group.2.push(level);
+                }
+                _ => groups.push((start, end, vec![level])),
+            }
+        }
+
+        let mut clauses = Vec::new();
+        let mut clause_start = 0;
+        for (index, (_, end, levels)) in groups.iter().enumerate() {
+            let (clause_end, next_start) = match groups.get(index + 1) {
+                Some((next, _, _)) => match self
+                    .clause_separator_re
+                    .find_iter(&sentence[*end..*next])
+                    .last()
+                {
+                    Some(separator) => (end + separator.start(), end + separator.end()),
+                    None => (*next, *next),
+                },
+                None => (sentence.len(), sentence.len()),
+            };
+            clauses.push((&sentence[clause_start..clause_end], levels.clone()));
+            clause_start = next_start;
+        }
+        clauses
+    }
+
+    /// The fields of study a clause asks for, e.g. "in Computer Science or
+    /// Mathematics".
+    fn fields(&self, clause: &str) -> Vec<String> {
+        self.field_re
+            .captures(clause)
+            .map(|caps| {
+                let list = self.related_re.replace_all(&caps[1], "");
+                self.field_separator_re
+                    .split(&list)
+                    .map(|field| field.trim().to_string())
+                    .filter(|field| {
+                        !field.is_empty()
+                            && !self.degree_res.iter().any(|(_, re)| re.is_match(field))
+                            && !["a", "an", "the", "field"].contains(&field.to_lowercase().as_str())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn match_requirements(
+        &self,
+        resume: &Resume,
+        job_description: &str,
+    ) -> Vec<EducationRequirementMatch> {
+        self.extract_requirements(job_description)
+            .into_iter()
+            .map(|requirement| self.match_requirement(&resume.education, requirement))
+            .collect()
+    }
+
+    fn match_requirement(
+        &self,
+        education: &[Education],
+        requirement: DegreeRequirement,
+    ) -> EducationRequirementMatch {
+        let mut best: Option<EducationRequirementMatch> = None;
+
+        for edu in education {
+            let candidate_level = self.degree_level(&edu.degree);
+            let level_met = candidate_level.is_some_and(|level| level >= requirement.level);
+            let field_match = self.field_match(edu, &requirement);
+            let met = level_met
+                && match field_match {
+                    FieldMatch::NotRequired | FieldMatch::Exact => true,
+                    FieldMatch::Related => requirement.accepts_related,
+                    FieldMatch::Unrelated => false,
+                };
+
+            let candidate = EducationRequirementMatch {
+                requirement: requirement.clone(),
+                best_match: Some(format!("{}, {}", edu.degree, edu.institution)),
+                candidate_level,
+                level_met,
+                field_match,
+                met,
+            };
+
+            let rank = |m: &EducationRequirementMatch| {
+                (
+                    m.met,
+                    m.level_met,
+                    matches!(m.field_match, FieldMatch::Exact | FieldMatch::NotRequired),
+                    m.field_match == FieldMatch::Related,
+                    m.candidate_level,
+                )
+            };
+            if best.as_ref().is_none_or(|b| rank(&candidate) > rank(b)) {
+                best = Some(candidate);
+            }
+        }
+
+        best.unwrap_or(EducationRequirementMatch {
+            requirement,
+            best_match: None,
+            candidate_level: None,
+            level_met: false,
+            field_match: FieldMatch::Unrelated,
+            met: false,
+        })
+    }
+
+    fn field_match(&self, edu: &Education, requirement: &DegreeRequirement) -> FieldMatch {
+        if requirement.fields.is_empty() {
+            return FieldMatch::NotRequired;
+        }
+
+        let studied = format!("{} {}", edu.field_of_study, edu.degree).to_lowercase();
+        let field_of_study = edu.field_of_study.to_lowercase();
+
+        let exact = requirement.fields.iter().any(|field| {
+            let field = field.to_lowercase();
+            studied.contains(&field)
+                || (!field_of_study.is_empty() && field.contains(&field_of_study))
+        });
+        if exact {
+            return FieldMatch::Exact;
+        }
+
+        if requirement
+            .fields
+            .iter()
+            .any(|field| self.is_related(field, &studied))
+        {
+            FieldMatch::Related
+        } else {
+            FieldMatch::Unrelated
+        }
+    }
+}
+
+/// Score out of 100 over the required (not merely preferred) degrees.
+pub fn requirements_score(matches: &[EducationRequirementMatch]) -> Option<u8> {
+    let required: Vec<&EducationRequirementMatch> = matches
+        .iter()
+        .filter(|m| !m.requirement.preferred)
+        .collect();
+    if required.is_empty() {
+        return None;
+    }
+
+    let total: u32 = required
+        .iter()
+        .map(|m| {
+            if m.met {
+                100
+            } else if m.level_met {
+                50
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    Some((total / required.len() as u32) as u8)
+}
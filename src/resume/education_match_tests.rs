@@ -0,0 +1,162 @@
+use crate::resume::education_match::*;
+use crate::resume::model::Resume;
+use crate::resume::optimizer::ResumeOptimizer;
+use crate::resume::test_support::{education, empty_resume};
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+fn create_test_resume(degree: &str, field: &str) -> Resume {
+    let mut resume = empty_resume();
+    resume
+        .education
+        .push(education("State University", degree, field));
+    resume
+}
+
+#[rstest]
+#[case("Bachelor of Science", Some(DegreeLevel::Bachelor))]
+#[case("B.S.", Some(DegreeLevel::Bachelor))]
+#[case("BA", Some(DegreeLevel::Bachelor))]
+#[case("Master's", Some(DegreeLevel::Master))]
+#[case("MBA", Some(DegreeLevel::Master))]
+#[case("Ph.D.", Some(DegreeLevel::Doctorate))]
+#[case("Associate's degree", Some(DegreeLevel::Associate))]
+#[case("High School Diploma", None)]
+fn test_parse_degree_level(#[case] degree: &str, #[case] expected: Option<DegreeLevel>) {
+    assert_eq!(EducationMatcher::new().degree_level(degree), expected);
+}
+
+#[test]
+fn test_degree_level_ordering() {
+    assert!(DegreeLevel::Associate < DegreeLevel::Bachelor);
+    assert!(DegreeLevel::Bachelor < DegreeLevel::Master);
+    assert!(DegreeLevel::Master < DegreeLevel::Doctorate);
+}
+
+#[test]
+fn test_extract_requirements() {
+    let matcher = EducationMatcher::new();
+    let job = "Requirements:\n- B.S. in Computer Science or related field.\n- Master's preferred.\n- Proficient with MS Office";
+    let requirements = matcher.extract_requirements(job);
+
+    assert_eq!(requirements.len(), 2);
+    assert_eq!(requirements[0].level, DegreeLevel::Bachelor);
+    assert_eq!(requirements[0].fields, vec!["Computer Science".to_string()]);
+    assert!(requirements[0].accepts_related);
+    assert!(!requirements[0].preferred);
+
+    assert_eq!(requirements[1].level, DegreeLevel::Master);
+    assert!(requirements[1].fields.is_empty());
+    assert!(requirements[1].preferred);
+}
+
+#[test]
+fn test_preferred_applies_to_its_own_degree() {
+    let matcher = EducationMatcher::new();
+    let requirements =
+        matcher.extract_requirements("BS in Computer Science or related field, MS preferred.");
+
+    assert_eq!(requirements.len(), 2);
+    assert_eq!(requirements[0].level, DegreeLevel::Bachelor);
+    assert_eq!(requirements[0].fields, vec!["Computer Science".to_string()]);
+    assert!(requirements[0].accepts_related);
+    assert!(!requirements[0].preferred);
+
+    assert_eq!(requirements[1].level, DegreeLevel::Master);
+    assert!(requirements[1].fields.is_empty());
+    assert!(requirements[1].preferred);
+}
+
+#[rstest]
+#[case("Bachelor's degree required, ideally a Master's in Statistics", vec![(DegreeLevel::Bachelor, false), (DegreeLevel::Master, true)])]
+#[case("Bachelor's or Master's degree preferred", vec![(DegreeLevel::Bachelor, true)])]
+#[case("BS in Physics, MS Office skills", vec![(DegreeLevel::Bachelor, false)])]
+fn test_degree_clauses(#[case] job: &str, #[case] expected: Vec<(DegreeLevel, bool)>) {
+    let requirements = EducationMatcher::new().extract_requirements(job);
+    let levels: Vec<(DegreeLevel, bool)> = requirements
+        .iter()
+        .map(|r| (r.level, r.preferred))
+        .collect();
+    assert_eq!(levels, expected);
+}
+
+#[test]
+fn test_extract_multiple_fields_uses_lowest_level() {
+    let matcher = EducationMatcher::new();
+    let requirements =
+        matcher.extract_requirements("BS/MS in Computer Science, Mathematics, or Statistics");
+
+    assert_eq!(requirements.len(), 1);
+    assert_eq!(requirements[0].level, DegreeLevel::Bachelor);
+    assert_eq!(
+        requirements[0].fields,
+        vec!["Computer Science", "Mathematics", "Statistics"]
+    );
+    assert!(!requirements[0].accepts_related);
+}
+
+#[test]
+fn test_match_exact_field() {
+    let matcher = EducationMatcher::new();
+    let resume = create_test_resume("Master of Science", "Computer Science");
+    let matches = matcher.match_requirements(&resume, "Bachelor's degree in Computer Science.");
+
+    assert_eq!(matches.len(), 1);
+    assert!(matches[0].met);
+    assert_eq!(matches[0].field_match, FieldMatch::Exact);
+    assert_eq!(matches[0].candidate_level, Some(DegreeLevel::Master));
+}
+
+#[test]
+fn test_match_related_field() {
+    let matcher = EducationMatcher::new();
+    let resume = create_test_resume("Bachelor of Science", "Software Engineering");
+
+    let matches = matcher.match_requirements(
+        &resume,
+        "Bachelor's degree in Computer Science or related field.",
+    );
+    assert!(matches[0].met);
+    assert_eq!(matches[0].field_match, FieldMatch::Related);
+
+    let matches = matcher.match_requirements(&resume, "Bachelor's degree in Computer Science.");
+    assert!(!matches[0].met);
+    assert!(matches[0].level_met);
+}
+
+#[test]
+fn test_custom_related_fields() {
+    let mut matcher = EducationMatcher::new();
+    let resume = create_test_resume("Bachelor of Arts", "Linguistics");
+    let job = "Bachelor's degree in Computer Science or a related field.";
+
+    assert!(!matcher.match_requirements(&resume, job)[0].met);
+    matcher.add_related_fields("computer science", &["linguistics"]);
+    assert!(matcher.match_requirements(&resume, job)[0].met);
+}
+
+#[test]
+fn test_insufficient_level() {
+    let matcher = EducationMatcher::new();
+    let resume = create_test_resume("Bachelor of Science", "Physics");
+    let matches = matcher.match_requirements(&resume, "PhD in Physics required.");
+
+    assert!(!matches[0].met);
+    assert!(!matches[0].level_met);
+    assert_eq!(requirements_score(&matches), Some(0));
+}
+
+#[test]
+fn test_optimizer_reports_education_requirements() {
+    let optimizer = ResumeOptimizer::new();
+    let resume = create_test_resume("Associate of Science", "Computer Science");
+
+    let result = optimizer.optimize(&resume, "Bachelor's degree in Computer Science required.");
+
+    assert_eq!(result.education_requirements.len(), 1);
+    assert_eq!(result.score_breakdown.get("education"), Some(&0));
+    assert!(result
+        .suggestions
+        .iter()
+        .any(|s| s.contains("requires a Bachelor's degree in Computer Science")));
+}
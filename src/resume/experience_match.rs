@@ -0,0 +1,316 @@
+use crate::resume::model::Resume;
+use chrono::NaiveDate;
+use regex::Regex;
+
+/// A "N+ years" style requirement found in a job description. `skill` is `None`
+/// for requirements on overall professional experience.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExperienceRequirement {
+    pub skill: Option<String>,
+    pub min_years: u32,
+    pub max_years: Option<u32>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExperienceRequirementMatch {
+    pub requirement: ExperienceRequirement,
+    pub candidate_years: f32,
+    pub met: bool,
+}
+
+const QUALIFIERS: [&str; 8] = [
+    "professional",
+    "hands-on",
+    "relevant",
+    "industry",
+    "commercial",
+    "practical",
+    "proven",
+    "solid",
+];
+
+const PREPOSITIONS: [&str; 4] = ["with", "in", "using", "on"];
+
+const NON_SKILLS: [&str; 14] = [
+    "experience",
+    "exp",
+    "working",
+    "work",
+    "the",
+    "a",
+    "an",
+    "and",
+    "or",
+    "as",
+    "at",
+    "software",
+    "total",
+    "overall",
+];
+
+/// Words that end a multi-word skill name.
+const SKILL_STOPS: [&str; 20] = [
+    "experience",
+    "exp",
+    "and",
+    "or",
+    "with",
+    "in",
+    "using",
+    "on",
+    "at",
+    "as",
+    "for",
+    "to",
+    "is",
+    "are",
+    "required",
+    "preferred",
+    "plus",
+    "development",
+    "programming",
+    "skills",
+];
+
+const MAX_SKILL_WORDS: usize = 3;
+
+/// Finds "N years" requirements in job descriptions and compares them with
+/// the resume's experience.
+#[derive(Debug, Clone)]
+pub struct ExperienceMatcher {
+    years_re: Regex,
+    token_re: Regex,
+    word_re: Regex,
+}
+
+impl Default for ExperienceMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExperienceMatcher {
+    pub fn new() -> Self {
+        Self {
+            years_re: Regex::new(
+                r"(?i)\b(\d{1,2})\s*(?:\+|plus)?\s*(?:(?:-|–|to)\s*(\d{1,2})\s*\+?\s*)?(?:years?|yrs?)\b",
+            )
+            .unwrap(),
+            token_re: Regex::new(r"^[\s,]*([A-Za-z](?:[\w+#./-]*[\w+#])?)").unwrap(),
+            word_re: Regex::new(r"^[ \t]+([A-Za-z](?:[\w+#./-]*[\w+#])?)").unwrap(),
+        }
+    }
+
+    pub fn extract_requirements(&self, job_description: &str) -> Vec<ExperienceRequirement> {
+        let mut requirements: Vec<ExperienceRequirement> = Vec::new();
+
+        for caps in self.years_re.captures_iter(job_description) {
+            let whole = caps.get(0).unwrap();
+            let min_years: u32 = caps[1].parse().unwrap_or(0);
+            let max_years: Option<u32> = caps.get(2).and_then(|m| m.as_str().parse().ok());
+
+            // Walk the words after "N years" to find what the years apply to:
+            // "of Rust", "of professional experience", "experience with Go", ...
+            let mut rest = &job_description[whole.end()..];
+            let mut skill = None;
+            let mut seen_of = false;
+            let mut seen_experience = false;
+            let mut seen_preposition = false;
+            while let Some(token) = self.token_re.captures(rest) {
+                let word = token.get(1).unwrap().as_str();
+                let lower = word.to_lowercase();
+                rest = &rest[token.get(0).unwrap().end()..];
+
+                if lower == "of" {
+                    seen_of = true;
+                    continue;
+                }
+                if QUALIFIERS.contains(&lower.as_str()) {
+                    continue;
+                }
+                if lower == "experience" || lower == "exp" {
+                    seen_experience = true;
+                    continue;
+                }
+                if PREPOSITIONS.contains(&lower.as_str()) {
+                    seen_preposition = true;
+                    continue;
+                }
+                // "5 years experience building ..." does not name a skill
+                if (!seen_experience || seen_preposition) && !NON_SKILLS.contains(&lower.as_str()) {
+                    let (name, next) = self.skill_name(word, rest);
+                    skill = Some(name);
+                    seen_experience |= matches!(next.as_deref(), Some("experience" | "exp"));
+                }
+                break;
+            }
+
+            // "founded 25 years ago" and "in 2 years we ..." aren't requirements
+            if !(seen_of || seen_experience || seen_preposition) {
+                continue;
+            }
+
+            let requirement = ExperienceRequirement {
+                skill,
+                min_years,
+                max_years,
+                text: whole.as_str().to_string(),
+            };
+
+            if !requirements.iter().any(|existing| {
+                existing.skill.as_deref().map(str::to_lowercase)
+                    == requirement.skill.as_deref().map(str::to_lowercase)
+                    && existing.min_years == requirement.min_years
+            }) {
+                requirements.push(requirement);
+            }
+        }
+
+        requirements
+    }
+
+    /// The skill starting at `first`: it runs over the following words up
+    /// to punctuation or a word that can't be part of it, as in "machine
+    /// learning" or "Amazon Web Services". Also returns that word, lowercased.
+    fn skill_name(&self, first: &str, mut rest: &str) -> (String, Option<String>) {
+        let capitalized = |word: &str| word.starts_with(|c: char| c.is_uppercase());
+        let mut name = first.to_string();
+        for _ in 1..MAX_SKILL_WORDS {
+            let Some(token) = self.word_re.captures(rest) else {
+                return (name, None);
+            };
+            let word = token.get(1).unwrap().as_str();
+            let lower = word.to_lowercase();
+            // "Java development" names Java; "machine learning" is one skill
+            if SKILL_STOPS.contains(&lower.as_str()) || capitalized(first) && !capitalized(word) {
+                return (name, Some(lower));
+            }
+            name.push(' ');
+            name.push_str(word);
+            rest = &rest[token.get(0).unwrap().end()..];
+        }
+        (name, None)
+    }
+
+    pub fn match_requirements(
+        &self,
+        resume: &Resume,
+        job_description: &str,
+        today: NaiveDate,
+    ) -> Vec<ExperienceRequirementMatch> {
+        self.extract_requirements(job_description)
+            .into_iter()
+            .map(|requirement| {
+                let candidate_years = match &requirement.skill {
+                    Some(skill) => skill_experience_years(resume, skill, today),
+                    None => total_experience_years(resume, today),
+                };
+                ExperienceRequirementMatch {
+                    met: candidate_years >= requirement.min_years as f32,
+                    candidate_years,
+                    requirement,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Total professional experience in years, counting overlapping roles once.
+pub fn total_experience_years(resume: &Resume, today: NaiveDate) -> f32 {
+    let ranges = resume
+        .experiences
+        .iter()
+        .filter_map(|exp| date_range(exp.start_date, exp.end_date, exp.current, today))
+        .collect();
+    merged_years(ranges)
+}
+
+/// Years of experience with a skill: the larger of the roles that list it and
+/// any `Skill::years` the candidate declared.
+pub fn skill_experience_years(resume: &Resume, skill: &str, today: NaiveDate) -> f32 {
+    let skill = skill.to_lowercase();
+
+    let ranges = resume
+        .experiences
+        .iter()
+        .filter(|exp| {
+            exp.technologies
+                .iter()
+                .any(|tech| tech.to_lowercase() == skill)
+                || exp.title.to_lowercase().contains(&skill)
+        })
+        .filter_map(|exp| date_range(exp.start_date, exp.end_date, exp.current, today))
+        .collect();
+    let from_roles = merged_years(ranges);
+
+    let declared = resume
+        .skills
+        .technical
+        .iter()
+        .chain(resume.skills.tools.iter())
+        .chain(resume.skills.languages.iter())
+        .chain(resume.skills.soft.iter())
+        .chain(resume.skills.other.iter())
+        .filter(|s| s.name.to_lowercase() == skill)
+        .filter_map(|s| s.years)
+        .max()
+        .unwrap_or(0) as f32;
+
+    from_roles.max(declared)
+}
+
+/// Score out of 100 with partial credit for requirements that are close to met.
+pub fn requirements_score(matches: &[ExperienceRequirementMatch]) -> Option<u8> {
+    if matches.is_empty() {
+        return None;
+    }
+
+    let total: f32 = matches
+        .iter()
+        .map(|m| {
+            if m.met || m.requirement.min_years == 0 {
+                1.0
+            } else {
+                m.candidate_years / m.requirement.min_years as f32
+            }
+        })
+        .sum();
+
+    Some(((total / matches.len() as f32) * 100.0).round() as u8)
+}
+
+fn date_range(
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    current: bool,
+    today: NaiveDate,
+) -> Option<(NaiveDate, NaiveDate)> {
+    let start = start?;
+    let end = if current { today } else { end.unwrap_or(today) };
+    (end > start).then_some((start, end))
+}
+
+fn merged_years(mut ranges: Vec<(NaiveDate, NaiveDate)>) -> f32 {
+    ranges.sort();
+
+    let mut days = 0;
+    let mut current: Option<(NaiveDate, NaiveDate)> = None;
+    for (start, end) in ranges {
+        match current {
+            Some((cur_start, cur_end)) if start <= cur_end => {
+                current = Some((cur_start, cur_end.max(end)));
+            }
+            Some((cur_start, cur_end)) => {
+                days += (cur_end - cur_start).num_days();
+                current = Some((start, end));
+            }
+            None => current = Some((start, end)),
+        }
+    }
+    if let Some((start, end)) = current {
+        days += (end - start).num_days();
+    }
+
+    days as f32 / 365.25
+}
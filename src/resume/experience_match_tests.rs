@@ -0,0 +1,153 @@
+use crate::resume::experience_match::*;
+use crate::resume::model::{Experience, Resume, Skill};
+use crate::resume::optimizer::ResumeOptimizer;
+use crate::resume::test_support::{date, empty_resume, experience, skill, strings};
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+fn role(
+    title: &str,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    technologies: &[&str],
+) -> Experience {
+    Experience {
+        start_date: start,
+        end_date: end,
+        current: end.is_none(),
+        technologies: strings(technologies),
+        ..experience(title, "Company")
+    }
+}
+
+fn create_test_resume() -> Resume {
+    let mut resume = empty_resume();
+
+    resume.experiences.push(role(
+        "Backend Engineer",
+        date(2020, 1),
+        None,
+        &["Rust", "Kubernetes"],
+    ));
+    resume
+        .experiences
+        .push(role("Developer", date(2016, 1), date(2020, 1), &["Python"]));
+    // Overlaps with the role above and shouldn't be double counted
+    resume.experiences.push(role(
+        "Contractor",
+        date(2018, 1),
+        date(2019, 1),
+        &["Python"],
+    ));
+
+    resume.skills.technical.push(Skill {
+        years: Some(2),
+        ..skill("Go")
+    });
+
+    resume
+}
+
+#[rstest]
+#[case("5+ years of Rust", Some("Rust"), 5, None)]
+#[case("3-5 years experience", None, 3, Some(5))]
+#[case(
+    "At least 4 years of professional experience with Python.",
+    Some("Python"),
+    4,
+    None
+)]
+#[case("2 yrs experience in Node.js", Some("Node.js"), 2, None)]
+#[case("7 years experience building distributed systems", None, 7, None)]
+#[case("5 to 8 years of industry experience", None, 5, Some(8))]
+#[case("5 years of machine learning", Some("machine learning"), 5, None)]
+#[case(
+    "3+ years of Amazon Web Services.",
+    Some("Amazon Web Services"),
+    3,
+    None
+)]
+#[case("6 years of Java development", Some("Java"), 6, None)]
+#[case("4+ years Rust experience", Some("Rust"), 4, None)]
+#[case("3 years in a fast-paced team", None, 3, None)]
+fn test_extract_requirement(
+    #[case] text: &str,
+    #[case] skill: Option<&str>,
+    #[case] min_years: u32,
+    #[case] max_years: Option<u32>,
+) {
+    let requirements = ExperienceMatcher::new().extract_requirements(text);
+    assert_eq!(requirements.len(), 1);
+    assert_eq!(requirements[0].skill.as_deref(), skill);
+    assert_eq!(requirements[0].min_years, min_years);
+    assert_eq!(requirements[0].max_years, max_years);
+}
+
+#[test]
+fn test_extract_multiple_requirements() {
+    let job =
+        "We need 5+ years of experience and 3+ years of Rust. 5+ years of experience preferred.";
+    let requirements = ExperienceMatcher::new().extract_requirements(job);
+    assert_eq!(requirements.len(), 2);
+    assert_eq!(requirements[0].skill, None);
+    assert_eq!(requirements[1].skill.as_deref(), Some("Rust"));
+}
+
+#[test]
+fn test_years_without_requirement_context_are_ignored() {
+    let job = "Founded 25 years ago, we have grown tenfold. In 2 years we plan to double again.";
+    assert_eq!(ExperienceMatcher::new().extract_requirements(job), vec![]);
+}
+
+#[test]
+fn test_total_experience_merges_overlaps() {
+    let resume = create_test_resume();
+    let years = total_experience_years(&resume, date(2024, 1).unwrap());
+    assert!((years - 8.0).abs() < 0.05, "got {}", years);
+}
+
+#[test]
+fn test_skill_experience_years() {
+    let resume = create_test_resume();
+    let today = date(2024, 1).unwrap();
+
+    assert!((skill_experience_years(&resume, "rust", today) - 4.0).abs() < 0.05);
+    assert!((skill_experience_years(&resume, "Python", today) - 4.0).abs() < 0.05);
+    // Declared on the skill only
+    assert_eq!(skill_experience_years(&resume, "Go", today), 2.0);
+    assert_eq!(skill_experience_years(&resume, "Java", today), 0.0);
+}
+
+#[test]
+fn test_match_requirements() {
+    let resume = create_test_resume();
+    let job = "5+ years of experience, 5+ years of Rust and 2 years of Go.";
+    let matches = ExperienceMatcher::new().match_requirements(&resume, job, date(2024, 1).unwrap());
+
+    assert_eq!(matches.len(), 3);
+    assert!(matches[0].met);
+    assert!(!matches[1].met);
+    assert!(matches[2].met);
+
+    let score = requirements_score(&matches).unwrap();
+    assert!(score > 80 && score < 100, "got {}", score);
+    assert_eq!(requirements_score(&[]), None);
+}
+
+#[test]
+fn test_optimizer_reports_experience_requirements() {
+    let optimizer = ResumeOptimizer::new();
+    let resume = create_test_resume();
+
+    let result = optimizer.optimize(&resume, "Rust developer with 15+ years of Rust.");
+
+    assert_eq!(result.experience_requirements.len(), 1);
+    assert!(!result.experience_requirements[0].met);
+    assert!(result.score_breakdown.contains_key("experience"));
+    assert!(result
+        .suggestions
+        .iter()
+        .any(|s| s.contains("15+ years of Rust")));
+    assert!(result.score < result.score_breakdown["keywords"]);
+}
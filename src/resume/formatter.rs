@@ -1,4 +1,12 @@
-use crate::resume::model::Resume;
+use crate::resume::docx::DocxWriter;
+use crate::resume::html_theme::{self, ColorScheme, HtmlTheme, StyleOptions};
+use crate::resume::latex::{self, LatexStyle};
+use crate::resume::locale::Locale;
+use crate::resume::model::{Certification, Resume};
+use crate::resume::page_fit::{self, PageMetrics};
+use crate::resume::structured_data::{self, escape_html, iso_month};
+use crate::resume::tailor::RelevanceScorer;
+use crate::resume::typst::{self, TypstTemplate};
 use chrono::NaiveDate;
 use std::collections::HashMap;
 use std::path::Path;
@@ -10,6 +18,9 @@ pub enum OutputFormat {
     JSON,
     HTML,
     PDF,
+    DOCX,
+    LaTeX,
+    Typst,
 }
 
 impl OutputFormat {
@@ -20,17 +31,77 @@ impl OutputFormat {
             Self::JSON => "json",
             Self::HTML => "html",
             Self::PDF => "pdf",
+            Self::DOCX => "docx",
+            Self::LaTeX => "tex",
+            Self::Typst => "typ",
         }
     }
 }
 
+const BUILTIN_TEMPLATES: [(&str, &str); 3] = [
+    ("modern", include_str!("../templates/modern.md")),
+    ("classic", include_str!("../templates/classic.md")),
+    ("minimal", include_str!("../templates/minimal.md")),
+];
+
+const DEFAULT_SECTION_ORDER: [&str; 8] = [
+    "experiences",
+    "education",
+    "skills",
+    "projects",
+    "certifications",
+    "languages",
+    "publications",
+    "volunteer",
+];
+
+const DEFAULT_HEADINGS: [(&str, &str); 10] = [
+    ("contact", "Contact Information"),
+    ("summary", "Professional Summary"),
+    ("experiences", "Work Experience"),
+    ("education", "Education"),
+    ("skills", "Skills"),
+    ("projects", "Projects"),
+    ("certifications", "Certifications"),
+    ("languages", "Languages"),
+    ("publications", "Publications"),
+    ("volunteer", "Volunteer Experience"),
+];
+
+/// Sections hidden to meet `max_pages`, least important first.
+const OPTIONAL_SECTIONS: [&str; 5] = [
+    "volunteer",
+    "publications",
+    "languages",
+    "certifications",
+    "projects",
+];
+
 #[derive(Debug, Clone)]
 pub struct FormattingOptions {
     pub template: String,
     pub include_contact_info: bool,
     pub include_picture: bool,
+    /// Chrono format for dates; `%B` and `%b` use the locale's month names.
     pub date_format: String,
+    /// Language of headings, labels and month names, e.g. "de". Resume
+    /// text is translated into it where the resume has translations. See
+    /// `Locale::builtin` for the supported locales.
+    pub locale: String,
     pub section_options: HashMap<String, bool>,
+    /// Order of the sections after the header and summary.
+    pub section_order: Vec<String>,
+    /// Heading text by section name, replacing the default heading, e.g.
+    /// "experiences" -> "Professional Experience". "contact" and "summary"
+    /// can be renamed too.
+    pub section_headings: HashMap<String, String>,
+    /// Longest allowed printed length. Course lists, less relevant or older
+    /// achievements and then optional sections are trimmed until the
+    /// estimate fits, and each removal is reported as a warning.
+    pub max_pages: Option<usize>,
+    /// Job description that achievements are weighed against when
+    /// trimming to `max_pages`. Without one only their age counts.
+    pub job_description: Option<String>,
     pub custom_options: HashMap<String, String>,
 }
 
@@ -51,22 +122,50 @@ impl Default for FormattingOptions {
             include_contact_info: true,
             include_picture: false,
             date_format: "%B %Y".to_string(),
+            locale: "en".to_string(),
             section_options,
+            section_order: DEFAULT_SECTION_ORDER
+                .iter()
+                .map(|section| section.to_string())
+                .collect(),
+            section_headings: HashMap::new(),
+            max_pages: None,
+            job_description: None,
             custom_options: HashMap::new(),
         }
     }
 }
 
+impl FormattingOptions {
+    /// Default options in the given locale, with its usual date format.
+    pub fn for_locale(code: &str) -> Result<Self, String> {
+        let locale =
+            Locale::find(code).ok_or_else(|| format!("Locale '{}' not supported", code))?;
+        Ok(Self {
+            date_format: locale.date_format.to_string(),
+            locale: locale.code.to_string(),
+            ..Self::default()
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct FormattingResult {
     pub content: String,
     pub format: OutputFormat,
     pub warnings: Vec<String>,
+    /// The packaged file for binary formats; `content` then holds its main
+    /// text part, e.g. `word/document.xml` for DOCX.
+    pub bytes: Option<Vec<u8>>,
+    /// Estimated printed length; `None` for JSON, which has no pages.
+    pub pages: Option<f32>,
 }
 
 pub struct ResumeFormatter {
     templates: HashMap<String, String>,
     options: FormattingOptions,
+    /// Section order of the variant being rendered, if it has one.
+    section_order: Vec<String>,
 }
 
 impl Default for ResumeFormatter {
@@ -80,6 +179,7 @@ impl ResumeFormatter {
         let mut formatter = Self {
             templates: HashMap::new(),
             options: FormattingOptions::default(),
+            section_order: vec![],
         };
 
         formatter.load_default_templates();
@@ -90,6 +190,7 @@ impl ResumeFormatter {
         let mut formatter = Self {
             templates: HashMap::new(),
             options,
+            section_order: vec![],
         };
 
         formatter.load_default_templates();
@@ -105,20 +206,23 @@ impl ResumeFormatter {
     }
 
     fn load_default_templates(&mut self) {
-        self.templates.insert(
-            "modern".to_string(),
-            include_str!("../templates/modern.md").to_string(),
-        );
-
-        self.templates.insert(
-            "classic".to_string(),
-            include_str!("../templates/classic.md").to_string(),
-        );
+        for (name, template) in BUILTIN_TEMPLATES {
+            self.templates
+                .insert(name.to_string(), template.to_string());
+        }
+    }
 
-        self.templates.insert(
-            "minimal".to_string(),
-            include_str!("../templates/minimal.md").to_string(),
-        );
+    /// Whether the selected template is one loaded from a file rather than a
+    /// built-in one, whose layout `format_markdown` renders.
+    fn uses_loaded_template(&self) -> bool {
+        let builtin = BUILTIN_TEMPLATES
+            .iter()
+            .find(|(name, _)| *name == self.options.template)
+            .map(|(_, template)| *template);
+        self.templates
+            .get(&self.options.template)
+            .map(String::as_str)
+            != builtin
     }
 
     pub fn format(
@@ -132,33 +236,201 @@ impl ResumeFormatter {
             return Err(format!("Template '{}' not found", self.options.template));
         }
 
-        let content = match format {
-            OutputFormat::Markdown => self.format_markdown(resume, &mut warnings)?,
-            OutputFormat::PlainText => self.format_plaintext(resume, &mut warnings)?,
-            OutputFormat::JSON => self.format_json(resume, &mut warnings)?,
-            OutputFormat::HTML => self.format_html(resume, &mut warnings)?,
+        // Markdown, plain text and DOCX are laid out by `format_markdown`,
+        // which can't apply a template loaded from a file yet
+        if matches!(
+            format,
+            OutputFormat::Markdown | OutputFormat::PlainText | OutputFormat::DOCX
+        ) && self.uses_loaded_template()
+        {
+            return Err(format!(
+                "Template '{}' was loaded from a file, which {:?} output doesn't support yet",
+                self.options.template, format
+            ));
+        }
+
+        if Locale::find(&self.options.locale).is_none() {
+            return Err(format!("Locale '{}' not supported", self.options.locale));
+        }
+        let translated = resume.translate(&self.options.locale);
+        let resume = match self.options.max_pages {
+            Some(max_pages) => &self.fit_to_pages(translated, format, max_pages, &mut warnings)?,
+            None => &translated,
+        };
+
+        let (content, bytes) = match format {
+            OutputFormat::Markdown => (self.format_markdown(resume, &mut warnings)?, None),
+            OutputFormat::PlainText => (self.format_plaintext(resume, &mut warnings)?, None),
+            OutputFormat::JSON => (self.format_json(resume, &mut warnings)?, None),
+            OutputFormat::HTML => (self.format_html(resume, &mut warnings)?, None),
             OutputFormat::PDF => return Err("PDF output requires additional setup".to_string()),
+            OutputFormat::DOCX => {
+                let (document, package) = self.format_docx(resume, &mut warnings)?;
+                (document, Some(package))
+            }
+            OutputFormat::LaTeX => (self.format_latex(resume, &mut warnings)?, None),
+            OutputFormat::Typst => (self.format_typst(resume, &mut warnings)?, None),
         };
 
         Ok(FormattingResult {
             content,
             format,
             warnings,
+            bytes,
+            pages: self.estimate_pages(resume, format)?,
         })
     }
 
+    /// Text capacity of a page in `format`, following the LaTeX style,
+    /// Typst template or HTML theme that would be used and the `page_size`
+    /// and `margin` custom options.
+    fn page_metrics(&self, format: OutputFormat) -> Option<PageMetrics> {
+        let custom = &self.options.custom_options;
+        match format {
+            OutputFormat::JSON => None,
+            OutputFormat::PlainText => Some(PageMetrics::PLAIN_TEXT),
+            OutputFormat::LaTeX => {
+                let name = custom.get("latex_style").unwrap_or(&self.options.template);
+                Some(
+                    LatexStyle::find(name)
+                        .unwrap_or(&LatexStyle::builtin()[0])
+                        .page,
+                )
+            }
+            OutputFormat::Typst => {
+                let template = TypstTemplate::find(&self.options.template)
+                    .or_else(|| TypstTemplate::find("classic"))?;
+                Some(self.page_setup(template.page, template.margin, template.margin))
+            }
+            OutputFormat::HTML => {
+                let theme = custom.get("html_theme").unwrap_or(&self.options.template);
+                let theme = HtmlTheme::find(theme).unwrap_or(&HtmlTheme::builtin()[0]);
+                // The document metrics assume 1in margins
+                Some(self.page_setup(PageMetrics::DOCUMENT, "1in", theme.margin))
+            }
+            _ => Some(PageMetrics::DOCUMENT),
+        }
+    }
+
+    /// `metrics`, measured on US Letter with `measured_margin`, for the
+    /// paper and margin set by the `page_size` and `margin` custom options,
+    /// or else US Letter and `default_margin`. Invalid values are reported
+    /// when formatting, so they keep the measured metrics here.
+    fn page_setup(
+        &self,
+        metrics: PageMetrics,
+        measured_margin: &str,
+        default_margin: &str,
+    ) -> PageMetrics {
+        let custom = &self.options.custom_options;
+        let paper = match custom.get("page_size") {
+            Some(name) => page_fit::paper_size(name),
+            None => Some(page_fit::LETTER),
+        };
+        let margin = custom
+            .get("margin")
+            .map(String::as_str)
+            .unwrap_or(default_margin);
+        match (
+            page_fit::inches(measured_margin),
+            paper,
+            page_fit::inches(margin),
+        ) {
+            (Some(measured), Some(paper), Some(margin)) => metrics.on_page(measured, paper, margin),
+            _ => metrics,
+        }
+    }
+
+    /// Estimates the printed length of `resume` in `format` from its
+    /// Markdown rendering.
+    fn estimate_pages(&self, resume: &Resume, format: OutputFormat) -> Result<Option<f32>, String> {
+        let Some(metrics) = self.page_metrics(format) else {
+            return Ok(None);
+        };
+        let markdown = self.format_markdown(resume, &mut Vec::new())?;
+        Ok(Some(metrics.estimate(&markdown)))
+    }
+
+    /// Trims `resume` one piece at a time until it fits in `max_pages`,
+    /// warning about each removal and about anything still too long.
+    fn fit_to_pages(
+        &self,
+        mut resume: Resume,
+        format: OutputFormat,
+        max_pages: usize,
+        warnings: &mut Vec<String>,
+    ) -> Result<Resume, String> {
+        if format == OutputFormat::JSON {
+            warnings.push("JSON output has no pages, ignoring the page limit".to_string());
+            return Ok(resume);
+        }
+
+        let optional_sections: Vec<(&str, &str)> = OPTIONAL_SECTIONS
+            .iter()
+            .filter(|section| self.section_enabled(section))
+            .map(|section| (*section, self.heading(section)))
+            .collect();
+
+        let scorer = self
+            .options
+            .job_description
+            .as_deref()
+            .map(RelevanceScorer::for_job);
+        let relevance = |text: &str| scorer.as_ref().map_or(0, |scorer| scorer.score(text));
+
+        while let Some(pages) = self.estimate_pages(&resume, format)? {
+            if pages <= max_pages as f32 {
+                break;
+            }
+            match page_fit::trim_once(&mut resume, &optional_sections, relevance) {
+                Some(removed) => warnings.push(removed),
+                None => {
+                    warnings.push(format!(
+                        "Page limit: still about {:.1} pages after trimming, over the {}-page limit",
+                        pages, max_pages
+                    ));
+                    break;
+                }
+            }
+        }
+        Ok(resume)
+    }
+
+    /// Formats the named variant from `resume.metadata.variants`, using the
+    /// variant's section order when it has one.
+    pub fn format_variant(
+        &self,
+        resume: &Resume,
+        variant: &str,
+        format: OutputFormat,
+    ) -> Result<FormattingResult, String> {
+        let definition = resume
+            .metadata
+            .variants
+            .get(variant)
+            .ok_or_else(|| format!("Variant '{}' not found", variant))?;
+        // Translate first so achievement tags follow translated bullets
+        let selected = resume
+            .translate(&self.options.locale)
+            .apply_variant(definition);
+
+        if definition.section_order.is_empty() {
+            return self.format(&selected, format);
+        }
+
+        let formatter = Self {
+            templates: self.templates.clone(),
+            options: self.options.clone(),
+            section_order: definition.section_order.clone(),
+        };
+        formatter.format(&selected, format)
+    }
+
     fn format_markdown(
         &self,
         resume: &Resume,
         warnings: &mut Vec<String>,
     ) -> Result<String, String> {
-        let _template = self
-            .templates
-            .get(&self.options.template)
-            .ok_or_else(|| format!("Template '{}' not found", self.options.template))?;
-
-        // hmm template engine goes here
-
         let mut content = String::new();
 
         content.push_str(&format!("# {}\n", resume.profile.name));
@@ -168,14 +440,23 @@ impl ResumeFormatter {
         }
 
         if self.options.include_contact_info {
-            content.push_str("## Contact Information\n\n");
+            let locale = self.locale();
+            content.push_str(&format!("## {}\n\n", self.heading("contact")));
 
             if !resume.profile.email.is_empty() {
-                content.push_str(&format!("- Email: {}\n", resume.profile.email));
+                content.push_str(&format!(
+                    "- {}: {}\n",
+                    locale.text("Email"),
+                    resume.profile.email
+                ));
             }
 
             if !resume.profile.phone.is_empty() {
-                content.push_str(&format!("- Phone: {}\n", resume.profile.phone));
+                content.push_str(&format!(
+                    "- {}: {}\n",
+                    locale.text("Phone"),
+                    resume.profile.phone
+                ));
             }
 
             if !resume.profile.location.city.is_empty()
@@ -194,233 +475,435 @@ impl ResumeFormatter {
                     (true, true) => String::new(),
                 };
                 if !location.is_empty() {
-                    content.push_str(&format!("- Location: {}\n", location));
+                    content.push_str(&format!("- {}: {}\n", locale.text("Location"), location));
                 }
             }
 
             if !resume.profile.linkedin.is_empty() {
-                content.push_str(&format!("- LinkedIn: {}\n", resume.profile.linkedin));
+                content.push_str(&format!(
+                    "- {}: {}\n",
+                    locale.text("LinkedIn"),
+                    resume.profile.linkedin
+                ));
             }
 
             if !resume.profile.github.is_empty() {
-                content.push_str(&format!("- GitHub: {}\n", resume.profile.github));
+                content.push_str(&format!(
+                    "- {}: {}\n",
+                    locale.text("GitHub"),
+                    resume.profile.github
+                ));
             }
 
             if !resume.profile.website.is_empty() {
-                content.push_str(&format!("- Website: {}\n", resume.profile.website));
+                content.push_str(&format!(
+                    "- {}: {}\n",
+                    locale.text("Website"),
+                    resume.profile.website
+                ));
             }
 
             content.push('\n');
         }
 
         if !resume.profile.summary.is_empty() {
-            content.push_str("## Professional Summary\n\n");
+            content.push_str(&format!("## {}\n\n", self.heading("summary")));
             content.push_str(&resume.profile.summary);
             content.push_str("\n\n");
         }
 
-        if self
-            .options
+        for section in self.section_order() {
+            if !self.section_enabled(&section) {
+                continue;
+            }
+            match section.as_str() {
+                "experiences" => self.markdown_experiences(resume, &mut content),
+                "education" => self.markdown_education(resume, &mut content),
+                "skills" => self.markdown_skills(resume, &mut content),
+                "projects" => self.markdown_projects(resume, &mut content),
+                "certifications" => self.markdown_certifications(resume, &mut content),
+                "languages" => markdown_languages(
+                    resume,
+                    self.locale(),
+                    self.heading("languages"),
+                    &mut content,
+                ),
+                "publications" => self.markdown_publications(resume, &mut content),
+                "volunteer" => self.markdown_volunteer(resume, &mut content),
+                other => warnings.push(format!("Unknown section '{}' in section order", other)),
+            }
+        }
+
+        warn_about_missing_sections(resume, warnings);
+        Ok(content)
+    }
+
+    /// The variant's section order, or else `options.section_order`,
+    /// followed by any default sections it leaves out.
+    fn section_order(&self) -> Vec<String> {
+        let mut order = if self.section_order.is_empty() {
+            self.options.section_order.clone()
+        } else {
+            self.section_order.clone()
+        };
+        for section in DEFAULT_SECTION_ORDER {
+            if !order.iter().any(|s| s == section) {
+                order.push(section.to_string());
+            }
+        }
+        order
+    }
+
+    /// The heading for `section`, from `options.section_headings` or else
+    /// the locale's default.
+    fn heading<'a>(&'a self, section: &'a str) -> &'a str {
+        self.options
+            .section_headings
+            .get(section)
+            .map(String::as_str)
+            .or_else(|| {
+                DEFAULT_HEADINGS
+                    .iter()
+                    .find(|(name, _)| *name == section)
+                    .map(|(_, heading)| self.locale().text(heading))
+            })
+            .unwrap_or(section)
+    }
+
+    /// The locale named by `options.locale`; `format` has already rejected
+    /// unknown ones.
+    fn locale(&self) -> &'static Locale {
+        Locale::find(&self.options.locale).unwrap_or(&Locale::builtin()[0])
+    }
+
+    fn format_date(&self, date: NaiveDate) -> String {
+        self.locale().format_date(date, &self.options.date_format)
+    }
+
+    fn section_enabled(&self, section: &str) -> bool {
+        self.options
             .section_options
-            .get("experiences")
+            .get(section)
             .copied()
             .unwrap_or(true)
-            && !resume.experiences.is_empty()
-        {
-            content.push_str("## Work Experience\n\n");
+    }
 
-            for exp in &resume.experiences {
-                content.push_str(&format!("### {} at {}\n", exp.title, exp.company));
+    fn markdown_experiences(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.experiences.is_empty() {
+            return;
+        }
 
-                let date_str = self.format_date_range(exp.start_date, exp.end_date, exp.current);
-                content.push_str(&format!("_{}_\n\n", date_str));
+        content.push_str(&format!("## {}\n\n", self.heading("experiences")));
 
-                if let Some(location) = &exp.location {
-                    content.push_str(&format!("**Location:** {}\n\n", location));
-                }
+        for exp in &resume.experiences {
+            content.push_str(&format!(
+                "### {}\n",
+                locale.fill("{0} at {1}", &[&exp.title, &exp.company])
+            ));
 
-                if !exp.description.is_empty() {
-                    content.push_str(&exp.description);
-                    content.push_str("\n\n");
-                }
+            let date_str = self.format_date_range(exp.start_date, exp.end_date, exp.current);
+            content.push_str(&format!("_{}_\n\n", date_str));
 
-                if !exp.achievements.is_empty() {
-                    content.push_str("**Key Achievements:**\n\n");
-                    for achievement in &exp.achievements {
-                        content.push_str(&format!("- {}\n", achievement));
-                    }
-                    content.push('\n');
-                }
+            if let Some(location) = &exp.location {
+                content.push_str(&format!(
+                    "**{}:** {}\n\n",
+                    locale.text("Location"),
+                    location
+                ));
+            }
+
+            if !exp.description.is_empty() {
+                content.push_str(&exp.description);
+                content.push_str("\n\n");
+            }
 
-                if !exp.technologies.is_empty() {
-                    content.push_str("**Technologies:** ");
-                    content.push_str(&exp.technologies.join(", "));
-                    content.push_str("\n\n");
+            if !exp.achievements.is_empty() {
+                content.push_str(&format!("**{}:**\n\n", locale.text("Key Achievements")));
+                for achievement in &exp.achievements {
+                    content.push_str(&format!("- {}\n", achievement));
                 }
+                content.push('\n');
+            }
+
+            if !exp.technologies.is_empty() {
+                content.push_str(&format!("**{}:** ", locale.text("Technologies")));
+                content.push_str(&exp.technologies.join(", "));
+                content.push_str("\n\n");
             }
         }
+    }
 
-        if self
-            .options
-            .section_options
-            .get("education")
-            .copied()
-            .unwrap_or(true)
-            && !resume.education.is_empty()
-        {
-            content.push_str("## Education\n\n");
+    fn markdown_education(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.education.is_empty() {
+            return;
+        }
 
-            for edu in &resume.education {
-                content.push_str(&format!("### {} in {}\n", edu.degree, edu.field_of_study));
-                content.push_str(&format!("**{}**\n", edu.institution));
+        content.push_str(&format!("## {}\n\n", self.heading("education")));
 
-                let date_str = self.format_date_range(edu.start_date, edu.end_date, edu.current);
-                content.push_str(&format!("_{}_\n\n", date_str));
+        for edu in &resume.education {
+            content.push_str(&format!(
+                "### {}\n",
+                locale.fill("{0} in {1}", &[&edu.degree, &edu.field_of_study])
+            ));
+            content.push_str(&format!("**{}**\n", edu.institution));
 
-                if let Some(gpa) = edu.gpa {
-                    content.push_str(&format!("**GPA:** {:.2}\n\n", gpa));
-                }
+            let date_str = self.format_date_range(edu.start_date, edu.end_date, edu.current);
+            content.push_str(&format!("_{}_\n\n", date_str));
 
-                if !edu.description.is_empty() {
-                    content.push_str(&edu.description);
-                    content.push_str("\n\n");
-                }
+            if let Some(gpa) = edu.gpa_text() {
+                content.push_str(&format!("**{}:** {}\n\n", locale.text("GPA"), gpa));
+            }
+
+            if !edu.description.is_empty() {
+                content.push_str(&edu.description);
+                content.push_str("\n\n");
+            }
 
-                if !edu.courses.is_empty() {
-                    content.push_str("**Relevant Courses:**\n\n");
-                    for course in &edu.courses {
-                        content.push_str(&format!("- {}\n", course));
-                    }
-                    content.push('\n');
+            if !edu.courses.is_empty() {
+                content.push_str(&format!("**{}:**\n\n", locale.text("Relevant Courses")));
+                for course in &edu.courses {
+                    content.push_str(&format!("- {}\n", course));
                 }
+                content.push('\n');
+            }
 
-                if !edu.achievements.is_empty() {
-                    content.push_str("**Achievements:**\n\n");
-                    for achievement in &edu.achievements {
-                        content.push_str(&format!("- {}\n", achievement));
-                    }
-                    content.push('\n');
+            if !edu.achievements.is_empty() {
+                content.push_str(&format!("**{}:**\n\n", locale.text("Achievements")));
+                for achievement in &edu.achievements {
+                    content.push_str(&format!("- {}\n", achievement));
                 }
+                content.push('\n');
             }
         }
+    }
+
+    fn markdown_skills(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        content.push_str(&format!("## {}\n\n", self.heading("skills")));
+
+        if !resume.skills.technical.is_empty() {
+            content.push_str(&format!("### {}\n\n", locale.text("Technical Skills")));
+            content.push_str(
+                &resume
+                    .skills
+                    .technical
+                    .iter()
+                    .map(|skill| skill.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+            content.push_str("\n\n");
+        }
 
-        if self
-            .options
-            .section_options
-            .get("skills")
-            .copied()
-            .unwrap_or(true)
-        {
-            content.push_str("## Skills\n\n");
-
-            if !resume.skills.technical.is_empty() {
-                content.push_str("### Technical Skills\n\n");
-                content.push_str(
-                    &resume
-                        .skills
-                        .technical
-                        .iter()
-                        .map(|skill| skill.name.clone())
-                        .collect::<Vec<_>>()
-                        .join(", "),
-                );
-                content.push_str("\n\n");
+        if !resume.skills.soft.is_empty() {
+            content.push_str(&format!("### {}\n\n", locale.text("Soft Skills")));
+            content.push_str(
+                &resume
+                    .skills
+                    .soft
+                    .iter()
+                    .map(|skill| skill.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+            content.push_str("\n\n");
+        }
+
+        if !resume.skills.tools.is_empty() {
+            content.push_str(&format!("### {}\n\n", locale.text("Tools")));
+            content.push_str(
+                &resume
+                    .skills
+                    .tools
+                    .iter()
+                    .map(|skill| skill.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+            content.push_str("\n\n");
+        }
+
+        if !resume.skills.languages.is_empty() {
+            content.push_str(&format!("### {}\n\n", locale.text("Languages")));
+            for lang in &resume.skills.languages {
+                content.push_str(&format!("- {}\n", lang.name));
             }
+            content.push('\n');
+        }
+
+        if !resume.skills.other.is_empty() {
+            content.push_str(&format!("### {}\n\n", locale.text("Other")));
+            content.push_str(
+                &resume
+                    .skills
+                    .other
+                    .iter()
+                    .map(|skill| skill.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+            content.push_str("\n\n");
+        }
+    }
+
+    fn markdown_projects(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.projects.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!("## {}\n\n", self.heading("projects")));
 
-            if !resume.skills.soft.is_empty() {
-                content.push_str("### Soft Skills\n\n");
-                content.push_str(
-                    &resume
-                        .skills
-                        .soft
-                        .iter()
-                        .map(|skill| skill.name.clone())
-                        .collect::<Vec<_>>()
-                        .join(", "),
-                );
+        for project in &resume.projects {
+            content.push_str(&format!("### {}\n\n", project.name));
+
+            if !project.description.is_empty() {
+                content.push_str(&project.description);
                 content.push_str("\n\n");
             }
 
-            if !resume.skills.tools.is_empty() {
-                content.push_str("### Tools\n\n");
-                content.push_str(
-                    &resume
-                        .skills
-                        .tools
-                        .iter()
-                        .map(|skill| skill.name.clone())
-                        .collect::<Vec<_>>()
-                        .join(", "),
-                );
+            if let Some(url) = &project.url {
+                content.push_str(&format!(
+                    "**{}:** [{}]({})\n\n",
+                    locale.text("Link"),
+                    locale.text("Project Link"),
+                    url
+                ));
+            }
+
+            if let Some(github) = &project.github {
+                content.push_str(&format!(
+                    "**GitHub:** [{}]({})\n\n",
+                    locale.text("Repository"),
+                    github
+                ));
+            }
+
+            if !project.technologies.is_empty() {
+                content.push_str(&format!("**{}:** ", locale.text("Technologies")));
+                content.push_str(&project.technologies.join(", "));
                 content.push_str("\n\n");
             }
 
-            if !resume.skills.languages.is_empty() {
-                content.push_str("### Languages\n\n");
-                for lang in &resume.skills.languages {
-                    content.push_str(&format!("- {}\n", lang.name));
+            if !project.highlights.is_empty() {
+                content.push_str(&format!("**{}:**\n\n", locale.text("Highlights")));
+                for highlight in &project.highlights {
+                    content.push_str(&format!("- {}\n", highlight));
                 }
                 content.push('\n');
             }
         }
+    }
 
-        if self
-            .options
-            .section_options
-            .get("projects")
-            .copied()
-            .unwrap_or(true)
-            && !resume.projects.is_empty()
-        {
-            content.push_str("## Projects\n\n");
-
-            for project in &resume.projects {
-                content.push_str(&format!("### {}\n\n", project.name));
+    fn markdown_certifications(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.certifications.is_empty() {
+            return;
+        }
 
-                if !project.description.is_empty() {
-                    content.push_str(&project.description);
-                    content.push_str("\n\n");
-                }
+        content.push_str(&format!("## {}\n\n", self.heading("certifications")));
 
-                if let Some(url) = &project.url {
-                    content.push_str(&format!("**Link:** [Project Link]({})\n\n", url));
-                }
+        for cert in &resume.certifications {
+            content.push_str(&format!("### {}\n", cert.name));
+            if !cert.issuer.is_empty() {
+                content.push_str(&format!("**{}**\n", cert.issuer));
+            }
 
-                if let Some(github) = &project.github {
-                    content.push_str(&format!("**GitHub:** [Repository]({})\n\n", github));
-                }
+            let dates = self.certification_dates(cert);
+            if !dates.is_empty() {
+                content.push_str(&format!("_{}_\n", dates));
+            }
+            content.push('\n');
 
-                if !project.technologies.is_empty() {
-                    content.push_str("**Technologies:** ");
-                    content.push_str(&project.technologies.join(", "));
-                    content.push_str("\n\n");
-                }
+            if let Some(id) = &cert.credential_id {
+                content.push_str(&format!("**{}:** {}\n\n", locale.text("Credential ID"), id));
+            }
 
-                if !project.highlights.is_empty() {
-                    content.push_str("**Highlights:**\n\n");
-                    for highlight in &project.highlights {
-                        content.push_str(&format!("- {}\n", highlight));
-                    }
-                    content.push('\n');
-                }
+            if let Some(url) = &cert.url {
+                content.push_str(&format!(
+                    "**{}:** [{}]({})\n\n",
+                    locale.text("Credential"),
+                    locale.text("Verify Credential"),
+                    url
+                ));
             }
         }
+    }
 
-        // other sections
-
-        if resume.experiences.is_empty() {
-            warnings.push("Resume doesn't have any work experiences".to_string());
+    fn markdown_publications(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.publications.is_empty() {
+            return;
         }
 
-        if resume.education.is_empty() {
-            warnings.push("Resume doesn't have any education entries".to_string());
+        content.push_str(&format!("## {}\n\n", self.heading("publications")));
+
+        for publication in &resume.publications {
+            content.push_str(&format!("### {}\n", publication.title));
+            if !publication.publisher.is_empty() {
+                content.push_str(&format!("**{}**\n", publication.publisher));
+            }
+
+            if let Some(date) = publication.published_date {
+                content.push_str(&format!("_{}_\n", self.format_date(date)));
+            }
+            content.push('\n');
+
+            if !publication.authors.is_empty() {
+                content.push_str(&format!("**{}:** ", locale.text("Authors")));
+                content.push_str(&publication.authors.join(", "));
+                content.push_str("\n\n");
+            }
+
+            if let Some(url) = &publication.url {
+                content.push_str(&format!(
+                    "**{}:** [{}]({})\n\n",
+                    locale.text("Link"),
+                    locale.text("Publication Link"),
+                    url
+                ));
+            }
+
+            if !publication.description.is_empty() {
+                content.push_str(&publication.description);
+                content.push_str("\n\n");
+            }
         }
+    }
 
-        if resume.skills.technical.is_empty() {
-            warnings.push("Resume doesn't have any technical skills".to_string());
+    fn markdown_volunteer(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.volunteer.is_empty() {
+            return;
         }
 
-        Ok(content)
+        content.push_str(&format!("## {}\n\n", self.heading("volunteer")));
+
+        for volunteer in &resume.volunteer {
+            content.push_str(&format!(
+                "### {}\n",
+                locale.fill("{0} at {1}", &[&volunteer.role, &volunteer.organization])
+            ));
+
+            let date_str =
+                self.format_date_range(volunteer.start_date, volunteer.end_date, volunteer.current);
+            content.push_str(&format!("_{}_\n\n", date_str));
+
+            if let Some(location) = &volunteer.location {
+                content.push_str(&format!(
+                    "**{}:** {}\n\n",
+                    locale.text("Location"),
+                    location
+                ));
+            }
+
+            if !volunteer.description.is_empty() {
+                content.push_str(&volunteer.description);
+                content.push_str("\n\n");
+            }
+        }
     }
 
     fn format_plaintext(
@@ -454,116 +937,1436 @@ impl ResumeFormatter {
     }
 
     fn format_html(&self, resume: &Resume, warnings: &mut Vec<String>) -> Result<String, String> {
-        let markdown = self.format_markdown(resume, warnings)?;
-
-        // In a real implementation, this would use a proper Markdown-to-HTML converter
-        // like pulldown-cmark, but for simplicity we'll do a basic conversion
-
-        let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        let locale = self.locale();
+        let mut html = format!("<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n", locale.code);
         html.push_str("<meta charset=\"UTF-8\">\n");
         html.push_str(
             "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\">\n",
         );
         html.push_str(&format!(
-            "<title>{}'s Resume</title>\n",
-            resume.profile.name
+            "<title>{}</title>\n",
+            escape_html(&locale.fill("{0}'s Resume", &[&resume.profile.name]))
         ));
 
         html.push_str("<style>\n");
-        html.push_str("body { font-family: Arial, sans-serif; line-height: 1.6; max-width: 800px; margin: 0 auto; padding: 20px; }\n");
-        html.push_str("h1, h2, h3 { color: #333; }\n");
-        html.push_str("h1 { border-bottom: 2px solid #333; padding-bottom: 10px; }\n");
-        html.push_str(
-            "h2 { border-bottom: 1px solid #ddd; padding-bottom: 5px; margin-top: 20px; }\n",
-        );
-        html.push_str("ul { margin-top: 5px; }\n");
+        html.push_str(&self.html_stylesheet(warnings)?);
         html.push_str("</style>\n");
         html.push_str("</head>\n<body>\n");
 
-        let mut content = markdown;
-        content = content
-            .replace("\n# ", "\n<h1>")
-            .replace("\n## ", "\n<h2>")
-            .replace("\n### ", "\n<h3>");
-        content = content
-            .replace(" #\n", "</h1>\n")
-            .replace(" ##\n", "</h2>\n")
-            .replace(" ###\n", "</h3>\n");
-
-        content = content.replace("\n- ", "\n<li>").replace("\n* ", "\n<li>");
-        content = regex::Regex::new(r"<li>(.*?)(\n|$)")
-            .unwrap()
-            .replace_all(&content, "<li>$1</li>$2")
-            .to_string();
-        content = regex::Regex::new(r"(<li>.*?</li>)+")
-            .unwrap()
-            .replace_all(&content, "<ul>$0</ul>")
-            .to_string();
-
-        content = content.replace("**", "<strong>").replace("__", "<strong>");
-        let strong_count = content.matches("<strong>").count();
-        if strong_count % 2 != 0 {
-            warnings.push("Mismatched bold markers in content".to_string());
-        }
-        for _ in 0..(strong_count / 2) {
-            content = content
-                .replacen("**", "<strong>", 1)
-                .replacen("**", "</strong>", 1);
-        }
-
-        content = content.replace("*", "<em>").replace("_", "<em>");
-        let em_count = content.matches("<em>").count();
-        if em_count % 2 != 0 {
-            warnings.push("Mismatched italic markers in content".to_string());
-        }
-        for _ in 0..(em_count / 2) {
-            content = content
-                .replacen("<em>", "</em>", 2)
-                .replacen("</em>", "<em>", 1);
-        }
-
-        let link_re = regex::Regex::new(r"\[(.*?)\]\((.*?)\)").unwrap();
-        content = link_re
-            .replace_all(&content, "<a href=\"$2\">$1</a>")
-            .to_string();
-
-        content = content.replace("\n\n", "\n</p>\n<p>\n");
-        content = format!("<p>{}</p>", content);
+        // The page body is an h-resume microformat, so its classes mark up
+        // the same elements readers see
+        let mut content = String::from("<div class=\"h-resume\">\n");
+        self.html_header(resume, &mut content);
+
+        if !resume.profile.summary.is_empty() {
+            content.push_str(&format!(
+                "<h2>{}</h2>\n<div class=\"p-summary\">\n",
+                escape_html(self.heading("summary"))
+            ));
+            content.push_str(&html_paragraphs(&resume.profile.summary));
+            content.push_str("</div>\n");
+        }
+
+        for section in self.section_order() {
+            if !self.section_enabled(&section) {
+                continue;
+            }
+            match section.as_str() {
+                "experiences" => self.html_experiences(resume, &mut content),
+                "education" => self.html_education(resume, &mut content),
+                "skills" => html_skills(resume, locale, self.heading("skills"), &mut content),
+                "projects" => self.html_projects(resume, &mut content),
+                "certifications" => self.html_certifications(resume, &mut content),
+                "languages" => {
+                    html_languages(resume, locale, self.heading("languages"), &mut content)
+                }
+                "publications" => self.html_publications(resume, &mut content),
+                "volunteer" => self.html_volunteer(resume, &mut content),
+                other => warnings.push(format!("Unknown section '{}' in section order", other)),
+            }
+        }
+        warn_about_missing_sections(resume, warnings);
 
+        content.push_str("</div>\n");
         html.push_str(&content);
-        html.push_str("\n</body>\n</html>");
+        html.push_str(&structured_data::json_ld_script(
+            &self.shown_content(resume),
+        ));
+        html.push_str("</body>\n</html>");
 
         Ok(html)
     }
 
-    fn format_date_range(
-        &self,
-        start: Option<NaiveDate>,
-        end: Option<NaiveDate>,
-        current: bool,
-    ) -> String {
-        let start_str = match start {
-            Some(date) => date.format(&self.options.date_format).to_string(),
-            None => "".to_string(),
-        };
+    /// The name and title, plus the contact list when it's included, as the
+    /// resume's `p-contact h-card`.
+    fn html_header(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        let profile = &resume.profile;
+        content.push_str("<div class=\"p-contact h-card\">\n");
+        content.push_str(&format!(
+            "<h1 class=\"p-name\">{}</h1>\n",
+            escape_html(&profile.name)
+        ));
+        if !profile.title.is_empty() {
+            content.push_str(&format!(
+                "<h2 class=\"p-job-title\">{}</h2>\n",
+                escape_html(&profile.title)
+            ));
+        }
 
-        let end_str = if current {
-            "Present".to_string()
-        } else {
-            match end {
-                Some(date) => date.format(&self.options.date_format).to_string(),
-                None => "".to_string(),
+        if self.options.include_contact_info {
+            let mut items = vec![];
+            if !profile.email.is_empty() {
+                let email = escape_html(&profile.email);
+                items.push((
+                    "Email",
+                    format!(
+                        "<a class=\"u-email\" href=\"mailto:{}\">{}</a>",
+                        email, email
+                    ),
+                ));
+            }
+            if !profile.phone.is_empty() {
+                items.push((
+                    "Phone",
+                    format!(
+                        "<span class=\"p-tel\">{}</span>",
+                        escape_html(&profile.phone)
+                    ),
+                ));
+            }
+            let location: Vec<String> = [
+                ("p-locality", &profile.location.city),
+                ("p-country-name", &profile.location.country),
+            ]
+            .into_iter()
+            .filter(|(_, part)| !part.is_empty())
+            .map(|(class, part)| format!("<span class=\"{}\">{}</span>", class, escape_html(part)))
+            .collect();
+            if !location.is_empty() {
+                items.push(("Location", location.join(", ")));
+            }
+            for (label, url) in [
+                ("LinkedIn", &profile.linkedin),
+                ("GitHub", &profile.github),
+                ("Website", &profile.website),
+            ] {
+                if !url.is_empty() {
+                    items.push((label, html_link("u-url", url, url)));
+                }
             }
-        };
 
-        if start_str.is_empty() && end_str.is_empty() {
-            "".to_string()
-        } else if start_str.is_empty() {
-            format!("Until {}", end_str)
-        } else if end_str.is_empty() {
-            format!("From {}", start_str)
-        } else {
-            format!("{} - {}", start_str, end_str)
+            content.push_str(&format!(
+                "<h2>{}</h2>\n<ul>\n",
+                escape_html(self.heading("contact"))
+            ));
+            for (label, value) in items {
+                content.push_str(&format!(
+                    "<li>{}: {}</li>\n",
+                    escape_html(locale.text(label)),
+                    value
+                ));
+            }
+            content.push_str("</ul>\n");
+        }
+        content.push_str("</div>\n");
+    }
+
+    fn html_experiences(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.experiences.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "<h2>{}</h2>\n",
+            escape_html(self.heading("experiences"))
+        ));
+        for exp in &resume.experiences {
+            content.push_str("<div class=\"p-experience h-event\">\n");
+            let title = format!("<span class=\"p-name\">{}</span>", escape_html(&exp.title));
+            let company = format!(
+                "<span class=\"p-location h-card\"><span class=\"p-name p-org\">{}</span></span>",
+                escape_html(&exp.company)
+            );
+            content.push_str(&format!(
+                "<h3>{}</h3>\n",
+                locale.fill("{0} at {1}", &[&title, &company])
+            ));
+            content.push_str(&html_dates(&self.html_date_range(
+                exp.start_date,
+                exp.end_date,
+                exp.current,
+            )));
+            if let Some(location) = &exp.location {
+                content.push_str(&html_detail(
+                    locale.text("Location"),
+                    &escape_html(location),
+                ));
+            }
+            if !exp.description.is_empty() {
+                content.push_str("<div class=\"p-summary\">\n");
+                content.push_str(&html_paragraphs(&exp.description));
+                content.push_str("</div>\n");
+            }
+            if !exp.achievements.is_empty() {
+                content.push_str(&html_list(
+                    locale.text("Key Achievements"),
+                    &exp.achievements,
+                ));
+            }
+            if !exp.technologies.is_empty() {
+                content.push_str(&html_labeled(
+                    locale.text("Technologies"),
+                    &exp.technologies,
+                ));
+            }
+            content.push_str("</div>\n");
+        }
+    }
+
+    fn html_education(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.education.is_empty() {
+            return;
         }
+
+        content.push_str(&format!(
+            "<h2>{}</h2>\n",
+            escape_html(self.heading("education"))
+        ));
+        for edu in &resume.education {
+            content.push_str("<div class=\"p-education h-event\">\n");
+            content.push_str(&format!(
+                "<h3 class=\"p-name\">{}</h3>\n",
+                escape_html(&locale.fill("{0} in {1}", &[&edu.degree, &edu.field_of_study]))
+            ));
+            content.push_str(&format!(
+                "<p class=\"p-location h-card\"><strong class=\"p-name\">{}</strong></p>\n",
+                escape_html(&edu.institution)
+            ));
+            content.push_str(&html_dates(&self.html_date_range(
+                edu.start_date,
+                edu.end_date,
+                edu.current,
+            )));
+            if let Some(gpa) = edu.gpa_text() {
+                content.push_str(&html_detail(locale.text("GPA"), &escape_html(&gpa)));
+            }
+            if !edu.description.is_empty() {
+                content.push_str("<div class=\"p-summary\">\n");
+                content.push_str(&html_paragraphs(&edu.description));
+                content.push_str("</div>\n");
+            }
+            if !edu.courses.is_empty() {
+                content.push_str(&html_list(locale.text("Relevant Courses"), &edu.courses));
+            }
+            if !edu.achievements.is_empty() {
+                content.push_str(&html_list(locale.text("Achievements"), &edu.achievements));
+            }
+            content.push_str("</div>\n");
+        }
+    }
+
+    fn html_projects(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.projects.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "<h2>{}</h2>\n",
+            escape_html(self.heading("projects"))
+        ));
+        for project in &resume.projects {
+            content.push_str(&format!("<h3>{}</h3>\n", escape_html(&project.name)));
+            if !project.description.is_empty() {
+                content.push_str(&html_paragraphs(&project.description));
+            }
+            if let Some(url) = &project.url {
+                content.push_str(&html_detail(
+                    locale.text("Link"),
+                    &html_link("", url, locale.text("Project Link")),
+                ));
+            }
+            if let Some(github) = &project.github {
+                content.push_str(&html_detail(
+                    "GitHub",
+                    &html_link("", github, locale.text("Repository")),
+                ));
+            }
+            if !project.technologies.is_empty() {
+                content.push_str(&html_labeled(
+                    locale.text("Technologies"),
+                    &project.technologies,
+                ));
+            }
+            if !project.highlights.is_empty() {
+                content.push_str(&html_list(locale.text("Highlights"), &project.highlights));
+            }
+        }
+    }
+
+    fn html_certifications(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.certifications.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "<h2>{}</h2>\n",
+            escape_html(self.heading("certifications"))
+        ));
+        for cert in &resume.certifications {
+            content.push_str(&format!("<h3>{}</h3>\n", escape_html(&cert.name)));
+            if !cert.issuer.is_empty() {
+                content.push_str(&format!(
+                    "<p><strong>{}</strong></p>\n",
+                    escape_html(&cert.issuer)
+                ));
+            }
+            let dates = self.certification_dates(cert);
+            if !dates.is_empty() {
+                content.push_str(&html_dates(&escape_html(&dates)));
+            }
+            if let Some(id) = &cert.credential_id {
+                content.push_str(&html_detail(locale.text("Credential ID"), &escape_html(id)));
+            }
+            if let Some(url) = &cert.url {
+                content.push_str(&html_detail(
+                    locale.text("Credential"),
+                    &html_link("", url, locale.text("Verify Credential")),
+                ));
+            }
+        }
+    }
+
+    fn html_publications(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.publications.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "<h2>{}</h2>\n",
+            escape_html(self.heading("publications"))
+        ));
+        for publication in &resume.publications {
+            content.push_str(&format!("<h3>{}</h3>\n", escape_html(&publication.title)));
+            if !publication.publisher.is_empty() {
+                content.push_str(&format!(
+                    "<p><strong>{}</strong></p>\n",
+                    escape_html(&publication.publisher)
+                ));
+            }
+            if let Some(date) = publication.published_date {
+                content.push_str(&html_dates(&escape_html(&self.format_date(date))));
+            }
+            if !publication.authors.is_empty() {
+                content.push_str(&html_labeled(locale.text("Authors"), &publication.authors));
+            }
+            if let Some(url) = &publication.url {
+                content.push_str(&html_detail(
+                    locale.text("Link"),
+                    &html_link("", url, locale.text("Publication Link")),
+                ));
+            }
+            if !publication.description.is_empty() {
+                content.push_str(&html_paragraphs(&publication.description));
+            }
+        }
+    }
+
+    fn html_volunteer(&self, resume: &Resume, content: &mut String) {
+        let locale = self.locale();
+        if resume.volunteer.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "<h2>{}</h2>\n",
+            escape_html(self.heading("volunteer"))
+        ));
+        for volunteer in &resume.volunteer {
+            content.push_str(&format!(
+                "<h3>{}</h3>\n",
+                escape_html(
+                    &locale.fill("{0} at {1}", &[&volunteer.role, &volunteer.organization])
+                )
+            ));
+            let dates =
+                self.format_date_range(volunteer.start_date, volunteer.end_date, volunteer.current);
+            content.push_str(&html_dates(&escape_html(&dates)));
+            if let Some(location) = &volunteer.location {
+                content.push_str(&html_detail(
+                    locale.text("Location"),
+                    &escape_html(location),
+                ));
+            }
+            if !volunteer.description.is_empty() {
+                content.push_str(&html_paragraphs(&volunteer.description));
+            }
+        }
+    }
+
+    /// `resume` without the sections and contact details the options hide,
+    /// so structured data describes only what the page shows.
+    fn shown_content(&self, resume: &Resume) -> Resume {
+        let mut shown = resume.clone();
+        if !self.options.include_contact_info {
+            let profile = &mut shown.profile;
+            for field in [
+                &mut profile.email,
+                &mut profile.phone,
+                &mut profile.linkedin,
+                &mut profile.github,
+                &mut profile.website,
+                &mut profile.location.city,
+                &mut profile.location.country,
+            ] {
+                field.clear();
+            }
+        }
+        if !self.section_enabled("experiences") {
+            shown.experiences.clear();
+        }
+        if !self.section_enabled("education") {
+            shown.education.clear();
+        }
+        if !self.section_enabled("skills") {
+            shown.skills = Default::default();
+        }
+        if !self.section_enabled("certifications") {
+            shown.certifications.clear();
+        }
+        shown
+    }
+
+    /// The `<style>` contents for HTML output. The theme is taken from the
+    /// `html_theme` custom option, or else from the template name. Other
+    /// custom options:
+    /// - `accent_color` and `font_family` override the theme's
+    /// - `color_scheme` is "light", "dark" or "auto"
+    /// - `page_size` and `margin` set up printed pages
+    /// - `html_css` names a CSS file added after the theme
+    fn html_stylesheet(&self, warnings: &mut Vec<String>) -> Result<String, String> {
+        let custom = &self.options.custom_options;
+        let theme = match custom.get("html_theme") {
+            Some(name) => {
+                HtmlTheme::find(name).ok_or_else(|| format!("HTML theme '{}' not found", name))?
+            }
+            None => HtmlTheme::find(&self.options.template).unwrap_or_else(|| {
+                warnings.push(format!(
+                    "No HTML theme for template '{}', using 'modern'",
+                    self.options.template
+                ));
+                &HtmlTheme::builtin()[0]
+            }),
+        };
+
+        let accent = custom
+            .get("accent_color")
+            .map(String::as_str)
+            .unwrap_or(theme.accent);
+        if !html_theme::is_color(accent) {
+            return Err(format!("Invalid accent color '{}'", accent));
+        }
+        let font = custom
+            .get("font_family")
+            .map(String::as_str)
+            .unwrap_or(theme.font);
+        if !html_theme::is_font_family(font) {
+            return Err(format!("Invalid font family '{}'", font));
+        }
+        let scheme = custom
+            .get("color_scheme")
+            .map(String::as_str)
+            .unwrap_or("light");
+        let scheme = ColorScheme::parse(scheme)
+            .ok_or_else(|| format!("Invalid color scheme '{}'", scheme))?;
+        let paper = custom
+            .get("page_size")
+            .map(String::as_str)
+            .unwrap_or("us-letter");
+        let page_size =
+            html_theme::page_size(paper).ok_or_else(|| format!("Invalid page size '{}'", paper))?;
+        let margin = custom
+            .get("margin")
+            .map(String::as_str)
+            .unwrap_or(theme.margin);
+        if !typst::is_length(margin) {
+            return Err(format!("Invalid page margin '{}'", margin));
+        }
+
+        let mut css = theme.stylesheet(&StyleOptions {
+            accent,
+            font,
+            scheme,
+            page_size,
+            margin,
+        });
+        if let Some(path) = custom.get("html_css") {
+            let user_css = std::fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read CSS file: {}", e))?;
+            if user_css.to_lowercase().contains("</style") {
+                return Err("CSS file must not contain '</style>'".to_string());
+            }
+            css.push_str(&user_css);
+            if !css.ends_with('\n') {
+                css.push('\n');
+            }
+        }
+        Ok(css)
+    }
+
+    /// Renders a standalone LaTeX document. The style is taken from the
+    /// `latex_style` custom option, or else from the template name.
+    fn format_latex(&self, resume: &Resume, warnings: &mut Vec<String>) -> Result<String, String> {
+        let style = match self.options.custom_options.get("latex_style") {
+            Some(name) => {
+                LatexStyle::find(name).ok_or_else(|| format!("LaTeX style '{}' not found", name))?
+            }
+            None => LatexStyle::find(&self.options.template).unwrap_or_else(|| {
+                warnings.push(format!(
+                    "No LaTeX style for template '{}', using 'classic'",
+                    self.options.template
+                ));
+                &LatexStyle::builtin()[0]
+            }),
+        };
+        let profile = &resume.profile;
+        let mut content = style.header();
+
+        content.push_str("\\begin{center}\n");
+        content.push_str(&format!(
+            "{{\\LARGE\\bfseries {}}}\\\\\n",
+            latex::escape(&profile.name)
+        ));
+        if !profile.title.is_empty() {
+            content.push_str(&format!("{}\\\\\n", latex::escape(&profile.title)));
+        }
+        if self.options.include_contact_info {
+            let location = [&profile.location.city, &profile.location.country]
+                .iter()
+                .filter(|part| !part.is_empty())
+                .map(|part| part.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let mut contact = vec![];
+            for value in [&profile.email, &profile.phone, &location] {
+                if !value.is_empty() {
+                    contact.push(latex::escape(value));
+                }
+            }
+            for url in [&profile.linkedin, &profile.github, &profile.website] {
+                if !url.is_empty() {
+                    contact.push(latex_link(url));
+                }
+            }
+            if !contact.is_empty() {
+                content.push_str(&contact.join(" \\textbar{} "));
+                content.push('\n');
+            }
+        }
+        content.push_str("\\end{center}\n");
+
+        if !profile.summary.is_empty() {
+            content.push_str(&format!(
+                "\n\\section*{{{}}}\n",
+                latex::escape(self.heading("summary"))
+            ));
+            content.push_str(&latex_paragraphs(&profile.summary));
+        }
+
+        for section in self.section_order() {
+            if !self.section_enabled(&section) {
+                continue;
+            }
+            match section.as_str() {
+                "experiences" => self.latex_experiences(resume, &mut content),
+                "education" => self.latex_education(resume, &mut content),
+                "skills" => {
+                    latex_skills(resume, self.locale(), self.heading("skills"), &mut content)
+                }
+                "projects" => self.latex_projects(resume, &mut content),
+                "certifications" => self.latex_certifications(resume, &mut content),
+                "languages" => latex_languages(
+                    resume,
+                    self.locale(),
+                    self.heading("languages"),
+                    &mut content,
+                ),
+                "publications" => self.latex_publications(resume, &mut content),
+                "volunteer" => self.latex_volunteer(resume, &mut content),
+                other => warnings.push(format!("Unknown section '{}' in section order", other)),
+            }
+        }
+
+        content.push_str("\n\\end{document}\n");
+        Ok(content)
+    }
+
+    fn latex_experiences(&self, resume: &Resume, content: &mut String) {
+        if resume.experiences.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n\\section*{{{}}}\n",
+            latex::escape(self.heading("experiences"))
+        ));
+        for exp in &resume.experiences {
+            let dates = self.format_date_range(exp.start_date, exp.end_date, exp.current);
+            content.push_str(&latex_entry(&exp.title, &exp.company, &dates));
+            if let Some(location) = &exp.location {
+                content.push_str(&latex_detail(&latex::escape(location)));
+            }
+            if !exp.description.is_empty() {
+                content.push_str(&latex_paragraphs(&exp.description));
+            }
+            content.push_str(&latex_list(&exp.achievements));
+            if !exp.technologies.is_empty() {
+                content.push_str(&latex_labeled(
+                    self.locale().text("Technologies"),
+                    &exp.technologies,
+                ));
+            }
+        }
+    }
+
+    fn latex_education(&self, resume: &Resume, content: &mut String) {
+        if resume.education.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n\\section*{{{}}}\n",
+            latex::escape(self.heading("education"))
+        ));
+        for edu in &resume.education {
+            let dates = self.format_date_range(edu.start_date, edu.end_date, edu.current);
+            let degree = self
+                .locale()
+                .fill("{0} in {1}", &[&edu.degree, &edu.field_of_study]);
+            content.push_str(&latex_entry(&degree, &edu.institution, &dates));
+            if let Some(gpa) = edu.gpa_text() {
+                content.push_str(&latex_detail(&format!(
+                    "{}: {}",
+                    latex::escape(self.locale().text("GPA")),
+                    gpa
+                )));
+            }
+            if !edu.description.is_empty() {
+                content.push_str(&latex_paragraphs(&edu.description));
+            }
+            if !edu.courses.is_empty() {
+                content.push_str(&latex_labeled(
+                    self.locale().text("Relevant Courses"),
+                    &edu.courses,
+                ));
+            }
+            content.push_str(&latex_list(&edu.achievements));
+        }
+    }
+
+    fn latex_projects(&self, resume: &Resume, content: &mut String) {
+        if resume.projects.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n\\section*{{{}}}\n",
+            latex::escape(self.heading("projects"))
+        ));
+        for project in &resume.projects {
+            let dates = self.format_date_range(project.start_date, project.end_date, false);
+            content.push_str(&latex_entry(&project.name, "", &dates));
+            if !project.description.is_empty() {
+                content.push_str(&latex_paragraphs(&project.description));
+            }
+            let links: Vec<String> = [&project.url, &project.github]
+                .into_iter()
+                .flatten()
+                .map(|url| latex_link(url))
+                .collect();
+            if !links.is_empty() {
+                content.push_str(&latex_detail(&links.join(" \\textbar{} ")));
+            }
+            if !project.technologies.is_empty() {
+                content.push_str(&latex_labeled(
+                    self.locale().text("Technologies"),
+                    &project.technologies,
+                ));
+            }
+            content.push_str(&latex_list(&project.highlights));
+        }
+    }
+
+    fn latex_certifications(&self, resume: &Resume, content: &mut String) {
+        if resume.certifications.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n\\section*{{{}}}\n",
+            latex::escape(self.heading("certifications"))
+        ));
+        for cert in &resume.certifications {
+            let dates = self.certification_dates(cert);
+            content.push_str(&latex_entry(&cert.name, &cert.issuer, &dates));
+            if let Some(id) = &cert.credential_id {
+                content.push_str(&latex_detail(&format!(
+                    "{}: {}",
+                    latex::escape(self.locale().text("Credential ID")),
+                    latex::escape(id)
+                )));
+            }
+            if let Some(url) = &cert.url {
+                content.push_str(&latex_detail(&latex_link(url)));
+            }
+        }
+    }
+
+    fn latex_publications(&self, resume: &Resume, content: &mut String) {
+        if resume.publications.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n\\section*{{{}}}\n",
+            latex::escape(self.heading("publications"))
+        ));
+        for publication in &resume.publications {
+            let date = publication
+                .published_date
+                .map(|date| self.format_date(date))
+                .unwrap_or_default();
+            content.push_str(&latex_entry(
+                &publication.title,
+                &publication.publisher,
+                &date,
+            ));
+            if !publication.authors.is_empty() {
+                content.push_str(&latex_detail(&latex::escape(
+                    &publication.authors.join(", "),
+                )));
+            }
+            if let Some(url) = &publication.url {
+                content.push_str(&latex_detail(&latex_link(url)));
+            }
+            if !publication.description.is_empty() {
+                content.push_str(&latex_paragraphs(&publication.description));
+            }
+        }
+    }
+
+    fn latex_volunteer(&self, resume: &Resume, content: &mut String) {
+        if resume.volunteer.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n\\section*{{{}}}\n",
+            latex::escape(self.heading("volunteer"))
+        ));
+        for volunteer in &resume.volunteer {
+            let dates =
+                self.format_date_range(volunteer.start_date, volunteer.end_date, volunteer.current);
+            content.push_str(&latex_entry(
+                &volunteer.role,
+                &volunteer.organization,
+                &dates,
+            ));
+            if let Some(location) = &volunteer.location {
+                content.push_str(&latex_detail(&latex::escape(location)));
+            }
+            if !volunteer.description.is_empty() {
+                content.push_str(&latex_paragraphs(&volunteer.description));
+            }
+        }
+    }
+
+    /// Renders a self-contained Typst document using the template named by
+    /// `options.template`. The `page_size` and `margin` custom options
+    /// override the paper (e.g. "a4") and the template's margin (e.g. "2cm").
+    fn format_typst(&self, resume: &Resume, warnings: &mut Vec<String>) -> Result<String, String> {
+        let template = TypstTemplate::find(&self.options.template).unwrap_or_else(|| {
+            warnings.push(format!(
+                "No Typst template for '{}', using 'classic'",
+                self.options.template
+            ));
+            TypstTemplate::find("classic").unwrap()
+        });
+
+        let paper = self
+            .options
+            .custom_options
+            .get("page_size")
+            .map(String::as_str)
+            .unwrap_or("us-letter");
+        let paper = page_fit::paper(paper)
+            .map(|paper| paper.typst)
+            .ok_or_else(|| format!("Invalid page size '{}'", paper))?;
+        let margin = self
+            .options
+            .custom_options
+            .get("margin")
+            .map(String::as_str)
+            .unwrap_or(template.margin);
+        if !typst::is_length(margin) {
+            return Err(format!("Invalid page margin '{}'", margin));
+        }
+
+        let profile = &resume.profile;
+        let locale = self.locale();
+        let title = locale.fill("{0} - Resume", &[&profile.name]);
+        let mut content = template.header(&title, &profile.name, locale.code, paper, margin);
+
+        content.push_str(&format!("= {}\n\n", typst::escape(&profile.name)));
+        let mut header_lines = vec![];
+        if !profile.title.is_empty() {
+            header_lines.push(typst::escape(&profile.title));
+        }
+        if self.options.include_contact_info {
+            let location = [&profile.location.city, &profile.location.country]
+                .iter()
+                .filter(|part| !part.is_empty())
+                .map(|part| part.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let mut contact = vec![];
+            for value in [&profile.email, &profile.phone, &location] {
+                if !value.is_empty() {
+                    contact.push(typst::escape(value));
+                }
+            }
+            for url in [&profile.linkedin, &profile.github, &profile.website] {
+                if !url.is_empty() {
+                    contact.push(typst_link(url));
+                }
+            }
+            if !contact.is_empty() {
+                header_lines.push(contact.join(" | "));
+            }
+        }
+        if !header_lines.is_empty() {
+            content.push_str(&format!(
+                "#align(center)[{}]\n",
+                header_lines.join(" \\\n  ")
+            ));
+        }
+
+        if !profile.summary.is_empty() {
+            content.push_str(&format!(
+                "\n== {}\n\n",
+                typst::escape(self.heading("summary"))
+            ));
+            content.push_str(&typst_paragraphs(&profile.summary));
+        }
+
+        for section in self.section_order() {
+            if !self.section_enabled(&section) {
+                continue;
+            }
+            match section.as_str() {
+                "experiences" => self.typst_experiences(resume, &mut content),
+                "education" => self.typst_education(resume, &mut content),
+                "skills" => {
+                    typst_skills(resume, self.locale(), self.heading("skills"), &mut content)
+                }
+                "projects" => self.typst_projects(resume, &mut content),
+                "certifications" => self.typst_certifications(resume, &mut content),
+                "languages" => typst_languages(
+                    resume,
+                    self.locale(),
+                    self.heading("languages"),
+                    &mut content,
+                ),
+                "publications" => self.typst_publications(resume, &mut content),
+                "volunteer" => self.typst_volunteer(resume, &mut content),
+                other => warnings.push(format!("Unknown section '{}' in section order", other)),
+            }
+        }
+
+        Ok(content)
+    }
+
+    fn typst_experiences(&self, resume: &Resume, content: &mut String) {
+        if resume.experiences.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n== {}\n",
+            typst::escape(self.heading("experiences"))
+        ));
+        for exp in &resume.experiences {
+            let dates = self.format_date_range(exp.start_date, exp.end_date, exp.current);
+            content.push_str(&typst_entry(&exp.title, &exp.company, &dates));
+            if let Some(location) = &exp.location {
+                content.push_str(&typst_detail(&typst::escape(location)));
+            }
+            if !exp.description.is_empty() {
+                content.push_str(&typst_paragraphs(&exp.description));
+            }
+            content.push_str(&typst_list(&exp.achievements));
+            if !exp.technologies.is_empty() {
+                content.push_str(&typst_labeled(
+                    self.locale().text("Technologies"),
+                    &exp.technologies,
+                ));
+            }
+        }
+    }
+
+    fn typst_education(&self, resume: &Resume, content: &mut String) {
+        if resume.education.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n== {}\n",
+            typst::escape(self.heading("education"))
+        ));
+        for edu in &resume.education {
+            let dates = self.format_date_range(edu.start_date, edu.end_date, edu.current);
+            let degree = self
+                .locale()
+                .fill("{0} in {1}", &[&edu.degree, &edu.field_of_study]);
+            content.push_str(&typst_entry(&degree, &edu.institution, &dates));
+            if let Some(gpa) = edu.gpa_text() {
+                content.push_str(&typst_detail(&format!(
+                    "{}: {}",
+                    typst::escape(self.locale().text("GPA")),
+                    gpa
+                )));
+            }
+            if !edu.description.is_empty() {
+                content.push_str(&typst_paragraphs(&edu.description));
+            }
+            if !edu.courses.is_empty() {
+                content.push_str(&typst_labeled(
+                    self.locale().text("Relevant Courses"),
+                    &edu.courses,
+                ));
+            }
+            content.push_str(&typst_list(&edu.achievements));
+        }
+    }
+
+    fn typst_projects(&self, resume: &Resume, content: &mut String) {
+        if resume.projects.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n== {}\n",
+            typst::escape(self.heading("projects"))
+        ));
+        for project in &resume.projects {
+            let dates = self.format_date_range(project.start_date, project.end_date, false);
+            content.push_str(&typst_entry(&project.name, "", &dates));
+            if !project.description.is_empty() {
+                content.push_str(&typst_paragraphs(&project.description));
+            }
+            let links: Vec<String> = [&project.url, &project.github]
+                .into_iter()
+                .flatten()
+                .map(|url| typst_link(url))
+                .collect();
+            if !links.is_empty() {
+                content.push_str(&typst_detail(&links.join(" | ")));
+            }
+            if !project.technologies.is_empty() {
+                content.push_str(&typst_labeled(
+                    self.locale().text("Technologies"),
+                    &project.technologies,
+                ));
+            }
+            content.push_str(&typst_list(&project.highlights));
+        }
+    }
+
+    fn typst_certifications(&self, resume: &Resume, content: &mut String) {
+        if resume.certifications.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n== {}\n",
+            typst::escape(self.heading("certifications"))
+        ));
+        for cert in &resume.certifications {
+            let dates = self.certification_dates(cert);
+            content.push_str(&typst_entry(&cert.name, &cert.issuer, &dates));
+            if let Some(id) = &cert.credential_id {
+                content.push_str(&typst_detail(&format!(
+                    "{}: {}",
+                    typst::escape(self.locale().text("Credential ID")),
+                    typst::escape(id)
+                )));
+            }
+            if let Some(url) = &cert.url {
+                content.push_str(&typst_detail(&typst_link(url)));
+            }
+        }
+    }
+
+    fn typst_publications(&self, resume: &Resume, content: &mut String) {
+        if resume.publications.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n== {}\n",
+            typst::escape(self.heading("publications"))
+        ));
+        for publication in &resume.publications {
+            let date = publication
+                .published_date
+                .map(|date| self.format_date(date))
+                .unwrap_or_default();
+            content.push_str(&typst_entry(
+                &publication.title,
+                &publication.publisher,
+                &date,
+            ));
+            if !publication.authors.is_empty() {
+                content.push_str(&typst_detail(&typst::escape(
+                    &publication.authors.join(", "),
+                )));
+            }
+            if let Some(url) = &publication.url {
+                content.push_str(&typst_detail(&typst_link(url)));
+            }
+            if !publication.description.is_empty() {
+                content.push_str(&typst_paragraphs(&publication.description));
+            }
+        }
+    }
+
+    fn typst_volunteer(&self, resume: &Resume, content: &mut String) {
+        if resume.volunteer.is_empty() {
+            return;
+        }
+
+        content.push_str(&format!(
+            "\n== {}\n",
+            typst::escape(self.heading("volunteer"))
+        ));
+        for volunteer in &resume.volunteer {
+            let dates =
+                self.format_date_range(volunteer.start_date, volunteer.end_date, volunteer.current);
+            content.push_str(&typst_entry(
+                &volunteer.role,
+                &volunteer.organization,
+                &dates,
+            ));
+            if let Some(location) = &volunteer.location {
+                content.push_str(&typst_detail(&typst::escape(location)));
+            }
+            if !volunteer.description.is_empty() {
+                content.push_str(&typst_paragraphs(&volunteer.description));
+            }
+        }
+    }
+
+    /// Returns the document part and the packaged .docx file.
+    fn format_docx(
+        &self,
+        resume: &Resume,
+        warnings: &mut Vec<String>,
+    ) -> Result<(String, Vec<u8>), String> {
+        let markdown = self.format_markdown(resume, warnings)?;
+        let writer = DocxWriter::new();
+        let document = writer.document_xml(&markdown);
+        let package = writer.package(&document)?;
+        Ok((document, package))
+    }
+
+    /// "Issued May 2021, expires May 2024", or whichever half is known.
+    fn certification_dates(&self, cert: &Certification) -> String {
+        let locale = self.locale();
+        match (cert.date_obtained, cert.expiry_date) {
+            (Some(obtained), Some(expiry)) => locale.fill(
+                "Issued {0}, expires {1}",
+                &[&self.format_date(obtained), &self.format_date(expiry)],
+            ),
+            (Some(obtained), None) => locale.fill("Issued {0}", &[&self.format_date(obtained)]),
+            (None, Some(expiry)) => locale.fill("Expires {0}", &[&self.format_date(expiry)]),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn format_date_range(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        current: bool,
+    ) -> String {
+        let start_str = match start {
+            Some(date) => self.format_date(date),
+            None => "".to_string(),
+        };
+
+        let end_str = if current {
+            self.locale().text("Present").to_string()
+        } else {
+            match end {
+                Some(date) => self.format_date(date),
+                None => "".to_string(),
+            }
+        };
+
+        self.join_date_range(&start_str, &end_str)
+    }
+
+    /// `format_date_range` with each date in a `<time>` element, marked up
+    /// as the `dt-start` and `dt-end` of the enclosing h-event.
+    fn html_date_range(
+        &self,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        current: bool,
+    ) -> String {
+        let time = |class: &str, date: Option<NaiveDate>| match date {
+            Some(date) => format!(
+                "<time class=\"{}\" datetime=\"{}\">{}</time>",
+                class,
+                iso_month(date),
+                escape_html(&self.format_date(date))
+            ),
+            None => "".to_string(),
+        };
+
+        let end_str = if current {
+            escape_html(self.locale().text("Present"))
+        } else {
+            time("dt-end", end)
+        };
+
+        self.join_date_range(&time("dt-start", start), &end_str)
+    }
+
+    fn join_date_range(&self, start: &str, end: &str) -> String {
+        if start.is_empty() && end.is_empty() {
+            "".to_string()
+        } else if start.is_empty() {
+            self.locale().fill("Until {0}", &[end])
+        } else if end.is_empty() {
+            self.locale().fill("From {0}", &[start])
+        } else {
+            format!("{} - {}", start, end)
+        }
+    }
+}
+
+/// The warnings every text format gives for a resume missing its core
+/// sections.
+fn warn_about_missing_sections(resume: &Resume, warnings: &mut Vec<String>) {
+    if resume.experiences.is_empty() {
+        warnings.push("Resume doesn't have any work experiences".to_string());
+    }
+
+    if resume.education.is_empty() {
+        warnings.push("Resume doesn't have any education entries".to_string());
+    }
+
+    if resume.skills.technical.is_empty() {
+        warnings.push("Resume doesn't have any technical skills".to_string());
+    }
+}
+
+fn markdown_languages(resume: &Resume, locale: &Locale, heading: &str, content: &mut String) {
+    if resume.languages.is_empty() {
+        return;
+    }
+
+    content.push_str(&format!("## {}\n\n", heading));
+    for language in &resume.languages {
+        content.push_str(&format!(
+            "- {} ({})\n",
+            language.name,
+            locale.text(language.proficiency.label())
+        ));
+    }
+    content.push('\n');
+}
+
+/// Each skill is a `p-skill` of the h-resume, grouped under its category.
+fn html_skills(resume: &Resume, locale: &Locale, heading: &str, content: &mut String) {
+    let categories = resume.skills.categories();
+    if categories.iter().all(|(_, list)| list.is_empty()) {
+        return;
+    }
+
+    content.push_str(&format!("<h2>{}</h2>\n", escape_html(heading)));
+    for (label, list) in categories {
+        if list.is_empty() {
+            continue;
+        }
+        let names: Vec<String> = list
+            .iter()
+            .map(|skill| {
+                format!(
+                    "<span class=\"p-skill\">{}</span>",
+                    escape_html(&skill.name)
+                )
+            })
+            .collect();
+        content.push_str(&format!(
+            "<h3>{}</h3>\n<p>{}</p>\n",
+            escape_html(locale.text(label)),
+            names.join(", ")
+        ));
+    }
+}
+
+fn html_languages(resume: &Resume, locale: &Locale, heading: &str, content: &mut String) {
+    if resume.languages.is_empty() {
+        return;
+    }
+
+    content.push_str(&format!("<h2>{}</h2>\n<ul>\n", escape_html(heading)));
+    for language in &resume.languages {
+        content.push_str(&format!(
+            "<li>{} ({})</li>\n",
+            escape_html(&language.name),
+            escape_html(locale.text(language.proficiency.label()))
+        ));
+    }
+    content.push_str("</ul>\n");
+}
+
+/// A link showing `text`, with `class` when it's not empty.
+fn html_link(class: &str, url: &str, text: &str) -> String {
+    let class = if class.is_empty() {
+        String::new()
+    } else {
+        format!(" class=\"{}\"", class)
+    };
+    format!(
+        "<a{} href=\"{}\">{}</a>",
+        class,
+        escape_html(url),
+        escape_html(text)
+    )
+}
+
+/// The date line of an entry; `dates` must already be escaped.
+fn html_dates(dates: &str) -> String {
+    format!("<p><em>{}</em></p>\n", dates)
+}
+
+/// A labeled line of an entry; `text` must already be escaped.
+fn html_detail(label: &str, text: &str) -> String {
+    format!("<p><strong>{}:</strong> {}</p>\n", escape_html(label), text)
+}
+
+fn html_labeled(label: &str, items: &[String]) -> String {
+    html_detail(label, &escape_html(&items.join(", ")))
+}
+
+/// Escapes text and keeps its blank-line paragraph breaks.
+fn html_paragraphs(text: &str) -> String {
+    text.split("\n\n")
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| format!("<p>{}</p>\n", escape_html(paragraph)))
+        .collect()
+}
+
+fn html_list(label: &str, items: &[String]) -> String {
+    let mut list = format!("<p><strong>{}:</strong></p>\n<ul>\n", escape_html(label));
+    for item in items {
+        list.push_str(&format!("<li>{}</li>\n", escape_html(item)));
+    }
+    list.push_str("</ul>\n");
+    list
+}
+
+fn latex_skills(resume: &Resume, locale: &Locale, heading: &str, content: &mut String) {
+    let categories = resume.skills.categories();
+    if categories.iter().all(|(_, list)| list.is_empty()) {
+        return;
+    }
+
+    content.push_str(&format!("\n\\section*{{{}}}\n", latex::escape(heading)));
+    for (label, list) in categories {
+        if list.is_empty() {
+            continue;
+        }
+        let names: Vec<&str> = list.iter().map(|skill| skill.name.as_str()).collect();
+        content.push_str(&format!(
+            "\\textbf{{{}:}} {}\\par\n",
+            latex::escape(locale.text(label)),
+            latex::escape(&names.join(", "))
+        ));
+    }
+}
+
+fn latex_languages(resume: &Resume, locale: &Locale, heading: &str, content: &mut String) {
+    if resume.languages.is_empty() {
+        return;
+    }
+
+    content.push_str(&format!("\n\\section*{{{}}}\n", latex::escape(heading)));
+    let languages: Vec<String> = resume
+        .languages
+        .iter()
+        .map(|language| {
+            format!(
+                "{} ({})",
+                latex::escape(&language.name),
+                latex::escape(locale.text(language.proficiency.label()))
+            )
+        })
+        .collect();
+    content.push_str(&languages.join(", "));
+    content.push_str("\\par\n");
+}
+
+fn latex_entry(title: &str, organization: &str, dates: &str) -> String {
+    format!(
+        "\\resumeentry{{{}}}{{{}}}{{{}}}\n",
+        latex::escape(title),
+        latex::escape(organization),
+        latex::escape(dates)
+    )
+}
+
+fn latex_link(url: &str) -> String {
+    format!(
+        "\\href{{{}}}{{{}}}",
+        latex::escape_url(url),
+        latex::escape(url)
+    )
+}
+
+/// A secondary line of an entry; `text` must already be escaped.
+fn latex_detail(text: &str) -> String {
+    format!("\\resumedetail{{{}}}\n", text)
+}
+
+fn latex_labeled(label: &str, items: &[String]) -> String {
+    format!(
+        "\\textit{{{}:}} {}\\par\n",
+        latex::escape(label),
+        latex::escape(&items.join(", "))
+    )
+}
+
+/// Escapes text and keeps its blank-line paragraph breaks.
+fn latex_paragraphs(text: &str) -> String {
+    let paragraphs: Vec<String> = text
+        .split("\n\n")
+        .map(|paragraph| latex::escape(paragraph.trim()))
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect();
+    format!("{}\\par\n", paragraphs.join("\\par\n"))
+}
+
+fn latex_list(items: &[String]) -> String {
+    if items.is_empty() {
+        return String::new();
+    }
+    let mut list = String::from("\\begin{itemize}\n");
+    for item in items {
+        list.push_str(&format!("  \\item {}\n", latex::escape(item)));
+    }
+    list.push_str("\\end{itemize}\n");
+    list
+}
+
+fn typst_skills(resume: &Resume, locale: &Locale, heading: &str, content: &mut String) {
+    let categories = resume.skills.categories();
+    if categories.iter().all(|(_, list)| list.is_empty()) {
+        return;
+    }
+
+    content.push_str(&format!("\n== {}\n\n", typst::escape(heading)));
+    for (label, list) in categories {
+        if list.is_empty() {
+            continue;
+        }
+        let names: Vec<&str> = list.iter().map(|skill| skill.name.as_str()).collect();
+        content.push_str(&format!(
+            "*{}:* {}\n\n",
+            typst::escape(locale.text(label)),
+            typst::escape(&names.join(", "))
+        ));
+    }
+}
+
+fn typst_languages(resume: &Resume, locale: &Locale, heading: &str, content: &mut String) {
+    if resume.languages.is_empty() {
+        return;
+    }
+
+    content.push_str(&format!("\n== {}\n\n", typst::escape(heading)));
+    let languages: Vec<String> = resume
+        .languages
+        .iter()
+        .map(|language| {
+            format!(
+                "{} ({})",
+                typst::escape(&language.name),
+                typst::escape(locale.text(language.proficiency.label()))
+            )
+        })
+        .collect();
+    content.push_str(&languages.join(", "));
+    content.push_str("\n\n");
+}
+
+fn typst_entry(title: &str, organization: &str, dates: &str) -> String {
+    format!(
+        "#entry[{}][{}][{}]\n",
+        typst::escape(title),
+        typst::escape(organization),
+        typst::escape(dates)
+    )
+}
+
+fn typst_link(url: &str) -> String {
+    format!(
+        "#link(\"{}\")[{}]",
+        typst::escape_string(url),
+        typst::escape(url)
+    )
+}
+
+/// A secondary line of an entry; `text` must already be escaped.
+fn typst_detail(text: &str) -> String {
+    format!("#detail[{}]\n", text)
+}
+
+fn typst_labeled(label: &str, items: &[String]) -> String {
+    format!(
+        "_{}:_ {}\n\n",
+        typst::escape(label),
+        typst::escape(&items.join(", "))
+    )
+}
+
+/// Escapes text and keeps its blank-line paragraph breaks.
+fn typst_paragraphs(text: &str) -> String {
+    let paragraphs: Vec<String> = text
+        .split("\n\n")
+        .map(|paragraph| typst::escape(paragraph.trim()))
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect();
+    format!("{}\n\n", paragraphs.join("\n\n"))
+}
+
+fn typst_list(items: &[String]) -> String {
+    if items.is_empty() {
+        return String::new();
+    }
+    let mut list = String::new();
+    for item in items {
+        list.push_str(&format!("- {}\n", typst::escape(item)));
     }
+    list.push('\n');
+    list
 }
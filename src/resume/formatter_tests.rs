@@ -1,11 +1,13 @@
 #[cfg(test)]
 mod tests {
-    use super::*;
     use crate::resume::formatter::{FormattingOptions, OutputFormat, ResumeFormatter};
     use crate::resume::model::{
-        Education, Experience, Location, Profile, Project, Resume, Skill, Skills,
+        Certification, Education, Experience, ExperienceTranslation, Language, LanguageProficiency,
+        Location, Profile, ProfileTranslation, Project, Publication, Resume, ResumeVariant, Skill,
+        Volunteer,
     };
     use chrono::NaiveDate;
+    use rstest::rstest;
     use std::collections::HashMap;
 
     fn create_test_resume() -> Resume {
@@ -22,6 +24,7 @@ mod tests {
             github: "https://github.com/janesmith".to_string(),
             website: "https://janesmith.dev".to_string(),
             summary: "Experienced software engineer with a focus on cloud technologies and distributed systems.".to_string(),
+            ..Profile::default()
         });
 
         // Add skills
@@ -29,38 +32,45 @@ mod tests {
             name: "Rust".to_string(),
             level: Some("Advanced".to_string()),
             years: Some(4),
+            tags: vec![],
         });
         resume.skills.technical.push(Skill {
             name: "TypeScript".to_string(),
             level: Some("Advanced".to_string()),
             years: Some(5),
+            tags: vec![],
         });
         resume.skills.technical.push(Skill {
             name: "Kubernetes".to_string(),
             level: Some("Intermediate".to_string()),
             years: Some(3),
+            tags: vec![],
         });
 
         resume.skills.soft.push(Skill {
             name: "Leadership".to_string(),
             level: None,
             years: None,
+            tags: vec![],
         });
         resume.skills.soft.push(Skill {
             name: "Communication".to_string(),
             level: None,
             years: None,
+            tags: vec![],
         });
 
         resume.skills.tools.push(Skill {
             name: "Docker".to_string(),
             level: Some("Advanced".to_string()),
             years: Some(4),
+            tags: vec![],
         });
         resume.skills.tools.push(Skill {
             name: "Git".to_string(),
             level: Some("Advanced".to_string()),
             years: Some(7),
+            tags: vec![],
         });
 
         // Add experience
@@ -84,6 +94,9 @@ mod tests {
                 "Kubernetes".to_string(),
                 "AWS".to_string(),
             ],
+            tags: vec![],
+            achievement_tags: HashMap::new(),
+            translations: HashMap::new(),
         });
 
         resume.experiences.push(Experience {
@@ -104,6 +117,9 @@ mod tests {
                 "React".to_string(),
                 "Node.js".to_string(),
             ],
+            tags: vec![],
+            achievement_tags: HashMap::new(),
+            translations: HashMap::new(),
         });
 
         // Add education
@@ -116,6 +132,7 @@ mod tests {
             end_date: Some(NaiveDate::from_ymd_opt(2016, 5, 31).unwrap()),
             current: false,
             gpa: Some(3.9),
+            gpa_scale: None,
             courses: vec![
                 "Distributed Systems".to_string(),
                 "Advanced Algorithms".to_string(),
@@ -123,6 +140,7 @@ mod tests {
             ],
             achievements: vec!["Graduated with honors".to_string()],
             description: "Focused on distributed systems and cloud computing.".to_string(),
+            translations: HashMap::new(),
         });
 
         // Add projects
@@ -139,6 +157,8 @@ mod tests {
                 "Achieved 99.99% uptime".to_string(),
                 "Handles 10,000 requests per second".to_string(),
             ],
+            tags: vec![],
+            translations: HashMap::new(),
         });
 
         resume
@@ -154,8 +174,10 @@ mod tests {
 
     #[test]
     fn test_formatter_with_options() {
-        let mut options = FormattingOptions::default();
-        options.include_contact_info = false;
+        let options = FormattingOptions {
+            include_contact_info: false,
+            ..FormattingOptions::default()
+        };
 
         let formatter = ResumeFormatter::with_options(options);
         let resume = create_test_resume();
@@ -283,10 +305,39 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_loaded_template_is_rejected_for_markdown_layouts() {
+        let mut formatter = ResumeFormatter::with_options(FormattingOptions {
+            template: "custom".to_string(),
+            ..FormattingOptions::default()
+        });
+        formatter
+            .load_template_from_file("custom", std::path::Path::new("src/templates/classic.md"))
+            .unwrap();
+        let resume = create_test_resume();
+
+        for format in [
+            OutputFormat::Markdown,
+            OutputFormat::PlainText,
+            OutputFormat::DOCX,
+        ] {
+            assert_eq!(
+                formatter.format(&resume, format).unwrap_err(),
+                format!(
+                    "Template 'custom' was loaded from a file, which {:?} output doesn't support yet",
+                    format
+                )
+            );
+        }
+        assert!(formatter.format(&resume, OutputFormat::HTML).is_ok());
+    }
+
     #[test]
     fn test_formatting_options() {
-        let mut options = FormattingOptions::default();
-        options.include_contact_info = false;
+        let options = FormattingOptions {
+            include_contact_info: false,
+            ..FormattingOptions::default()
+        };
 
         let formatter = ResumeFormatter::with_options(options);
         let resume = create_test_resume();
@@ -294,4 +345,682 @@ mod tests {
 
         assert!(!result.content.contains(&resume.profile.email));
     }
+
+    #[test]
+    fn test_unknown_section_in_variant_order() {
+        let mut resume = create_test_resume();
+        resume.metadata.variants.insert(
+            "hobbyist".to_string(),
+            ResumeVariant {
+                section_order: vec!["hobbies".to_string()],
+                ..ResumeVariant::default()
+            },
+        );
+
+        let result = ResumeFormatter::new()
+            .format_variant(&resume, "hobbyist", OutputFormat::Markdown)
+            .unwrap();
+        assert!(result
+            .warnings
+            .contains(&"Unknown section 'hobbies' in section order".to_string()));
+        // Sections the variant leaves out keep their default order
+        let position = |heading: &str| result.content.find(heading).unwrap();
+        assert!(position("## Work Experience") < position("## Education"));
+        assert!(position("## Skills") < position("## Projects"));
+    }
+
+    #[test]
+    fn test_section_order() {
+        let options = FormattingOptions {
+            section_order: vec!["skills".to_string(), "experiences".to_string()],
+            ..FormattingOptions::default()
+        };
+
+        let formatter = ResumeFormatter::with_options(options);
+        let result = formatter
+            .format(&create_test_resume(), OutputFormat::Markdown)
+            .unwrap();
+
+        let position = |heading: &str| result.content.find(heading).unwrap();
+        assert!(position("## Skills") < position("## Work Experience"));
+        assert!(position("## Work Experience") < position("## Education"));
+        assert!(position("## Education") < position("## Projects"));
+    }
+
+    #[test]
+    fn test_variant_order_overrides_options() {
+        let mut resume = create_test_resume();
+        resume.metadata.variants.insert(
+            "graduate".to_string(),
+            ResumeVariant {
+                section_order: vec!["education".to_string()],
+                ..ResumeVariant::default()
+            },
+        );
+        let formatter = ResumeFormatter::with_options(FormattingOptions {
+            section_order: vec!["skills".to_string()],
+            ..FormattingOptions::default()
+        });
+
+        let result = formatter
+            .format_variant(&resume, "graduate", OutputFormat::Markdown)
+            .unwrap();
+        let position = |heading: &str| result.content.find(heading).unwrap();
+        assert!(position("## Education") < position("## Work Experience"));
+        assert!(position("## Work Experience") < position("## Skills"));
+    }
+
+    fn research_options() -> FormattingOptions {
+        let mut options = FormattingOptions {
+            section_order: vec!["publications".to_string(), "education".to_string()],
+            ..FormattingOptions::default()
+        };
+        options.section_headings.insert(
+            "experiences".to_string(),
+            "Professional Experience".to_string(),
+        );
+        options.section_headings.insert(
+            "publications".to_string(),
+            "Research & Publications".to_string(),
+        );
+        options
+    }
+
+    #[rstest]
+    fn test_section_order_and_headings(
+        #[values(
+            OutputFormat::Markdown,
+            OutputFormat::PlainText,
+            OutputFormat::HTML,
+            OutputFormat::DOCX,
+            OutputFormat::LaTeX,
+            OutputFormat::Typst
+        )]
+        format: OutputFormat,
+    ) {
+        let mut resume = create_test_resume();
+        add_other_sections(&mut resume);
+
+        let content = ResumeFormatter::with_options(research_options())
+            .format(&resume, format)
+            .unwrap()
+            .content;
+
+        let position = |text: &str| content.find(text).unwrap();
+        assert!(position("Research") < position("Master of Science"));
+        assert!(position("Master of Science") < position("Professional Experience"));
+        assert!(!content.contains("Work Experience"));
+    }
+
+    #[test]
+    fn test_section_headings_are_escaped() {
+        let mut resume = create_test_resume();
+        add_other_sections(&mut resume);
+        let formatter = ResumeFormatter::with_options(research_options());
+
+        let markdown = formatter.format(&resume, OutputFormat::Markdown).unwrap();
+        assert!(markdown.content.contains("## Research & Publications\n\n"));
+        let latex = formatter.format(&resume, OutputFormat::LaTeX).unwrap();
+        assert!(latex
+            .content
+            .contains("\\section*{Research \\& Publications}\n"));
+        let typst = formatter.format(&resume, OutputFormat::Typst).unwrap();
+        assert!(typst.content.contains("\n== Research & Publications\n"));
+    }
+
+    #[test]
+    fn test_rename_summary_and_contact() {
+        let mut options = FormattingOptions::default();
+        options
+            .section_headings
+            .insert("summary".to_string(), "Profile".to_string());
+        options
+            .section_headings
+            .insert("contact".to_string(), "Contact".to_string());
+
+        let content = ResumeFormatter::with_options(options)
+            .format(&create_test_resume(), OutputFormat::Markdown)
+            .unwrap()
+            .content;
+
+        assert!(content.contains("## Profile\n\n"));
+        assert!(content.contains("## Contact\n\n"));
+        assert!(!content.contains("## Professional Summary"));
+    }
+
+    #[test]
+    fn test_format_variant() {
+        let mut resume = create_test_resume();
+        resume.experiences[1].tags = vec!["frontend".to_string()];
+        resume.metadata.variants.insert(
+            "backend".to_string(),
+            ResumeVariant {
+                exclude_tags: vec!["frontend".to_string()],
+                max_bullets: Some(1),
+                section_order: vec!["education".to_string()],
+                ..ResumeVariant::default()
+            },
+        );
+
+        let formatter = ResumeFormatter::new();
+        let result = formatter
+            .format_variant(&resume, "backend", OutputFormat::Markdown)
+            .unwrap();
+
+        assert!(!result.content.contains("Web Solutions Ltd."));
+        assert!(result.content.contains("Cloud Systems Inc."));
+        assert!(result.content.find("## Education") < result.content.find("## Work Experience"));
+        assert!(formatter
+            .format_variant(&resume, "sales", OutputFormat::Markdown)
+            .is_err());
+    }
+
+    fn add_other_sections(resume: &mut Resume) {
+        resume.certifications.push(Certification {
+            name: "Certified Kubernetes Administrator".to_string(),
+            issuer: "CNCF".to_string(),
+            date_obtained: NaiveDate::from_ymd_opt(2021, 5, 1),
+            expiry_date: NaiveDate::from_ymd_opt(2024, 5, 1),
+            credential_id: Some("LF-12345".to_string()),
+            url: Some("https://verify.cncf.io/LF-12345".to_string()),
+        });
+        resume.languages.push(Language {
+            name: "Portuguese".to_string(),
+            proficiency: LanguageProficiency::FullProfessional,
+        });
+        resume.publications.push(Publication {
+            title: "Consensus at Scale".to_string(),
+            publisher: "ACM Queue".to_string(),
+            published_date: NaiveDate::from_ymd_opt(2022, 7, 1),
+            authors: vec!["Jane Smith".to_string(), "Alan Jones".to_string()],
+            url: Some("https://queue.acm.org/consensus".to_string()),
+            description: "Survey of consensus protocols.".to_string(),
+            translations: HashMap::new(),
+        });
+        resume.volunteer.push(Volunteer {
+            organization: "Code Club".to_string(),
+            role: "Mentor".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2019, 1, 1),
+            end_date: None,
+            current: true,
+            description: "Teaches kids to code.".to_string(),
+            location: Some("Brooklyn".to_string()),
+            translations: HashMap::new(),
+        });
+    }
+
+    #[test]
+    fn test_render_other_sections() {
+        let mut resume = create_test_resume();
+        add_other_sections(&mut resume);
+
+        let content = ResumeFormatter::new()
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap()
+            .content;
+
+        assert!(content.contains(
+            "## Certifications\n\n### Certified Kubernetes Administrator\n**CNCF**\n\
+             _Issued May 2021, expires May 2024_\n\n\
+             **Credential ID:** LF-12345\n\n\
+             **Credential:** [Verify Credential](https://verify.cncf.io/LF-12345)\n\n"
+        ));
+        assert!(content.contains("## Languages\n\n- Portuguese (Full professional)\n\n"));
+        assert!(content.contains(
+            "## Publications\n\n### Consensus at Scale\n**ACM Queue**\n_July 2022_\n\n\
+             **Authors:** Jane Smith, Alan Jones\n\n\
+             **Link:** [Publication Link](https://queue.acm.org/consensus)\n\n\
+             Survey of consensus protocols.\n\n"
+        ));
+        assert!(content.contains(
+            "## Volunteer Experience\n\n### Mentor at Code Club\n_January 2019 - Present_\n\n\
+             **Location:** Brooklyn\n\nTeaches kids to code.\n\n"
+        ));
+    }
+
+    #[test]
+    fn test_render_other_skills() {
+        let mut resume = create_test_resume();
+        resume.skills.other = ["Public speaking", "Woodworking"]
+            .iter()
+            .map(|name| Skill {
+                name: name.to_string(),
+                level: None,
+                years: None,
+                tags: vec![],
+            })
+            .collect();
+
+        let content = ResumeFormatter::new()
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap()
+            .content;
+        assert!(content.contains("### Other\n\nPublic speaking, Woodworking\n\n"));
+
+        let german = ResumeFormatter::with_options(FormattingOptions::for_locale("de").unwrap())
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap()
+            .content;
+        assert!(german.contains("### Sonstiges\n\nPublic speaking, Woodworking\n\n"));
+    }
+
+    #[test]
+    fn test_certification_dates() {
+        let mut resume = create_test_resume();
+        add_other_sections(&mut resume);
+        resume.certifications[0].expiry_date = None;
+        resume.certifications.push(Certification {
+            name: "AWS Solutions Architect".to_string(),
+            issuer: "".to_string(),
+            date_obtained: None,
+            expiry_date: NaiveDate::from_ymd_opt(2026, 1, 1),
+            credential_id: None,
+            url: None,
+        });
+
+        let content = ResumeFormatter::new()
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap()
+            .content;
+
+        assert!(content.contains("**CNCF**\n_Issued May 2021_\n"));
+        assert!(content.contains("### AWS Solutions Architect\n_Expires January 2026_\n"));
+    }
+
+    #[rstest]
+    fn test_section_toggle_hides_section(
+        #[values(
+            ("certifications", "Certified Kubernetes Administrator"),
+            ("languages", "Portuguese"),
+            ("publications", "Consensus at Scale"),
+            ("volunteer", "Code Club")
+        )]
+        section: (&str, &str),
+        #[values(
+            OutputFormat::Markdown,
+            OutputFormat::PlainText,
+            OutputFormat::HTML,
+            OutputFormat::DOCX,
+            OutputFormat::LaTeX,
+            OutputFormat::Typst
+        )]
+        format: OutputFormat,
+    ) {
+        let (name, text) = section;
+        let mut resume = create_test_resume();
+        add_other_sections(&mut resume);
+
+        let shown = ResumeFormatter::new().format(&resume, format).unwrap();
+        assert!(shown.content.contains(text));
+
+        let mut options = FormattingOptions::default();
+        options.section_options.insert(name.to_string(), false);
+        let hidden = ResumeFormatter::with_options(options)
+            .format(&resume, format)
+            .unwrap();
+        assert!(!hidden.content.contains(text));
+    }
+
+    #[test]
+    fn test_localized_markdown() {
+        let options = FormattingOptions::for_locale("de").unwrap();
+        let content = ResumeFormatter::with_options(options)
+            .format(&create_test_resume(), OutputFormat::Markdown)
+            .unwrap()
+            .content;
+
+        assert!(content.contains("## Kontakt\n\n- E-Mail: jane@example.com\n"));
+        assert!(content.contains("## Berufserfahrung\n\n"));
+        assert!(content.contains(
+            "### Senior Software Engineer bei Cloud Systems Inc.\n_März 2019 - heute_\n\n\
+             **Ort:** New York, NY\n\n"
+        ));
+        assert!(content.contains("**Wichtigste Erfolge:**\n\n"));
+        assert!(content.contains("_Mai 2016 - Februar 2019_"));
+        assert!(content.contains("### Master of Science in Computer Science\n"));
+        assert!(content.contains("### Fachkenntnisse\n\n"));
+        assert!(!content.contains("Work Experience"));
+    }
+
+    #[rstest]
+    #[case(
+        "fr",
+        "_mars 2019 - aujourd'hui_",
+        "Obtenu en mai 2021, expire en mai 2024"
+    )]
+    #[case(
+        "es",
+        "_marzo de 2019 - actualidad_",
+        "Emitido en mayo de 2021, vence en mayo de 2024"
+    )]
+    #[case("zh", "_2019年3月 - 至今_", "2021年5月获得，2024年5月到期")]
+    fn test_localized_dates(#[case] locale: &str, #[case] range: &str, #[case] issued: &str) {
+        let mut resume = create_test_resume();
+        add_other_sections(&mut resume);
+
+        let content = ResumeFormatter::with_options(FormattingOptions::for_locale(locale).unwrap())
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap()
+            .content;
+
+        assert!(content.contains(range));
+        assert!(content.contains(issued));
+    }
+
+    #[test]
+    fn test_localized_open_ranges() {
+        let mut resume = create_test_resume();
+        resume.experiences[0].current = false;
+        resume.experiences[1].start_date = None;
+
+        let content = ResumeFormatter::with_options(FormattingOptions::for_locale("de").unwrap())
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap()
+            .content;
+
+        assert!(content.contains("_Seit März 2019_"));
+        assert!(content.contains("_Bis Februar 2019_"));
+    }
+
+    #[test]
+    fn test_heading_override_wins_over_locale() {
+        let mut options = FormattingOptions::for_locale("de").unwrap();
+        options
+            .section_headings
+            .insert("experiences".to_string(), "Werdegang".to_string());
+
+        let content = ResumeFormatter::with_options(options)
+            .format(&create_test_resume(), OutputFormat::Markdown)
+            .unwrap()
+            .content;
+        assert!(content.contains("## Werdegang\n\n"));
+        assert!(content.contains("## Ausbildung\n\n"));
+    }
+
+    #[test]
+    fn test_localized_documents() {
+        let mut resume = create_test_resume();
+        add_other_sections(&mut resume);
+        let formatter = ResumeFormatter::with_options(FormattingOptions::for_locale("fr").unwrap());
+
+        let html = formatter
+            .format(&resume, OutputFormat::HTML)
+            .unwrap()
+            .content;
+        assert!(html.contains("<html lang=\"fr\">"));
+        assert!(html.contains("<title>CV de Jane Smith</title>"));
+
+        let latex = formatter
+            .format(&resume, OutputFormat::LaTeX)
+            .unwrap()
+            .content;
+        assert!(latex.contains("\\section*{Expérience professionnelle}\n"));
+        assert!(latex.contains("Portuguese (Courant)"));
+
+        let typst = formatter
+            .format(&resume, OutputFormat::Typst)
+            .unwrap()
+            .content;
+        assert!(typst.contains("#set document(title: \"Jane Smith - CV\""));
+        assert!(typst.contains("#set text(lang: \"fr\")\n"));
+        assert!(typst.contains("*Compétences techniques:* Rust"));
+    }
+
+    #[test]
+    fn test_unsupported_locale() {
+        assert_eq!(
+            FormattingOptions::for_locale("ja").unwrap_err(),
+            "Locale 'ja' not supported"
+        );
+
+        let options = FormattingOptions {
+            locale: "ja".to_string(),
+            ..FormattingOptions::default()
+        };
+        let result = ResumeFormatter::with_options(options)
+            .format(&create_test_resume(), OutputFormat::Markdown);
+        assert_eq!(result.unwrap_err(), "Locale 'ja' not supported");
+    }
+
+    fn add_german_translation(resume: &mut Resume) {
+        resume.profile.translations.insert(
+            "de".to_string(),
+            ProfileTranslation {
+                title: Some("Senior-Softwareentwicklerin".to_string()),
+                summary: Some("Erfahrene Entwicklerin für verteilte Systeme.".to_string()),
+            },
+        );
+        resume.experiences[0].translations.insert(
+            "de".to_string(),
+            ExperienceTranslation {
+                title: None,
+                description: Some("Leitet die Entwicklung von Cloud-Anwendungen.".to_string()),
+                achievements: Some(vec![
+                    "Microservice-Plattform entworfen".to_string(),
+                    "Team von 5 Entwicklern geleitet".to_string(),
+                ]),
+            },
+        );
+    }
+
+    #[test]
+    fn test_format_in_content_language() {
+        let mut resume = create_test_resume();
+        add_german_translation(&mut resume);
+
+        let german = ResumeFormatter::with_options(FormattingOptions::for_locale("de").unwrap())
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap()
+            .content;
+        assert!(german.contains("## Senior-Softwareentwicklerin\n"));
+        assert!(german.contains("## Profil\n\nErfahrene Entwicklerin für verteilte Systeme.\n"));
+        assert!(german.contains("- Team von 5 Entwicklern geleitet\n"));
+        // Untranslated entries fall back to the resume's own text
+        assert!(german.contains("Developed modern web applications for enterprise clients."));
+
+        let english = ResumeFormatter::new()
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap()
+            .content;
+        assert!(english.contains("Leading development of distributed cloud-native applications."));
+        assert!(!english.contains("Cloud-Anwendungen"));
+    }
+
+    #[test]
+    fn test_format_variant_in_content_language() {
+        let mut resume = create_test_resume();
+        add_german_translation(&mut resume);
+        resume.experiences[0]
+            .achievement_tags
+            .insert(1, vec!["management".to_string()]);
+        resume.metadata.variants.insert(
+            "management".to_string(),
+            ResumeVariant {
+                include_tags: vec!["management".to_string()],
+                max_bullets: Some(1),
+                ..ResumeVariant::default()
+            },
+        );
+
+        let content = ResumeFormatter::with_options(FormattingOptions::for_locale("de").unwrap())
+            .format_variant(&resume, "management", OutputFormat::Markdown)
+            .unwrap()
+            .content;
+        assert!(content.contains("- Team von 5 Entwicklern geleitet\n"));
+        assert!(!content.contains("Microservice-Plattform"));
+    }
+
+    fn lengthen(resume: &mut Resume) {
+        add_other_sections(resume);
+        for exp in &mut resume.experiences {
+            for i in 1..=12 {
+                exp.achievements.push(format!(
+                    "Delivered improvement number {} to the {} platform, cutting support tickets and on-call load for the team",
+                    i, exp.company
+                ));
+            }
+        }
+    }
+
+    fn with_max_pages(max_pages: usize) -> ResumeFormatter {
+        ResumeFormatter::with_options(FormattingOptions {
+            max_pages: Some(max_pages),
+            ..FormattingOptions::default()
+        })
+    }
+
+    #[test]
+    fn test_estimated_pages() {
+        let resume = create_test_resume();
+        let formatter = ResumeFormatter::new();
+        let pages = |format| formatter.format(&resume, format).unwrap().pages;
+
+        let markdown = pages(OutputFormat::Markdown).unwrap();
+        assert!(markdown > 1.0 && markdown < 2.0);
+        assert!(pages(OutputFormat::PlainText).unwrap() < markdown);
+        assert!(pages(OutputFormat::LaTeX).unwrap() > 0.0);
+        assert!(pages(OutputFormat::Typst).unwrap() > 0.0);
+        assert_eq!(pages(OutputFormat::JSON), None);
+
+        let mut long = create_test_resume();
+        lengthen(&mut long);
+        let long_pages = formatter
+            .format(&long, OutputFormat::Markdown)
+            .unwrap()
+            .pages;
+        assert!(long_pages.unwrap() > 2.0);
+    }
+
+    #[test]
+    fn test_fit_to_two_pages() {
+        let mut resume = create_test_resume();
+        lengthen(&mut resume);
+
+        let result = with_max_pages(2)
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap();
+        assert!(result.pages.unwrap() <= 2.0);
+        assert_eq!(
+            result.warnings[..3],
+            [
+                "Page limit: removed course 'Machine Learning' from MIT",
+                "Page limit: removed course 'Advanced Algorithms' from MIT",
+                "Page limit: removed course 'Distributed Systems' from MIT",
+            ]
+        );
+        // The older role loses its last bullets first
+        assert_eq!(
+            result.warnings[3],
+            "Page limit: removed achievement 'Delivered improvement number 12 to the Web Solutions Ltd. platform, cutting support tickets and on-call load for the team' from Full Stack Developer at Web Solutions Ltd."
+        );
+        assert!(result
+            .warnings
+            .iter()
+            .all(|warning| warning.starts_with("Page limit: removed")
+                || warning.starts_with("Page limit: hid")));
+        assert!(!result.content.contains("Machine Learning"));
+        assert!(!result
+            .content
+            .contains("improvement number 12 to the Web Solutions"));
+        assert!(result
+            .content
+            .contains("Architected a microservices platform that reduced deployment time by 70%"));
+    }
+
+    #[test]
+    fn test_fit_keeps_relevant_achievements() {
+        let mut resume = create_test_resume();
+        lengthen(&mut resume);
+        let relevant = "Optimized database queries resulting in 60% faster page loads";
+
+        let untargeted = with_max_pages(2)
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap();
+        assert!(!untargeted.content.contains(relevant));
+
+        let targeted = ResumeFormatter::with_options(FormattingOptions {
+            max_pages: Some(2),
+            job_description: Some(
+                "Database engineer tuning slow queries. Database experience required.".to_string(),
+            ),
+            ..FormattingOptions::default()
+        })
+        .format(&resume, OutputFormat::Markdown)
+        .unwrap();
+        assert!(targeted.pages.unwrap() <= 2.0);
+        assert!(targeted.content.contains(relevant));
+    }
+
+    #[test]
+    fn test_fit_hides_optional_sections_last() {
+        let mut resume = create_test_resume();
+        lengthen(&mut resume);
+        resume.profile.summary = "Builds reliable distributed systems. ".repeat(60);
+
+        let result = with_max_pages(1)
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap();
+        let hidden: Vec<&String> = result
+            .warnings
+            .iter()
+            .filter(|warning| warning.starts_with("Page limit: hid"))
+            .collect();
+        assert_eq!(
+            hidden[0],
+            "Page limit: hid the Volunteer Experience section"
+        );
+        assert!(!result.content.contains("Code Club"));
+        // Every role keeps its top achievement
+        assert!(result.content.contains(
+            "Implemented a real-time dashboard that increased client satisfaction by 40%"
+        ));
+    }
+
+    #[test]
+    fn test_fit_leaves_short_resume_alone() {
+        let resume = create_test_resume();
+
+        let fitted = with_max_pages(2)
+            .format(&resume, OutputFormat::HTML)
+            .unwrap();
+        let unlimited = ResumeFormatter::new()
+            .format(&resume, OutputFormat::HTML)
+            .unwrap();
+        assert!(fitted.warnings.is_empty());
+        assert_eq!(fitted.content, unlimited.content);
+    }
+
+    #[test]
+    fn test_fit_reports_resume_still_too_long() {
+        let mut resume = create_test_resume();
+        resume.profile.summary = "Builds reliable distributed systems.\n\n".repeat(150);
+
+        let result = with_max_pages(1)
+            .format(&resume, OutputFormat::Markdown)
+            .unwrap();
+        assert!(result.pages.unwrap() > 1.0);
+        assert!(result
+            .warnings
+            .last()
+            .unwrap()
+            .ends_with("pages after trimming, over the 1-page limit"));
+        assert!(result
+            .warnings
+            .contains(&"Page limit: hid the Projects section".to_string()));
+    }
+
+    #[test]
+    fn test_fit_ignored_for_json() {
+        let mut resume = create_test_resume();
+        lengthen(&mut resume);
+
+        let result = with_max_pages(1)
+            .format(&resume, OutputFormat::JSON)
+            .unwrap();
+        assert_eq!(
+            result.warnings,
+            vec!["JSON output has no pages, ignoring the page limit".to_string()]
+        );
+        assert!(result.content.contains("Code Club"));
+    }
 }
@@ -0,0 +1,193 @@
+use crate::resume::page_fit;
+
+/// A built-in HTML theme: the stylesheet placed in the page's `<style>`
+/// block.
+///
+/// Themes style the page through CSS custom properties, so the accent
+/// color, font and color scheme can change without editing the theme:
+/// - `--accent` for headings and rules
+/// - `--font` for the body text
+/// - `--text`, `--muted` and `--background` for the palette
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HtmlTheme {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Accent color used unless the `accent_color` custom option is set.
+    pub accent: &'static str,
+    /// Accent color on the dark palette, where `accent` may be unreadable.
+    pub dark_accent: &'static str,
+    /// Font stack used unless the `font_family` custom option is set.
+    pub font: &'static str,
+    /// Printed page margin used unless the `margin` custom option is set.
+    pub margin: &'static str,
+    pub css: &'static str,
+}
+
+const THEMES: [HtmlTheme; 4] = [
+    HtmlTheme {
+        name: "modern",
+        description: "Sans-serif type with colored, ruled section headings",
+        accent: "#1f4e79",
+        dark_accent: "#7fb2e5",
+        font: "Arial, Helvetica, sans-serif",
+        margin: "0.75in",
+        css: "h1 { border-bottom: 2px solid var(--accent); padding-bottom: 10px; }
+h1, h2, h3 { color: var(--accent); }
+h2 { border-bottom: 1px solid var(--muted); padding-bottom: 5px; margin-top: 20px; }
+ul { margin-top: 5px; }
+",
+    },
+    HtmlTheme {
+        name: "classic",
+        description: "Serif type with a centered name and plain headings",
+        accent: "#222222",
+        dark_accent: "#e4e4e4",
+        font: "Georgia, \"Times New Roman\", serif",
+        margin: "1in",
+        css: "h1 { text-align: center; font-variant: small-caps; }
+h1, h2, h3 { color: var(--accent); }
+h2 { border-bottom: 1px solid var(--accent); text-transform: uppercase; font-size: 1.1em; letter-spacing: 0.05em; }
+p { text-align: justify; }
+",
+    },
+    HtmlTheme {
+        name: "minimal",
+        description: "Compact layout without rules that fits more on a page",
+        accent: "#444444",
+        dark_accent: "#c8c8c8",
+        font: "system-ui, -apple-system, \"Segoe UI\", sans-serif",
+        margin: "0.6in",
+        css: "body { line-height: 1.4; font-size: 0.95em; }
+h1 { font-size: 1.6em; margin-bottom: 0; }
+h2 { color: var(--accent); font-size: 1em; text-transform: uppercase; margin: 1.2em 0 0.3em; }
+h3 { font-size: 1em; margin: 0.8em 0 0.2em; }
+p, ul { margin: 0.3em 0; }
+",
+    },
+    HtmlTheme {
+        name: "academic",
+        description: "Serif type with italic entry titles, suited to publication lists",
+        accent: "#5a1e1e",
+        dark_accent: "#d98c8c",
+        font: "\"Palatino Linotype\", Palatino, \"Book Antiqua\", serif",
+        margin: "1in",
+        css: "h1 { text-align: center; }
+h2 { color: var(--accent); border-bottom: 1px solid var(--muted); font-size: 1.15em; }
+h3 { font-style: italic; font-weight: normal; font-size: 1em; }
+li { margin-bottom: 0.25em; }
+",
+    },
+];
+
+const LIGHT: &str = "--text: #222222; --muted: #666666; --background: #ffffff;";
+const DARK: &str = "--text: #e4e4e4; --muted: #a0a0a0; --background: #1b1b1f;";
+
+/// Which palette the page uses on screen. Printed pages are always light.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+    /// Follows the reader's system setting
+    Auto,
+}
+
+impl ColorScheme {
+    pub fn parse(value: &str) -> Option<ColorScheme> {
+        match value.to_lowercase().as_str() {
+            "light" => Some(ColorScheme::Light),
+            "dark" => Some(ColorScheme::Dark),
+            "auto" => Some(ColorScheme::Auto),
+            _ => None,
+        }
+    }
+}
+
+/// Page setup and colors for `HtmlTheme::stylesheet`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleOptions<'a> {
+    pub accent: &'a str,
+    pub font: &'a str,
+    pub scheme: ColorScheme,
+    /// A CSS page size, e.g. "letter" or "A4"; see `page_size`.
+    pub page_size: &'a str,
+    pub margin: &'a str,
+}
+
+impl HtmlTheme {
+    pub fn builtin() -> &'static [HtmlTheme] {
+        &THEMES
+    }
+
+    pub fn find(name: &str) -> Option<&'static HtmlTheme> {
+        THEMES
+            .iter()
+            .find(|theme| theme.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The full stylesheet: palette variables, base rules, the theme's own
+    /// rules and print rules that keep headings with their entries.
+    pub fn stylesheet(&self, options: &StyleOptions) -> String {
+        let mut css = format!(
+            ":root {{ --accent: {}; --font: {}; {} }}\n",
+            options.accent, options.font, LIGHT
+        );
+        // A custom accent color is used on both palettes
+        let dark_accent = if options.accent == self.accent {
+            self.dark_accent
+        } else {
+            options.accent
+        };
+        match options.scheme {
+            ColorScheme::Light => css.push_str(":root { color-scheme: light; }\n"),
+            ColorScheme::Dark => css.push_str(&format!(
+                ":root {{ color-scheme: dark; }}\n@media screen {{\n  :root {{ --accent: {}; {} }}\n}}\n",
+                dark_accent, DARK
+            )),
+            ColorScheme::Auto => css.push_str(&format!(
+                ":root {{ color-scheme: light dark; }}\n@media screen and (prefers-color-scheme: dark) {{\n  :root {{ --accent: {}; {} }}\n}}\n",
+                dark_accent, DARK
+            )),
+        }
+        css.push_str(
+            "body { font-family: var(--font); color: var(--text); background: var(--background); line-height: 1.6; max-width: 800px; margin: 0 auto; padding: 20px; }\n",
+        );
+        css.push_str("a { color: var(--accent); }\n");
+        css.push_str(self.css);
+        css.push_str(&format!(
+            "@page {{ size: {}; margin: {}; }}\n",
+            options.page_size, options.margin
+        ));
+        css.push_str(&format!(
+            "@media print {{\n  :root {{ --accent: {}; {} }}\n  body {{ max-width: none; margin: 0; padding: 0; font-size: 10.5pt; }}\n  a {{ color: inherit; text-decoration: none; }}\n  h1, h2, h3 {{ break-after: avoid; page-break-after: avoid; }}\n  p, li {{ break-inside: avoid; page-break-inside: avoid; orphans: 3; widows: 3; }}\n}}\n",
+            options.accent, LIGHT
+        ));
+        css
+    }
+}
+
+/// The CSS page size for a paper name, accepting the Typst names used by
+/// the `page_size` custom option, e.g. "us-letter" or "a4".
+pub fn page_size(name: &str) -> Option<&'static str> {
+    page_fit::paper(name).map(|paper| paper.css)
+}
+
+/// Whether `value` is a hex color such as "#1f4e79" or a named color such
+/// as "teal".
+pub fn is_color(value: &str) -> bool {
+    match value.strip_prefix('#') {
+        Some(hex) => {
+            matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        }
+        None => !value.is_empty() && value.chars().all(|c| c.is_ascii_alphabetic()),
+    }
+}
+
+/// Whether `value` is a safe CSS font stack, e.g. `"Fira Sans", sans-serif`.
+pub fn is_font_family(value: &str) -> bool {
+    !value.trim().is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, ' ' | ',' | '-' | '"' | '\''))
+        && value.matches('"').count().is_multiple_of(2)
+        && value.matches('\'').count().is_multiple_of(2)
+}
@@ -0,0 +1,247 @@
+use crate::resume::formatter::{FormattingOptions, OutputFormat, ResumeFormatter};
+use crate::resume::html_theme::{
+    is_color, is_font_family, page_size, ColorScheme, HtmlTheme, StyleOptions,
+};
+use crate::resume::model::{Profile, Resume};
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use std::path::PathBuf;
+
+fn create_test_resume() -> Resume {
+    Resume::new(Profile {
+        name: "Jane Smith".to_string(),
+        title: "Backend Engineer".to_string(),
+        email: "jane@example.com".to_string(),
+        summary: "Builds reliable APIs.".to_string(),
+        ..Profile::default()
+    })
+}
+
+fn render(options: FormattingOptions) -> Result<String, String> {
+    ResumeFormatter::with_options(options)
+        .format(&create_test_resume(), OutputFormat::HTML)
+        .map(|result| result.content)
+}
+
+fn with_custom(pairs: &[(&str, &str)]) -> FormattingOptions {
+    let mut options = FormattingOptions::default();
+    for (key, value) in pairs {
+        options
+            .custom_options
+            .insert(key.to_string(), value.to_string());
+    }
+    options
+}
+
+fn style_options(scheme: ColorScheme) -> StyleOptions<'static> {
+    StyleOptions {
+        accent: "#1f4e79",
+        font: "Arial, sans-serif",
+        scheme,
+        page_size: "A4",
+        margin: "2cm",
+    }
+}
+
+#[rstest]
+#[case("#1f4e79", true)]
+#[case("#abc", true)]
+#[case("#1f4e7980", true)]
+#[case("teal", true)]
+#[case("#12345", false)]
+#[case("#ggg", false)]
+#[case("red; } body { display: none", false)]
+#[case("", false)]
+fn test_is_color(#[case] value: &str, #[case] expected: bool) {
+    assert_eq!(is_color(value), expected);
+}
+
+#[rstest]
+#[case("\"Fira Sans\", sans-serif", true)]
+#[case("Georgia, serif", true)]
+#[case("'Noto Sans CJK SC'", true)]
+#[case("\"Fira Sans, serif", false)]
+#[case("Arial; } h1 { color: red", false)]
+#[case(" ", false)]
+fn test_is_font_family(#[case] value: &str, #[case] expected: bool) {
+    assert_eq!(is_font_family(value), expected);
+}
+
+#[rstest]
+#[case("us-letter", Some("letter"))]
+#[case("a4", Some("A4"))]
+#[case("A5", Some("A5"))]
+#[case("us-legal", Some("legal"))]
+#[case("tabloid", None)]
+fn test_page_size(#[case] name: &str, #[case] expected: Option<&str>) {
+    assert_eq!(page_size(name), expected);
+}
+
+#[test]
+fn test_stylesheet() {
+    let theme = HtmlTheme::find("modern").unwrap();
+    let css = theme.stylesheet(&style_options(ColorScheme::Light));
+
+    assert!(css.starts_with(
+        ":root { --accent: #1f4e79; --font: Arial, sans-serif; --text: #222222; --muted: #666666; --background: #ffffff; }\n"
+    ));
+    assert!(css.contains(":root { color-scheme: light; }\n"));
+    assert!(css.contains(theme.css));
+    assert!(css.contains("@page { size: A4; margin: 2cm; }\n"));
+    assert!(css.contains("@media print {"));
+    assert!(css.contains("h1, h2, h3 { break-after: avoid; page-break-after: avoid; }"));
+    assert!(!css.contains("prefers-color-scheme"));
+}
+
+#[rstest]
+#[case(ColorScheme::Dark, "@media screen {\n  :root { --accent: #7fb2e5;")]
+#[case(
+    ColorScheme::Auto,
+    "@media screen and (prefers-color-scheme: dark) {\n  :root { --accent: #7fb2e5;"
+)]
+fn test_dark_stylesheet(#[case] scheme: ColorScheme, #[case] expected: &str) {
+    let css = HtmlTheme::find("modern")
+        .unwrap()
+        .stylesheet(&style_options(scheme));
+
+    assert!(css.contains(expected));
+    assert!(css.contains("--background: #1b1b1f;"));
+    // Printed pages stay light
+    let print = &css[css.find("@media print").unwrap()..];
+    assert!(print.contains("--accent: #1f4e79; --text: #222222;"));
+}
+
+#[test]
+fn test_custom_accent_kept_on_dark_palette() {
+    let css = HtmlTheme::find("modern")
+        .unwrap()
+        .stylesheet(&StyleOptions {
+            accent: "teal",
+            ..style_options(ColorScheme::Dark)
+        });
+    assert!(css.contains("@media screen {\n  :root { --accent: teal;"));
+}
+
+#[rstest]
+#[case("modern", "#1f4e79")]
+#[case("classic", "#222222")]
+#[case("minimal", "#444444")]
+fn test_theme_follows_template(#[case] template: &str, #[case] accent: &str) {
+    let content = render(FormattingOptions {
+        template: template.to_string(),
+        ..FormattingOptions::default()
+    })
+    .unwrap();
+
+    let theme = HtmlTheme::find(template).unwrap();
+    assert!(content.contains(theme.css));
+    assert!(content.contains(&format!("--accent: {};", accent)));
+    assert!(content.contains(&format!(
+        "@page {{ size: letter; margin: {}; }}",
+        theme.margin
+    )));
+}
+
+#[test]
+fn test_theme_option() {
+    let content = render(with_custom(&[
+        ("html_theme", "academic"),
+        ("accent_color", "#004d40"),
+        ("font_family", "\"Source Serif Pro\", serif"),
+        ("color_scheme", "auto"),
+        ("page_size", "a4"),
+        ("margin", "18mm"),
+    ]))
+    .unwrap();
+
+    assert!(content.contains(HtmlTheme::find("academic").unwrap().css));
+    assert!(content.contains(":root { --accent: #004d40; --font: \"Source Serif Pro\", serif;"));
+    assert!(content.contains("prefers-color-scheme: dark"));
+    assert!(content.contains("@page { size: A4; margin: 18mm; }"));
+}
+
+#[test]
+fn test_page_options_change_estimate() {
+    let pages = |options| {
+        ResumeFormatter::with_options(options)
+            .format(&create_test_resume(), OutputFormat::HTML)
+            .unwrap()
+            .pages
+            .unwrap()
+    };
+    let default = pages(FormattingOptions::default());
+
+    assert!(pages(with_custom(&[("page_size", "a5")])) > default);
+    assert!(pages(with_custom(&[("margin", "2in")])) > default);
+    // The minimal theme's narrower margins fit more
+    assert!(pages(with_custom(&[("html_theme", "minimal")])) < default);
+}
+
+#[rstest]
+#[case("html_theme", "neon", "HTML theme 'neon' not found")]
+#[case("accent_color", "red}", "Invalid accent color 'red}'")]
+#[case("font_family", "x</style>", "Invalid font family 'x</style>'")]
+#[case("color_scheme", "sepia", "Invalid color scheme 'sepia'")]
+#[case("page_size", "tabloid", "Invalid page size 'tabloid'")]
+#[case("margin", "wide", "Invalid page margin 'wide'")]
+fn test_invalid_options(#[case] key: &str, #[case] value: &str, #[case] expected: &str) {
+    assert_eq!(render(with_custom(&[(key, value)])).unwrap_err(), expected);
+}
+
+fn write_css(name: &str, css: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("autocv-{}-{}.css", std::process::id(), name));
+    std::fs::write(&path, css).unwrap();
+    path
+}
+
+#[test]
+fn test_user_css() {
+    let path = write_css("user", "h2 { color: rebeccapurple; }");
+    let content = render(with_custom(&[("html_css", path.to_str().unwrap())]));
+    std::fs::remove_file(&path).unwrap();
+
+    let content = content.unwrap();
+    let theme_at = content
+        .find(HtmlTheme::find("modern").unwrap().css)
+        .unwrap();
+    let user_at = content
+        .find("h2 { color: rebeccapurple; }\n</style>")
+        .unwrap();
+    assert!(user_at > theme_at);
+}
+
+#[test]
+fn test_user_css_cannot_close_style() {
+    let path = write_css("closing", "</STYLE><script>alert(1)</script>");
+    let result = render(with_custom(&[("html_css", path.to_str().unwrap())]));
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(result.unwrap_err(), "CSS file must not contain '</style>'");
+}
+
+#[test]
+fn test_missing_user_css() {
+    let error = render(with_custom(&[("html_css", "/nonexistent/theme.css")])).unwrap_err();
+    assert!(error.starts_with("Failed to read CSS file: "));
+}
+
+#[test]
+fn test_template_without_theme() {
+    let mut formatter = ResumeFormatter::with_options(FormattingOptions {
+        template: "custom".to_string(),
+        ..FormattingOptions::default()
+    });
+    formatter
+        .load_template_from_file("custom", std::path::Path::new("src/templates/modern.md"))
+        .unwrap();
+
+    let result = formatter
+        .format(&create_test_resume(), OutputFormat::HTML)
+        .unwrap();
+    assert!(result
+        .content
+        .contains(HtmlTheme::find("modern").unwrap().css));
+    assert!(result
+        .warnings
+        .contains(&"No HTML theme for template 'custom', using 'modern'".to_string()));
+}
@@ -0,0 +1,1016 @@
+use crate::resume::docx::DocxReader;
+use crate::resume::model::{
+    parse_gpa, Certification, Education, Experience, Language, LanguageProficiency, Location,
+    Profile, Project, Resume, Skill,
+};
+use crate::resume::parser::{DateRange, PartialDate, ResumeParser, SectionKind};
+use crate::resume::pdf_import::PdfTextExtractor;
+use chrono::NaiveDate;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+impl fmt::Display for Confidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Low => write!(f, "low"),
+            Self::Medium => write!(f, "medium"),
+            Self::High => write!(f, "high"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldConfidence {
+    /// Path of the field in the imported resume, e.g. "experiences[0].company".
+    pub field: String,
+    pub value: String,
+    pub confidence: Confidence,
+    /// Why the value is uncertain.
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    pub fields: Vec<FieldConfidence>,
+    /// Lines that could not be assigned to any field.
+    pub unparsed: Vec<String>,
+}
+
+impl ImportReport {
+    pub fn get(&self, field: &str) -> Option<&FieldConfidence> {
+        self.fields.iter().find(|f| f.field == field)
+    }
+
+    /// Fields the user should review, least certain first.
+    pub fn uncertain(&self) -> Vec<&FieldConfidence> {
+        let mut fields: Vec<&FieldConfidence> = self
+            .fields
+            .iter()
+            .filter(|f| f.confidence < Confidence::High)
+            .collect();
+        fields.sort_by_key(|f| f.confidence);
+        fields
+    }
+
+    pub fn summary(&self) -> String {
+        let uncertain = self.uncertain();
+        let mut summary = format!(
+            "Imported {} field(s), {} need review\n",
+            self.fields.len(),
+            uncertain.len()
+        );
+        for field in uncertain {
+            summary.push_str(&format!(
+                "- {} ({}): \"{}\"",
+                field.field, field.confidence, field.value
+            ));
+            if let Some(note) = &field.note {
+                summary.push_str(&format!(" - {}", note));
+            }
+            summary.push('\n');
+        }
+        if !self.unparsed.is_empty() {
+            summary.push_str("Lines that were not imported:\n");
+            for line in &self.unparsed {
+                summary.push_str(&format!("- {}\n", line));
+            }
+        }
+        summary
+    }
+
+    fn record(&mut self, field: String, value: &str, confidence: Confidence, note: Option<&str>) {
+        if value.trim().is_empty() {
+            return;
+        }
+        self.fields.push(FieldConfidence {
+            field,
+            value: value.to_string(),
+            confidence,
+            note: note.map(str::to_string),
+        });
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportResult {
+    pub resume: Resume,
+    pub report: ImportReport,
+}
+
+/// An entry in a dated section: its heading lines, dates and the lines below.
+#[derive(Debug, Default)]
+struct Entry {
+    header: Vec<String>,
+    range: Option<DateRange>,
+    body: Vec<String>,
+}
+
+const TITLE_WORDS: [&str; 24] = [
+    "engineer",
+    "developer",
+    "manager",
+    "analyst",
+    "designer",
+    "lead",
+    "director",
+    "consultant",
+    "intern",
+    "specialist",
+    "scientist",
+    "architect",
+    "administrator",
+    "coordinator",
+    "officer",
+    "assistant",
+    "associate",
+    "head",
+    "president",
+    "programmer",
+    "researcher",
+    "technician",
+    "representative",
+    "owner",
+];
+
+const DEGREE_WORDS: [&str; 14] = [
+    "bachelor",
+    "master",
+    "phd",
+    "ph.d",
+    "doctor",
+    "b.s",
+    "m.s",
+    "bsc",
+    "msc",
+    "b.a",
+    "m.a",
+    "mba",
+    "associate",
+    "diploma",
+];
+
+const BULLETS: [char; 7] = ['-', '*', '•', '·', '▪', '◦', '–'];
+
+/// Imports resumes that only exist as text, such as a pasted document or the
+/// output of `format_plaintext`, recording how sure it is about every field.
+pub struct ResumeImporter {
+    parser: ResumeParser,
+    url_re: Regex,
+    label_re: Regex,
+    numbered_re: Regex,
+}
+
+impl Default for ResumeImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResumeImporter {
+    pub fn new() -> Self {
+        Self {
+            parser: ResumeParser::new(),
+            url_re: Regex::new(
+                r"(?i)\b(?:https?://|www\.)[^\s()<>|,]+|\b(?:linkedin|github)\.com/[^\s()<>|,]+",
+            )
+            .unwrap(),
+            label_re: Regex::new(r"^([A-Za-z][A-Za-z ]{1,24}):\s*(.*)$").unwrap(),
+            numbered_re: Regex::new(r"^\d{1,2}[.)]\s+").unwrap(),
+        }
+    }
+
+    pub fn import_text(&self, text: &str) -> ImportResult {
+        let mut resume = Resume::new(Profile::default());
+        let mut report = ImportReport::default();
+
+        let mut header = vec![];
+        let mut sections: Vec<(SectionKind, Vec<String>)> = vec![];
+        for line in text.lines().map(str::trim) {
+            if let Some(kind) = SectionKind::from_heading(line) {
+                match sections.last_mut() {
+                    // Keep "Technical Skills" under "Skills" as a category
+                    Some((last, lines)) if *last == kind => lines.push(line.to_string()),
+                    _ => sections.push((kind, vec![])),
+                }
+            } else if line.is_empty() {
+                continue;
+            } else if let Some((_, lines)) = sections.last_mut() {
+                lines.push(line.to_string());
+            } else {
+                header.push(line.to_string());
+            }
+        }
+
+        let mut contact = header.clone();
+        for (kind, lines) in &sections {
+            if *kind == SectionKind::Contact {
+                contact.extend(lines.iter().cloned());
+            }
+        }
+        self.import_profile(&header, &contact, text, &mut resume.profile, &mut report);
+
+        for (kind, lines) in &sections {
+            match kind {
+                SectionKind::Contact => {}
+                SectionKind::Summary => {
+                    resume.profile.summary = lines.join(" ");
+                    report.record(
+                        "profile.summary".to_string(),
+                        &resume.profile.summary,
+                        Confidence::High,
+                        None,
+                    );
+                }
+                SectionKind::Experience => self.import_experiences(lines, &mut resume, &mut report),
+                SectionKind::Education => self.import_education(lines, &mut resume, &mut report),
+                SectionKind::Skills => self.import_skills(lines, &mut resume, &mut report),
+                SectionKind::Languages => self.import_languages(lines, &mut resume, &mut report),
+                SectionKind::Projects => self.import_projects(lines, &mut resume, &mut report),
+                SectionKind::Certifications => {
+                    self.import_certifications(lines, &mut resume, &mut report)
+                }
+                SectionKind::Publications | SectionKind::Volunteer => {
+                    report.unparsed.extend(lines.iter().cloned())
+                }
+            }
+        }
+
+        ImportResult { resume, report }
+    }
+
+    /// Imports a PDF resume from its text, read in reading order.
+    pub fn import_pdf(&self, bytes: &[u8]) -> Result<ImportResult, String> {
+        let text = PdfTextExtractor::new().extract_text(bytes)?;
+        Ok(self.import_text(&text))
+    }
+
+    /// Imports a Word resume from its paragraphs, using heading styles to
+    /// separate sections.
+    pub fn import_docx(&self, bytes: &[u8]) -> Result<ImportResult, String> {
+        let text = DocxReader::new().read_text(bytes)?;
+        Ok(self.import_text(&text))
+    }
+
+    fn import_profile(
+        &self,
+        header: &[String],
+        contact: &[String],
+        text: &str,
+        profile: &mut Profile,
+        report: &mut ImportReport,
+    ) {
+        let contact_text = contact.join("\n");
+
+        let parsed_header = self.parser.parse_text(&contact_text);
+        let parsed_all = self.parser.parse_text(text);
+        match (parsed_header.email, parsed_all.email) {
+            (Some(email), _) => {
+                report.record("profile.email".to_string(), &email, Confidence::High, None);
+                profile.email = email;
+            }
+            (None, Some(email)) => {
+                report.record(
+                    "profile.email".to_string(),
+                    &email,
+                    Confidence::Medium,
+                    Some("found outside the contact details"),
+                );
+                profile.email = email;
+            }
+            (None, None) => {}
+        }
+        if let Some(phone) = parsed_header.phone {
+            let digits = phone.chars().filter(char::is_ascii_digit).count();
+            let confidence = if digits >= 10 {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            };
+            report.record("profile.phone".to_string(), &phone, confidence, None);
+            profile.phone = phone;
+        }
+
+        for found in self.url_re.find_iter(&contact_text) {
+            let url = found.as_str().trim_end_matches(['.', ')']).to_string();
+            let lower = url.to_lowercase();
+            let field = if lower.contains("linkedin.com") {
+                &mut profile.linkedin
+            } else if lower.contains("github.com") {
+                &mut profile.github
+            } else {
+                &mut profile.website
+            };
+            if field.is_empty() {
+                *field = url;
+            }
+        }
+        for (name, value) in [
+            ("linkedin", &profile.linkedin),
+            ("github", &profile.github),
+            ("website", &profile.website),
+        ] {
+            report.record(format!("profile.{}", name), value, Confidence::High, None);
+        }
+
+        for line in contact {
+            let line = strip_bullet(line);
+            if let Some(caps) = self.label_re.captures(line) {
+                if caps[1].eq_ignore_ascii_case("location")
+                    || caps[1].eq_ignore_ascii_case("address")
+                {
+                    profile.location = parse_location(&caps[2]);
+                    report.record(
+                        "profile.location".to_string(),
+                        caps[2].trim(),
+                        Confidence::High,
+                        None,
+                    );
+                    break;
+                }
+            } else if let Some(location) = line
+                .split(['|', '•', '·'])
+                .map(str::trim)
+                .find(|part| looks_like_location(part))
+            {
+                profile.location = parse_location(location);
+                report.record(
+                    "profile.location".to_string(),
+                    location,
+                    Confidence::Medium,
+                    Some("guessed from the contact line"),
+                );
+                break;
+            }
+        }
+
+        // The first header line is almost always the name, the next one may
+        // be a professional title.
+        let mut lines = header.iter().filter(|line| !self.is_contact_line(line));
+        if let Some(name) = lines.next() {
+            let words = name.split_whitespace().count();
+            let capitalized = name
+                .split_whitespace()
+                .all(|word| word.chars().next().is_some_and(char::is_uppercase));
+            let confidence = if (2..=4).contains(&words) && capitalized {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            };
+            profile.name = name.clone();
+            report.record("profile.name".to_string(), name, confidence, None);
+        }
+        if let Some(title) = lines.next() {
+            if title.split_whitespace().count() <= 6 && !title.ends_with('.') {
+                profile.title = title.clone();
+                report.record(
+                    "profile.title".to_string(),
+                    title,
+                    Confidence::Medium,
+                    Some("assumed from the line below the name"),
+                );
+            }
+        }
+    }
+
+    fn is_contact_line(&self, line: &str) -> bool {
+        let digits = line.chars().filter(char::is_ascii_digit).count();
+        line.contains('@')
+            || digits >= 7
+            || self.url_re.is_match(line)
+            || self.label_re.is_match(strip_bullet(line))
+            || line.contains('|')
+    }
+
+    fn import_experiences(&self, lines: &[String], resume: &mut Resume, report: &mut ImportReport) {
+        for entry in self.split_entries(lines, false, report) {
+            let index = resume.experiences.len();
+            let field = |name: &str| format!("experiences[{}].{}", index, name);
+            let (title, company, confidence, note) = split_title_company(&entry.header);
+
+            let mut experience = Experience {
+                company,
+                title,
+                location: None,
+                start_date: None,
+                end_date: None,
+                current: false,
+                description: String::new(),
+                achievements: vec![],
+                technologies: vec![],
+                tags: vec![],
+                achievement_tags: HashMap::new(),
+                translations: HashMap::new(),
+            };
+            report.record(field("title"), &experience.title, confidence, note);
+            report.record(field("company"), &experience.company, confidence, note);
+            if let Some(range) = entry.range {
+                self.record_range(range, &field, report);
+                experience.start_date = range.0.and_then(to_date);
+                experience.end_date = range.1.and_then(to_date);
+                experience.current = range.2;
+            }
+
+            let mut description = vec![];
+            for line in &entry.body {
+                if self.is_bullet(line) {
+                    experience.achievements.push(self.strip_marker(line));
+                } else if let Some(caps) = self.label_re.captures(line) {
+                    let value = caps[2].trim();
+                    match caps[1].to_lowercase().as_str() {
+                        "location" => experience.location = Some(value.to_string()),
+                        "technologies" | "tech stack" | "stack" | "tools" | "environment" => {
+                            experience.technologies = split_list(value)
+                        }
+                        _ if value.is_empty() => {}
+                        _ => description.push(line.clone()),
+                    }
+                } else {
+                    description.push(line.clone());
+                }
+            }
+            experience.description = description.join(" ");
+
+            report.record(
+                field("achievements"),
+                &experience.achievements.join("; "),
+                Confidence::High,
+                None,
+            );
+            report.record(
+                field("description"),
+                &experience.description,
+                Confidence::Medium,
+                Some("unlabelled lines below the role"),
+            );
+            resume.experiences.push(experience);
+        }
+    }
+
+    fn import_education(&self, lines: &[String], resume: &mut Resume, report: &mut ImportReport) {
+        for entry in self.split_entries(lines, true, report) {
+            let index = resume.education.len();
+            let field = |name: &str| format!("education[{}].{}", index, name);
+
+            let parts: Vec<String> = entry
+                .header
+                .iter()
+                .flat_map(|line| split_parts(line))
+                .collect();
+            let degree_index = parts
+                .iter()
+                .position(|part| is_degree(part) || part.contains(" in "));
+            let (degree_line, institution) = match degree_index {
+                Some(position) => (
+                    parts[position].clone(),
+                    parts
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| *i != position)
+                        .map(|(_, part)| part.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+                None => (String::new(), parts.join(", ")),
+            };
+            let (degree, field_of_study) = match degree_line.split_once(" in ") {
+                Some((degree, field)) => (degree.trim().to_string(), field.trim().to_string()),
+                None => (degree_line.clone(), String::new()),
+            };
+            let confidence = if is_degree(&degree) {
+                Confidence::High
+            } else {
+                Confidence::Low
+            };
+
+            let mut education = Education {
+                institution,
+                degree,
+                field_of_study,
+                location: None,
+                start_date: None,
+                end_date: None,
+                current: false,
+                gpa: None,
+                gpa_scale: None,
+                courses: vec![],
+                achievements: vec![],
+                description: String::new(),
+                translations: HashMap::new(),
+            };
+            report.record(field("degree"), &education.degree, confidence, None);
+            report.record(
+                field("field_of_study"),
+                &education.field_of_study,
+                confidence,
+                None,
+            );
+            report.record(
+                field("institution"),
+                &education.institution,
+                Confidence::Medium,
+                Some("taken from the entry lines that are not the degree"),
+            );
+            if let Some(range) = entry.range {
+                self.record_range(range, &field, report);
+                education.start_date = range.0.and_then(to_date);
+                education.end_date = range.1.and_then(to_date);
+                education.current = range.2;
+            }
+
+            let mut list: Option<&str> = None;
+            let mut description = vec![];
+            for line in &entry.body {
+                if self.is_bullet(line) {
+                    let item = self.strip_marker(line);
+                    match list {
+                        Some("courses") => education.courses.push(item),
+                        _ => education.achievements.push(item),
+                    }
+                } else if let Some(caps) = self.label_re.captures(line) {
+                    let value = caps[2].trim();
+                    match caps[1].to_lowercase().as_str() {
+                        "gpa" => {
+                            if let Some((gpa, scale)) = parse_gpa(value) {
+                                education.gpa = Some(gpa);
+                                education.gpa_scale = scale;
+                            }
+                        }
+                        "location" => education.location = Some(value.to_string()),
+                        "relevant courses" | "courses" | "coursework" => {
+                            list = Some("courses");
+                            education.courses.extend(split_list(value));
+                        }
+                        "achievements" | "honors" | "awards" => {
+                            list = Some("achievements");
+                            education.achievements.extend(split_list(value));
+                        }
+                        _ => description.push(line.clone()),
+                    }
+                } else {
+                    description.push(line.clone());
+                }
+            }
+            education.description = description.join(" ");
+            resume.education.push(education);
+        }
+    }
+
+    fn import_skills(&self, lines: &[String], resume: &mut Resume, report: &mut ImportReport) {
+        // Set by heading lines such as "Tools"; "Tools: Docker" only labels its own line
+        let mut section_category: Option<&str> = None;
+
+        for line in lines {
+            let line = strip_bullet(line);
+            let (category, items) = match line.split_once(':') {
+                Some((label, items)) if items.trim().is_empty() => {
+                    section_category = skill_category(label).or(Some("technical"));
+                    continue;
+                }
+                Some((label, items)) => (skill_category(label), items.trim()),
+                None if skill_category(line).is_some() => {
+                    section_category = skill_category(line);
+                    continue;
+                }
+                None => (section_category, line),
+            };
+
+            let target = category.unwrap_or("technical");
+            let list = match target {
+                "soft" => &mut resume.skills.soft,
+                "tools" => &mut resume.skills.tools,
+                "languages" => &mut resume.skills.languages,
+                "other" => &mut resume.skills.other,
+                _ => &mut resume.skills.technical,
+            };
+            for name in split_list(items) {
+                let index = list.len();
+                let (confidence, note) = match category {
+                    Some(_) => (Confidence::High, None),
+                    None => (Confidence::Medium, Some("no category, filed as technical")),
+                };
+                report.record(
+                    format!("skills.{}[{}]", target, index),
+                    &name,
+                    confidence,
+                    note,
+                );
+                list.push(Skill {
+                    name,
+                    level: None,
+                    years: None,
+                    tags: vec![],
+                });
+            }
+        }
+    }
+
+    fn import_projects(&self, lines: &[String], resume: &mut Resume, report: &mut ImportReport) {
+        let mut in_highlights = false;
+
+        for line in lines {
+            let starts_project = !self.is_bullet(line)
+                && !self.label_re.is_match(line)
+                && line.split_whitespace().count() <= 8
+                && !line.ends_with('.')
+                && resume.projects.last().is_none_or(|project: &Project| {
+                    !project.description.is_empty() || !project.highlights.is_empty()
+                });
+
+            if starts_project {
+                in_highlights = false;
+                report.record(
+                    format!("projects[{}].name", resume.projects.len()),
+                    line,
+                    Confidence::Medium,
+                    Some("assumed from a short line starting the entry"),
+                );
+                resume.projects.push(Project {
+                    name: line.clone(),
+                    description: String::new(),
+                    url: None,
+                    github: None,
+                    technologies: vec![],
+                    start_date: None,
+                    end_date: None,
+                    highlights: vec![],
+                    tags: vec![],
+                    translations: HashMap::new(),
+                });
+                continue;
+            }
+
+            let Some(project) = resume.projects.last_mut() else {
+                report.unparsed.push(line.clone());
+                continue;
+            };
+            if self.is_bullet(line) {
+                if in_highlights || !project.description.is_empty() {
+                    project.highlights.push(self.strip_marker(line));
+                } else {
+                    project.description = self.strip_marker(line);
+                }
+            } else if let Some(caps) = self.label_re.captures(line) {
+                let value = caps[2].trim();
+                let url = self
+                    .url_re
+                    .find(value)
+                    .map(|m| m.as_str().trim_end_matches(')').to_string());
+                match caps[1].to_lowercase().as_str() {
+                    "technologies" | "tech stack" | "stack" => {
+                        project.technologies = split_list(value)
+                    }
+                    "highlights" => in_highlights = true,
+                    "github" | "repository" => project.github = url,
+                    "link" | "url" | "website" | "demo" => project.url = url,
+                    _ => report.unparsed.push(line.clone()),
+                }
+            } else if project.description.is_empty() {
+                project.description = line.clone();
+            } else {
+                project.description = format!("{} {}", project.description, line);
+            }
+        }
+    }
+
+    /// "German (Native)", "German - Native" or "German: Native" items, several
+    /// to a line if they're separated like a skill list. A language without a
+    /// recognized proficiency is read as professional working proficiency.
+    fn import_languages(&self, lines: &[String], resume: &mut Resume, report: &mut ImportReport) {
+        for item in lines.iter().flat_map(|line| split_list(strip_bullet(line))) {
+            let (name, label) = match item.rsplit_once(" (") {
+                Some((name, label)) if label.ends_with(')') => {
+                    (name, Some(label.trim_end_matches(')')))
+                }
+                _ => match item.split_once(" - ").or_else(|| item.split_once(':')) {
+                    Some((name, label)) => (name, Some(label)),
+                    None => (item.as_str(), None),
+                },
+            };
+            let proficiency = label.and_then(LanguageProficiency::from_label);
+
+            let index = resume.languages.len();
+            let (confidence, note) = match proficiency {
+                Some(_) => (Confidence::High, None),
+                None => (
+                    Confidence::Medium,
+                    Some("no proficiency, read as professional working"),
+                ),
+            };
+            report.record(
+                format!("languages[{}].name", index),
+                name.trim(),
+                confidence,
+                note,
+            );
+            resume.languages.push(Language {
+                name: name.trim().to_string(),
+                proficiency: proficiency.unwrap_or(LanguageProficiency::Professional),
+            });
+        }
+    }
+
+    fn import_certifications(
+        &self,
+        lines: &[String],
+        resume: &mut Resume,
+        report: &mut ImportReport,
+    ) {
+        for line in lines {
+            let line = self.strip_marker(line);
+            let date = self.parser.parse_date(&line);
+            let parts: Vec<String> = split_parts(&line)
+                .iter()
+                .map(|part| match part.split_once(" (") {
+                    Some((text, dated)) if self.parser.parse_date(dated).is_some() => {
+                        text.trim().to_string()
+                    }
+                    _ => part.clone(),
+                })
+                .filter(|part| {
+                    part.split_whitespace().count() > 2 || self.parser.parse_date(part).is_none()
+                })
+                .collect();
+            let Some(name) = parts.first() else {
+                report.unparsed.push(line);
+                continue;
+            };
+
+            let index = resume.certifications.len();
+            report.record(
+                format!("certifications[{}].name", index),
+                name,
+                Confidence::Medium,
+                None,
+            );
+            resume.certifications.push(Certification {
+                name: name.clone(),
+                issuer: parts[1..].join(", "),
+                date_obtained: date.and_then(to_date),
+                expiry_date: None,
+                credential_id: None,
+                url: None,
+            });
+        }
+    }
+
+    /// Groups the lines of a dated section into entries. Every line holding a
+    /// date range starts an entry, together with up to two heading lines
+    /// right above it. Education may also be dated by a lone graduation date.
+    fn split_entries(
+        &self,
+        lines: &[String],
+        single_dates: bool,
+        report: &mut ImportReport,
+    ) -> Vec<Entry> {
+        let mut entries: Vec<Entry> = vec![];
+        let mut body_start = 0;
+
+        for (index, line) in lines.iter().enumerate() {
+            if self.is_bullet(line) || self.label_re.is_match(line) {
+                continue;
+            }
+            let found = self.parser.find_date_range(line).or_else(|| {
+                let date = self.parser.parse_date(line)?;
+                (single_dates && line.split_whitespace().count() <= 2)
+                    .then(|| ((None, Some(date), false), String::new()))
+            });
+            let Some((range, rest)) = found else {
+                continue;
+            };
+
+            let mut header_start = index;
+            let mut header = vec![];
+            if !rest.is_empty() {
+                header.push(rest.clone());
+            }
+            let needs = if rest.is_empty() {
+                2
+            } else if split_parts(&rest).len() >= 2 {
+                0
+            } else {
+                1
+            };
+            for _ in 0..needs {
+                let Some(previous) = header_start.checked_sub(1) else {
+                    break;
+                };
+                if previous < body_start || !self.is_heading_line(&lines[previous]) {
+                    break;
+                }
+                // Two heading lines are only needed when one can't be split
+                if header.len() == 1 && split_parts(&header[0]).len() >= 2 {
+                    break;
+                }
+                header.insert(0, lines[previous].clone());
+                header_start = previous;
+            }
+
+            match entries.last_mut() {
+                Some(last) => last.body = lines[body_start..header_start].to_vec(),
+                None => report
+                    .unparsed
+                    .extend(lines[body_start..header_start].iter().cloned()),
+            }
+            entries.push(Entry {
+                header,
+                range: Some(range),
+                body: vec![],
+            });
+            body_start = index + 1;
+        }
+
+        match entries.last_mut() {
+            Some(last) => last.body = lines[body_start..].to_vec(),
+            None => report.unparsed.extend(lines.iter().cloned()),
+        }
+        entries
+    }
+
+    fn is_heading_line(&self, line: &str) -> bool {
+        !self.is_bullet(line)
+            && !self.label_re.is_match(line)
+            && !line.ends_with('.')
+            && line.split_whitespace().count() <= 12
+    }
+
+    fn is_bullet(&self, line: &str) -> bool {
+        line.starts_with(BULLETS) || self.numbered_re.is_match(line)
+    }
+
+    fn strip_marker(&self, line: &str) -> String {
+        let line = strip_bullet(line);
+        self.numbered_re.replace(line, "").trim().to_string()
+    }
+
+    fn record_range(
+        &self,
+        range: DateRange,
+        field: &dyn Fn(&str) -> String,
+        report: &mut ImportReport,
+    ) {
+        for (name, date) in [("start_date", range.0), ("end_date", range.1)] {
+            let Some(date) = date else {
+                continue;
+            };
+            match date.month {
+                Some(month) => report.record(
+                    field(name),
+                    &format!("{}-{:02}", date.year, month),
+                    Confidence::High,
+                    None,
+                ),
+                None => report.record(
+                    field(name),
+                    &date.year.to_string(),
+                    Confidence::Medium,
+                    Some("no month given, January assumed"),
+                ),
+            }
+        }
+        if range.2 {
+            report.record(field("current"), "true", Confidence::High, None);
+        }
+    }
+}
+
+fn strip_bullet(line: &str) -> &str {
+    line.trim_start_matches(BULLETS).trim()
+}
+
+fn to_date(date: PartialDate) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(date.year, date.month.unwrap_or(1), 1)
+}
+
+fn split_list(items: &str) -> Vec<String> {
+    items
+        .split([',', ';', '|', '•'])
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Splits an entry heading on the separators people use between a title, a
+/// company and a location.
+fn split_parts(line: &str) -> Vec<String> {
+    let line = line.trim_start_matches('#').trim();
+    for separator in [" | ", " at ", " @ ", " — ", " – ", " - ", ", "] {
+        if line.contains(separator) {
+            return line
+                .split(separator)
+                .map(|part| part.trim().to_string())
+                .filter(|part| !part.is_empty())
+                .collect();
+        }
+    }
+    vec![line.to_string()]
+}
+
+fn looks_like_title(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    lower
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|word| TITLE_WORDS.contains(&word))
+}
+
+fn is_degree(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    DEGREE_WORDS.iter().any(|word| {
+        lower
+            .split(|c: char| c.is_whitespace() || c == '(' || c == ',')
+            .any(|token| token.trim_end_matches('.') == word.trim_end_matches('.'))
+    })
+}
+
+/// Decides which heading part is the title and which is the company.
+fn split_title_company(header: &[String]) -> (String, String, Confidence, Option<&'static str>) {
+    if let [line] = header {
+        let line = line.trim_start_matches('#').trim();
+        if let Some((title, company)) = line.split_once(" at ") {
+            return (
+                title.trim().to_string(),
+                company.trim().to_string(),
+                Confidence::High,
+                None,
+            );
+        }
+    }
+
+    let parts: Vec<String> = header.iter().flat_map(|line| split_parts(line)).collect();
+    match parts.as_slice() {
+        [] => (String::new(), String::new(), Confidence::Low, None),
+        [only] if looks_like_title(only) => (
+            only.clone(),
+            String::new(),
+            Confidence::Low,
+            Some("no company found"),
+        ),
+        [only] => (
+            String::new(),
+            only.clone(),
+            Confidence::Low,
+            Some("no title found"),
+        ),
+        [first, second, ..] => {
+            if looks_like_title(first) {
+                (first.clone(), second.clone(), Confidence::Medium, None)
+            } else if looks_like_title(second) {
+                (second.clone(), first.clone(), Confidence::Medium, None)
+            } else {
+                (
+                    first.clone(),
+                    second.clone(),
+                    Confidence::Low,
+                    Some("title and company order guessed"),
+                )
+            }
+        }
+    }
+}
+
+fn skill_category(heading: &str) -> Option<&'static str> {
+    match heading.trim().trim_end_matches(':').to_lowercase().as_str() {
+        "technical" | "technical skills" | "programming" | "programming languages" => {
+            Some("technical")
+        }
+        "soft" | "soft skills" | "interpersonal" => Some("soft"),
+        "tools" | "tools & technologies" | "software" => Some("tools"),
+        "languages" | "spoken languages" => Some("languages"),
+        "other" | "other skills" | "interests" => Some("other"),
+        _ => None,
+    }
+}
+
+fn looks_like_location(text: &str) -> bool {
+    let Some((city, region)) = text.split_once(", ") else {
+        return false;
+    };
+    let capitalized = |part: &str| {
+        !part.is_empty()
+            && part.split_whitespace().all(|word| {
+                word.chars().next().is_some_and(char::is_uppercase)
+                    && word.chars().all(|c| c.is_alphabetic() || c == '.')
+            })
+    };
+    capitalized(city) && capitalized(region) && text.split_whitespace().count() <= 5
+}
+
+fn parse_location(text: &str) -> Location {
+    match text.rsplit_once(", ") {
+        Some((city, country)) => Location {
+            city: city.trim().to_string(),
+            country: country.trim().to_string(),
+        },
+        None => Location {
+            city: text.trim().to_string(),
+            country: String::new(),
+        },
+    }
+}
@@ -0,0 +1,241 @@
+use crate::resume::formatter::{OutputFormat, ResumeFormatter};
+use crate::resume::importer::*;
+use crate::resume::model::{Language, LanguageProficiency, Skill};
+use crate::resume::test_support::{date, sample_resume};
+use pretty_assertions::assert_eq;
+
+const PASTED_RESUME: &str = "JANE SMITH
+Backend Engineer
+jane.smith@example.com | +1 (555) 123-4567 | Berlin, Germany
+linkedin.com/in/janesmith
+
+SUMMARY
+Backend engineer with eight years of experience
+building payment systems.
+
+EXPERIENCE
+Acme Corp | Senior Software Engineer | Jan 2020 - Present
+• Led the migration to event sourcing
+• Cut settlement time by 40%
+Technologies: Rust, Kafka
+
+Initech
+Developer
+2016 - 2019
+Maintained the billing monolith.
+1. Introduced integration tests
+
+EDUCATION
+B.Sc. in Computer Science, Technical University of Munich
+2016
+
+SKILLS
+Programming: Rust, Go, Python
+Tools: Docker; Terraform
+Communication, Mentoring
+
+VOLUNTEER
+Code mentor at a local school
+";
+
+fn import() -> ImportResult {
+    ResumeImporter::new().import_text(PASTED_RESUME)
+}
+
+fn names(skills: &[Skill]) -> Vec<&str> {
+    skills.iter().map(|skill| skill.name.as_str()).collect()
+}
+
+#[test]
+fn test_import_contact_details() {
+    let profile = import().resume.profile;
+    assert_eq!(profile.name, "JANE SMITH");
+    assert_eq!(profile.title, "Backend Engineer");
+    assert_eq!(profile.email, "jane.smith@example.com");
+    assert_eq!(profile.phone, "+1 (555) 123-4567");
+    assert_eq!(profile.linkedin, "linkedin.com/in/janesmith");
+    assert_eq!(profile.location.city, "Berlin");
+    assert_eq!(profile.location.country, "Germany");
+    assert_eq!(
+        profile.summary,
+        "Backend engineer with eight years of experience building payment systems."
+    );
+}
+
+#[test]
+fn test_import_experiences() {
+    let experiences = import().resume.experiences;
+    assert_eq!(experiences.len(), 2);
+
+    let acme = &experiences[0];
+    assert_eq!(acme.title, "Senior Software Engineer");
+    assert_eq!(acme.company, "Acme Corp");
+    assert_eq!(acme.start_date, date(2020, 1));
+    assert!(acme.current);
+    assert_eq!(
+        acme.achievements,
+        vec![
+            "Led the migration to event sourcing",
+            "Cut settlement time by 40%"
+        ]
+    );
+    assert_eq!(acme.technologies, vec!["Rust", "Kafka"]);
+
+    let initech = &experiences[1];
+    assert_eq!(initech.title, "Developer");
+    assert_eq!(initech.company, "Initech");
+    assert_eq!(initech.start_date, date(2016, 1));
+    assert_eq!(initech.end_date, date(2019, 1));
+    assert_eq!(initech.description, "Maintained the billing monolith.");
+    assert_eq!(initech.achievements, vec!["Introduced integration tests"]);
+}
+
+#[test]
+fn test_import_education_and_skills() {
+    let resume = import().resume;
+    assert_eq!(resume.education.len(), 1);
+    let education = &resume.education[0];
+    assert_eq!(education.degree, "B.Sc.");
+    assert_eq!(education.field_of_study, "Computer Science");
+    assert_eq!(education.institution, "Technical University of Munich");
+    assert_eq!(education.end_date, date(2016, 1));
+
+    assert_eq!(
+        names(&resume.skills.technical),
+        vec!["Rust", "Go", "Python", "Communication", "Mentoring"]
+    );
+    assert_eq!(names(&resume.skills.tools), vec!["Docker", "Terraform"]);
+
+    let report = import().report;
+    let unlabelled = report.get("skills.technical[3]").unwrap();
+    assert_eq!(unlabelled.confidence, Confidence::Medium);
+    assert_eq!(
+        unlabelled.note.as_deref(),
+        Some("no category, filed as technical")
+    );
+}
+
+#[test]
+fn test_import_languages() {
+    let result = ResumeImporter::new().import_text(
+        "Jane Smith
+jane.smith@example.com
+
+LANGUAGES
+• German (Native)
+• English - Full professional proficiency
+French: Elementary, Spanish
+",
+    );
+    let language = |name: &str, proficiency| Language {
+        name: name.to_string(),
+        proficiency,
+    };
+    assert_eq!(
+        result.resume.languages,
+        vec![
+            language("German", LanguageProficiency::Native),
+            language("English", LanguageProficiency::FullProfessional),
+            language("French", LanguageProficiency::Elementary),
+            language("Spanish", LanguageProficiency::Professional),
+        ]
+    );
+    assert!(result.resume.skills.languages.is_empty());
+
+    let spanish = result.report.get("languages[3].name").unwrap();
+    assert_eq!(spanish.confidence, Confidence::Medium);
+    assert_eq!(
+        spanish.note.as_deref(),
+        Some("no proficiency, read as professional working")
+    );
+}
+
+#[test]
+fn test_confidence_report() {
+    let report = import().report;
+
+    let confidence = |field: &str| report.get(field).map(|f| f.confidence);
+    assert_eq!(confidence("profile.email"), Some(Confidence::High));
+    assert_eq!(confidence("profile.title"), Some(Confidence::Medium));
+    assert_eq!(confidence("profile.location"), Some(Confidence::Medium));
+    assert_eq!(
+        confidence("experiences[0].company"),
+        Some(Confidence::Medium)
+    );
+    assert_eq!(
+        confidence("experiences[0].start_date"),
+        Some(Confidence::High)
+    );
+    assert_eq!(
+        confidence("experiences[1].start_date"),
+        Some(Confidence::Medium)
+    );
+    assert_eq!(confidence("profile.website"), None);
+
+    let uncertain = report.uncertain();
+    assert!(uncertain
+        .windows(2)
+        .all(|pair| pair[0].confidence <= pair[1].confidence));
+    assert_eq!(report.unparsed, vec!["Code mentor at a local school"]);
+
+    let summary = report.summary();
+    assert!(summary.contains("- experiences[1].start_date (medium): \"2016\" - no month given"));
+    assert!(summary.contains("Lines that were not imported:\n- Code mentor at a local school\n"));
+}
+
+#[test]
+fn test_ambiguous_heading_has_low_confidence() {
+    let result = ResumeImporter::new()
+        .import_text("Jane Smith\n\nExperience\nAcme Corp, Payments\nMarch 2018 - June 2019\n");
+    let report = &result.report;
+    let field = report.get("experiences[0].title").unwrap();
+    assert_eq!(field.confidence, Confidence::Low);
+    assert_eq!(
+        field.note.as_deref(),
+        Some("title and company order guessed")
+    );
+}
+
+#[test]
+fn test_import_plaintext_output() {
+    let mut resume = sample_resume();
+    resume.education[0].gpa = Some(3.8);
+
+    let text = ResumeFormatter::new()
+        .format(&resume, OutputFormat::PlainText)
+        .unwrap()
+        .content;
+    let result = ResumeImporter::new().import_text(&text);
+    let imported = result.resume;
+
+    assert_eq!(imported.profile.name, "Jane Smith");
+    assert_eq!(imported.profile.title, "Software Engineer");
+    assert_eq!(imported.profile.phone, "+1 555 123 4567");
+    assert_eq!(imported.profile.location.city, "Austin");
+    assert_eq!(imported.profile.summary, resume.profile.summary);
+
+    let experience = &imported.experiences[0];
+    assert_eq!(
+        (experience.title.as_str(), experience.company.as_str()),
+        ("Senior Engineer", "Acme Corp")
+    );
+    assert_eq!(experience.start_date, date(2020, 3));
+    assert!(experience.current);
+    assert_eq!(experience.location.as_deref(), Some("Remote"));
+    assert_eq!(experience.description, "Owns the billing platform.");
+    assert_eq!(experience.achievements, resume.experiences[0].achievements);
+    assert_eq!(experience.technologies, resume.experiences[0].technologies);
+
+    let education = &imported.education[0];
+    assert_eq!(education.degree, "BSc");
+    assert_eq!(education.institution, "MIT");
+    assert_eq!(education.end_date, date(2016, 6));
+    assert_eq!(education.gpa, Some(3.8));
+
+    assert_eq!(names(&imported.skills.technical), vec!["Rust", "SQL"]);
+    assert_eq!(
+        result.report.get("skills.technical[0]").unwrap().confidence,
+        Confidence::High
+    );
+    assert!(result.report.unparsed.is_empty());
+}
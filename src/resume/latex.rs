@@ -0,0 +1,148 @@
+use crate::resume::page_fit::PageMetrics;
+
+/// A built-in LaTeX document style: the class options and preamble placed
+/// before `\begin{document}`.
+///
+/// Every style defines the same macros, so the document body is the same
+/// whichever style is chosen:
+/// - `\resumeentry{title}{organization}{dates}` starts an entry
+/// - `\resumedetail{text}` adds a secondary line, e.g. a location
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatexStyle {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub class_options: &'static str,
+    /// Text capacity of a page, for length estimates.
+    pub page: PageMetrics,
+    pub preamble: &'static str,
+}
+
+const COMMON_PREAMBLE: &str = r"\usepackage[T1]{fontenc}
+\usepackage[utf8]{inputenc}
+\usepackage{enumitem}
+\usepackage[hidelinks]{hyperref}
+\pagestyle{empty}
+\setlength{\parindent}{0pt}
+\setlist[itemize]{leftmargin=*,topsep=2pt,itemsep=1pt}
+";
+
+const STYLES: [LatexStyle; 4] = [
+    LatexStyle {
+        name: "classic",
+        description: "Serif type with plain section headings",
+        class_options: "11pt,letterpaper",
+        page: PageMetrics {
+            lines_per_page: 47.0,
+            chars_per_line: 88,
+        },
+        preamble: r"\usepackage{lmodern}
+\usepackage[margin=1in]{geometry}
+\newcommand{\resumeentry}[3]{\par\smallskip\textbf{#1}\ifx&#2&\else, #2\fi\hfill\textit{#3}\par}
+\newcommand{\resumedetail}[1]{\textit{#1}\par}
+",
+    },
+    LatexStyle {
+        name: "modern",
+        description: "Sans-serif type with ruled, colored section headings",
+        class_options: "11pt,letterpaper",
+        page: PageMetrics {
+            lines_per_page: 49.0,
+            chars_per_line: 92,
+        },
+        preamble: r"\usepackage[margin=0.8in]{geometry}
+\usepackage{xcolor}
+\usepackage{titlesec}
+\renewcommand{\familydefault}{\sfdefault}
+\definecolor{accent}{RGB}{31,78,121}
+\titleformat{\section}{\large\bfseries\color{accent}}{}{0em}{}[\titlerule]
+\titlespacing*{\section}{0pt}{10pt}{6pt}
+\newcommand{\resumeentry}[3]{\par\smallskip\textbf{#1}\ifx&#2&\else\ \textbar\ #2\fi\hfill{\color{gray}#3}\par}
+\newcommand{\resumedetail}[1]{{\small #1}\par}
+",
+    },
+    LatexStyle {
+        name: "minimal",
+        description: "Compact layout that fits more on a page",
+        class_options: "10pt,letterpaper",
+        page: PageMetrics {
+            lines_per_page: 60.0,
+            chars_per_line: 110,
+        },
+        preamble: r"\usepackage{lmodern}
+\usepackage[margin=0.6in]{geometry}
+\usepackage{titlesec}
+\titleformat{\section}{\normalsize\bfseries\MakeUppercase}{}{0em}{}
+\titlespacing*{\section}{0pt}{6pt}{2pt}
+\newcommand{\resumeentry}[3]{\par\textbf{#1}\ifx&#2&\else, #2\fi\hfill #3\par}
+\newcommand{\resumedetail}[1]{#1\par}
+",
+    },
+    LatexStyle {
+        name: "academic",
+        description:
+            "Curriculum vitae style with small-caps headings, suited to long publication lists",
+        class_options: "11pt,a4paper",
+        page: PageMetrics {
+            lines_per_page: 52.0,
+            chars_per_line: 84,
+        },
+        preamble: r"\usepackage{lmodern}
+\usepackage[margin=1in]{geometry}
+\usepackage{titlesec}
+\titleformat{\section}{\large\scshape}{}{0em}{}[\vspace{-4pt}\rule{\textwidth}{0.4pt}]
+\newcommand{\resumeentry}[3]{\par\smallskip\textbf{#1}\ifx&#2&\else\\ #2\fi\hfill #3\par}
+\newcommand{\resumedetail}[1]{\textit{#1}\par}
+",
+    },
+];
+
+impl LatexStyle {
+    pub fn builtin() -> &'static [LatexStyle] {
+        &STYLES
+    }
+
+    pub fn find(name: &str) -> Option<&'static LatexStyle> {
+        STYLES
+            .iter()
+            .find(|style| style.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The document up to and including `\begin{document}`.
+    pub fn header(&self) -> String {
+        format!(
+            "\\documentclass[{}]{{article}}\n{}{}\\begin{{document}}\n",
+            self.class_options, COMMON_PREAMBLE, self.preamble
+        )
+    }
+}
+
+/// Escapes text so LaTeX prints it literally.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str(r"\textbackslash{}"),
+            '~' => escaped.push_str(r"\textasciitilde{}"),
+            '^' => escaped.push_str(r"\textasciicircum{}"),
+            '%' | '&' | '_' | '#' | '$' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escapes a URL for the first argument of `\href`, which is read nearly
+/// verbatim: only `%`, `#` and braces need a backslash.
+pub fn escape_url(url: &str) -> String {
+    let mut escaped = String::with_capacity(url.len());
+    for c in url.chars() {
+        if matches!(c, '%' | '#' | '{' | '}' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -0,0 +1,224 @@
+use crate::resume::formatter::{FormattingOptions, OutputFormat, ResumeFormatter};
+use crate::resume::latex::{escape, escape_url, LatexStyle};
+use crate::resume::model::{
+    Certification, Experience, Language, LanguageProficiency, Profile, Publication, Resume,
+    Volunteer,
+};
+use crate::resume::test_support::{date, experience, skills, strings};
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use std::collections::HashMap;
+
+fn create_test_resume() -> Resume {
+    let mut resume = Resume::new(Profile {
+        name: "Jane Smith".to_string(),
+        title: "Research Engineer".to_string(),
+        email: "jane_smith@example.com".to_string(),
+        website: "https://example.com/~jane#cv".to_string(),
+        summary: "Cut costs by 30% & shipped C# tooling.".to_string(),
+        ..Profile::default()
+    });
+    resume.experiences.push(Experience {
+        start_date: date(2020, 3),
+        current: true,
+        achievements: strings(&["Saved $2M with a {fast} path in C:\\tools"]),
+        technologies: strings(&["Rust"]),
+        ..experience("Senior Engineer", "Acme & Sons")
+    });
+    resume.skills.technical = skills(&["C#"]);
+    resume.certifications.push(Certification {
+        name: "CKA".to_string(),
+        issuer: "CNCF".to_string(),
+        date_obtained: date(2021, 5),
+        expiry_date: None,
+        credential_id: Some("LF-123_45".to_string()),
+        url: None,
+    });
+    resume.languages.push(Language {
+        name: "German".to_string(),
+        proficiency: LanguageProficiency::FullProfessional,
+    });
+    resume.publications.push(Publication {
+        title: "Consensus at 99% Availability".to_string(),
+        publisher: "ACM".to_string(),
+        published_date: date(2022, 7),
+        authors: vec!["J. Smith".to_string(), "A. Jones".to_string()],
+        url: Some("https://doi.org/10.1145/1234%20x".to_string()),
+        description: "".to_string(),
+        translations: HashMap::new(),
+    });
+    resume.volunteer.push(Volunteer {
+        organization: "Code Club".to_string(),
+        role: "Mentor".to_string(),
+        start_date: date(2019, 1),
+        end_date: None,
+        current: true,
+        description: "Teaches kids to code.".to_string(),
+        location: None,
+        translations: HashMap::new(),
+    });
+    resume
+}
+
+fn render(options: FormattingOptions) -> Result<String, String> {
+    ResumeFormatter::with_options(options)
+        .format(&create_test_resume(), OutputFormat::LaTeX)
+        .map(|result| result.content)
+}
+
+#[rstest]
+#[case("100%", r"100\%")]
+#[case("R&D", r"R\&D")]
+#[case("snake_case", r"snake\_case")]
+#[case("C#", r"C\#")]
+#[case("$5", r"\$5")]
+#[case(r"C:\tools", r"C:\textbackslash{}tools")]
+#[case("{x}", r"\{x\}")]
+#[case("~/bin ^2", r"\textasciitilde{}/bin \textasciicircum{}2")]
+#[case("Zürich", "Zürich")]
+fn test_escape(#[case] text: &str, #[case] expected: &str) {
+    assert_eq!(escape(text), expected);
+}
+
+#[test]
+fn test_escape_url() {
+    assert_eq!(
+        escape_url("https://example.com/a%20b#top?x=1&y_z"),
+        r"https://example.com/a\%20b\#top?x=1&y_z"
+    );
+}
+
+#[test]
+fn test_standalone_document() {
+    let content = render(FormattingOptions::default()).unwrap();
+
+    assert!(content.starts_with("\\documentclass[11pt,letterpaper]{article}\n"));
+    assert!(content.contains("\\begin{document}\n"));
+    assert!(content.ends_with("\\end{document}\n"));
+    assert_eq!(
+        content.matches("\\begin{itemize}").count(),
+        content.matches("\\end{itemize}").count()
+    );
+    assert!(content.contains("{\\LARGE\\bfseries Jane Smith}\\\\\n"));
+    assert!(content.contains("jane\\_smith@example.com"));
+    assert!(content.contains(
+        "\\href{https://example.com/~jane\\#cv}{https://example.com/\\textasciitilde{}jane\\#cv}"
+    ));
+}
+
+#[test]
+fn test_content_is_escaped() {
+    let content = render(FormattingOptions::default()).unwrap();
+
+    assert!(content.contains("Cut costs by 30\\% \\& shipped C\\# tooling.\\par\n"));
+    assert!(
+        content.contains("\\resumeentry{Senior Engineer}{Acme \\& Sons}{March 2020 - Present}\n")
+    );
+    assert!(content
+        .contains("  \\item Saved \\$2M with a \\{fast\\} path in C:\\textbackslash{}tools\n"));
+    assert!(content.contains("\\textbf{Technical Skills:} C\\#\\par\n"));
+    assert!(content.contains("\\resumedetail{Credential ID: LF-123\\_45}\n"));
+}
+
+#[test]
+fn test_renders_every_section() {
+    let content = render(FormattingOptions::default()).unwrap();
+
+    let sections: Vec<&str> = content
+        .lines()
+        .filter_map(|line| line.strip_prefix("\\section*{"))
+        .map(|line| line.trim_end_matches('}'))
+        .collect();
+    assert_eq!(
+        sections,
+        vec![
+            "Professional Summary",
+            "Work Experience",
+            "Skills",
+            "Certifications",
+            "Languages",
+            "Publications",
+            "Volunteer Experience",
+        ]
+    );
+    assert!(content.contains("\\resumeentry{Consensus at 99\\% Availability}{ACM}{July 2022}\n"));
+    assert!(content.contains("\\resumedetail{J. Smith, A. Jones}\n"));
+    assert!(content.contains("\\href{https://doi.org/10.1145/1234\\%20x}"));
+    assert!(content.contains("German (Full professional)\\par\n"));
+    assert!(content.contains("\\resumeentry{Mentor}{Code Club}{January 2019 - Present}\n"));
+}
+
+#[test]
+fn test_section_toggle() {
+    let mut options = FormattingOptions::default();
+    options
+        .section_options
+        .insert("publications".to_string(), false);
+
+    let content = render(options).unwrap();
+    assert!(!content.contains("\\section*{Publications}"));
+    assert!(content.contains("\\section*{Certifications}"));
+}
+
+#[rstest]
+#[case("classic", "\\usepackage[margin=1in]{geometry}")]
+#[case("modern", "\\renewcommand{\\familydefault}{\\sfdefault}")]
+#[case("minimal", "\\documentclass[10pt,letterpaper]{article}")]
+#[case("academic", "\\documentclass[11pt,a4paper]{article}")]
+fn test_builtin_styles(#[case] style: &str, #[case] expected: &str) {
+    let mut options = FormattingOptions::default();
+    options
+        .custom_options
+        .insert("latex_style".to_string(), style.to_string());
+
+    let content = render(options).unwrap();
+    assert!(content.contains(expected));
+    // Each style defines the macros used by the body
+    assert!(content.contains("\\newcommand{\\resumeentry}[3]"));
+    assert!(content.contains("\\newcommand{\\resumedetail}[1]"));
+}
+
+#[test]
+fn test_style_follows_template() {
+    let options = FormattingOptions {
+        template: "minimal".to_string(),
+        ..FormattingOptions::default()
+    };
+    let content = render(options).unwrap();
+    assert!(content.starts_with(&LatexStyle::find("minimal").unwrap().header()));
+}
+
+#[test]
+fn test_unknown_style() {
+    let mut options = FormattingOptions::default();
+    options
+        .custom_options
+        .insert("latex_style".to_string(), "fancy".to_string());
+
+    assert_eq!(
+        render(options).unwrap_err(),
+        "LaTeX style 'fancy' not found"
+    );
+}
+
+#[test]
+fn test_template_without_style_falls_back_to_classic() {
+    let mut formatter = ResumeFormatter::with_options(FormattingOptions {
+        template: "custom".to_string(),
+        ..FormattingOptions::default()
+    });
+    formatter
+        .load_template_from_file("custom", std::path::Path::new("src/templates/modern.md"))
+        .unwrap();
+
+    let result = formatter
+        .format(&create_test_resume(), OutputFormat::LaTeX)
+        .unwrap();
+    assert_eq!(result.format.extension(), "tex");
+    assert!(result
+        .content
+        .starts_with(&LatexStyle::find("classic").unwrap().header()));
+    assert!(result
+        .warnings
+        .contains(&"No LaTeX style for template 'custom', using 'classic'".to_string()));
+}
@@ -0,0 +1,519 @@
+use chrono::{Datelike, NaiveDate};
+
+/// A built-in output language: translations of the fixed labels written by
+/// the formatter, month names and the usual date format.
+///
+/// Labels are looked up by their English text. Labels with values use
+/// numbered placeholders, e.g. "{0} at {1}", so a translation can reorder
+/// them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Locale {
+    pub code: &'static str,
+    pub name: &'static str,
+    /// Date format set by `FormattingOptions::for_locale`.
+    pub date_format: &'static str,
+    /// Month names used for `%B` in date formats.
+    pub months: [&'static str; 12],
+    /// Abbreviated month names used for `%b` in date formats.
+    pub short_months: [&'static str; 12],
+    /// English label and its translation. Labels missing here are written
+    /// in English, e.g. "GitHub".
+    pub labels: &'static [(&'static str, &'static str)],
+    /// Frequent short words used by `Locale::detect`. Chinese is detected
+    /// by its script instead.
+    pub common_words: &'static [&'static str],
+}
+
+const LOCALES: [Locale; 5] = [
+    Locale {
+        code: "en",
+        name: "English",
+        date_format: "%B %Y",
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        short_months: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        labels: &[],
+        common_words: &[
+            "the",
+            "and",
+            "of",
+            "to",
+            "with",
+            "for",
+            "you",
+            "we",
+            "our",
+            "will",
+            "are",
+            "is",
+            "this",
+            "that",
+            "be",
+            "on",
+            "as",
+            "your",
+            "team",
+            "experience",
+        ],
+    },
+    Locale {
+        code: "de",
+        name: "Deutsch",
+        date_format: "%B %Y",
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        short_months: [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+        labels: &[
+            ("Contact Information", "Kontakt"),
+            ("Professional Summary", "Profil"),
+            ("Work Experience", "Berufserfahrung"),
+            ("Education", "Ausbildung"),
+            ("Skills", "Kenntnisse"),
+            ("Projects", "Projekte"),
+            ("Certifications", "Zertifikate"),
+            ("Languages", "Sprachen"),
+            ("Publications", "Publikationen"),
+            ("Volunteer Experience", "Ehrenamtliches Engagement"),
+            ("Email", "E-Mail"),
+            ("Phone", "Telefon"),
+            ("Location", "Ort"),
+            ("Website", "Webseite"),
+            ("Key Achievements", "Wichtigste Erfolge"),
+            ("Technologies", "Technologien"),
+            ("GPA", "Notendurchschnitt"),
+            ("Relevant Courses", "Relevante Kurse"),
+            ("Achievements", "Erfolge"),
+            ("Technical Skills", "Fachkenntnisse"),
+            ("Soft Skills", "Soziale Kompetenzen"),
+            ("Tools", "Werkzeuge"),
+            ("Other", "Sonstiges"),
+            ("Project Link", "Projektlink"),
+            ("Credential ID", "Zertifikats-ID"),
+            ("Credential", "Nachweis"),
+            ("Verify Credential", "Zertifikat prüfen"),
+            ("Authors", "Autoren"),
+            ("Publication Link", "Link zur Publikation"),
+            ("Elementary", "Grundkenntnisse"),
+            ("Limited working", "Gute Kenntnisse"),
+            ("Professional working", "Sehr gute Kenntnisse"),
+            ("Full professional", "Verhandlungssicher"),
+            ("Native", "Muttersprache"),
+            ("Present", "heute"),
+            ("{0} at {1}", "{0} bei {1}"),
+            ("From {0}", "Seit {0}"),
+            ("Until {0}", "Bis {0}"),
+            ("Issued {0}", "Ausgestellt {0}"),
+            ("Expires {0}", "Gültig bis {0}"),
+            ("Issued {0}, expires {1}", "Ausgestellt {0}, gültig bis {1}"),
+            ("{0} - Resume", "{0} - Lebenslauf"),
+            ("{0}'s Resume", "Lebenslauf von {0}"),
+        ],
+        common_words: &[
+            "der",
+            "die",
+            "das",
+            "und",
+            "mit",
+            "für",
+            "wir",
+            "sie",
+            "ist",
+            "ein",
+            "eine",
+            "einen",
+            "den",
+            "von",
+            "zu",
+            "im",
+            "auf",
+            "bei",
+            "ihre",
+            "des",
+            "dem",
+            "sind",
+            "oder",
+            "erfahrung",
+        ],
+    },
+    Locale {
+        code: "fr",
+        name: "Français",
+        date_format: "%B %Y",
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        short_months: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        labels: &[
+            ("Contact Information", "Coordonnées"),
+            ("Professional Summary", "Profil professionnel"),
+            ("Work Experience", "Expérience professionnelle"),
+            ("Education", "Formation"),
+            ("Skills", "Compétences"),
+            ("Projects", "Projets"),
+            ("Languages", "Langues"),
+            ("Volunteer Experience", "Bénévolat"),
+            ("Email", "E-mail"),
+            ("Phone", "Téléphone"),
+            ("Location", "Lieu"),
+            ("Website", "Site web"),
+            ("Key Achievements", "Réalisations clés"),
+            ("GPA", "Moyenne"),
+            ("Relevant Courses", "Cours suivis"),
+            ("Achievements", "Réalisations"),
+            ("Technical Skills", "Compétences techniques"),
+            ("Soft Skills", "Savoir-être"),
+            ("Tools", "Outils"),
+            ("Other", "Autres"),
+            ("Link", "Lien"),
+            ("Project Link", "Lien du projet"),
+            ("Repository", "Dépôt"),
+            ("Highlights", "Points forts"),
+            ("Credential ID", "Identifiant du certificat"),
+            ("Credential", "Certificat"),
+            ("Verify Credential", "Vérifier le certificat"),
+            ("Authors", "Auteurs"),
+            ("Publication Link", "Lien de la publication"),
+            ("Elementary", "Notions"),
+            ("Limited working", "Intermédiaire"),
+            ("Professional working", "Avancé"),
+            ("Full professional", "Courant"),
+            ("Native", "Langue maternelle"),
+            ("Present", "aujourd'hui"),
+            ("{0} at {1}", "{0} chez {1}"),
+            ("{0} in {1}", "{0} en {1}"),
+            ("From {0}", "Depuis {0}"),
+            ("Until {0}", "Jusqu'en {0}"),
+            ("Issued {0}", "Obtenu en {0}"),
+            ("Expires {0}", "Expire en {0}"),
+            ("Issued {0}, expires {1}", "Obtenu en {0}, expire en {1}"),
+            ("{0} - Resume", "{0} - CV"),
+            ("{0}'s Resume", "CV de {0}"),
+        ],
+        common_words: &[
+            "le",
+            "les",
+            "et",
+            "des",
+            "du",
+            "une",
+            "pour",
+            "avec",
+            "nous",
+            "vous",
+            "est",
+            "dans",
+            "sur",
+            "au",
+            "aux",
+            "vos",
+            "notre",
+            "votre",
+            "sont",
+            "ou",
+            "expérience",
+        ],
+    },
+    Locale {
+        code: "es",
+        name: "Español",
+        date_format: "%B de %Y",
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        short_months: [
+            "ene.", "feb.", "mar.", "abr.", "may.", "jun.", "jul.", "ago.", "sept.", "oct.",
+            "nov.", "dic.",
+        ],
+        labels: &[
+            ("Contact Information", "Contacto"),
+            ("Professional Summary", "Perfil profesional"),
+            ("Work Experience", "Experiencia laboral"),
+            ("Education", "Formación académica"),
+            ("Skills", "Habilidades"),
+            ("Projects", "Proyectos"),
+            ("Certifications", "Certificaciones"),
+            ("Languages", "Idiomas"),
+            ("Publications", "Publicaciones"),
+            ("Volunteer Experience", "Voluntariado"),
+            ("Email", "Correo electrónico"),
+            ("Phone", "Teléfono"),
+            ("Location", "Ubicación"),
+            ("Website", "Sitio web"),
+            ("Key Achievements", "Logros principales"),
+            ("Technologies", "Tecnologías"),
+            ("GPA", "Promedio"),
+            ("Relevant Courses", "Cursos relevantes"),
+            ("Achievements", "Logros"),
+            ("Technical Skills", "Habilidades técnicas"),
+            ("Soft Skills", "Habilidades blandas"),
+            ("Tools", "Herramientas"),
+            ("Other", "Otros"),
+            ("Link", "Enlace"),
+            ("Project Link", "Enlace del proyecto"),
+            ("Repository", "Repositorio"),
+            ("Highlights", "Aspectos destacados"),
+            ("Credential ID", "ID de credencial"),
+            ("Credential", "Credencial"),
+            ("Verify Credential", "Verificar credencial"),
+            ("Authors", "Autores"),
+            ("Publication Link", "Enlace de la publicación"),
+            ("Elementary", "Básico"),
+            ("Limited working", "Intermedio"),
+            ("Professional working", "Avanzado"),
+            ("Full professional", "Bilingüe"),
+            ("Native", "Nativo"),
+            ("Present", "actualidad"),
+            ("{0} at {1}", "{0} en {1}"),
+            ("{0} in {1}", "{0} en {1}"),
+            ("From {0}", "Desde {0}"),
+            ("Until {0}", "Hasta {0}"),
+            ("Issued {0}", "Emitido en {0}"),
+            ("Expires {0}", "Vence en {0}"),
+            ("Issued {0}, expires {1}", "Emitido en {0}, vence en {1}"),
+            ("{0} - Resume", "{0} - Currículum"),
+            ("{0}'s Resume", "Currículum de {0}"),
+        ],
+        common_words: &[
+            "el",
+            "los",
+            "las",
+            "y",
+            "con",
+            "para",
+            "una",
+            "por",
+            "del",
+            "se",
+            "nuestro",
+            "nuestra",
+            "somos",
+            "buscamos",
+            "o",
+            "experiencia",
+        ],
+    },
+    Locale {
+        code: "zh",
+        name: "中文",
+        date_format: "%Y年%-m月",
+        months: [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        short_months: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        labels: &[
+            ("Contact Information", "联系方式"),
+            ("Professional Summary", "个人简介"),
+            ("Work Experience", "工作经历"),
+            ("Education", "教育背景"),
+            ("Skills", "技能"),
+            ("Projects", "项目经历"),
+            ("Certifications", "证书"),
+            ("Languages", "语言能力"),
+            ("Publications", "出版物"),
+            ("Volunteer Experience", "志愿者经历"),
+            ("Email", "邮箱"),
+            ("Phone", "电话"),
+            ("Location", "所在地"),
+            ("Website", "个人网站"),
+            ("Key Achievements", "主要成就"),
+            ("Technologies", "技术栈"),
+            ("Relevant Courses", "相关课程"),
+            ("Achievements", "成就"),
+            ("Technical Skills", "专业技能"),
+            ("Soft Skills", "软技能"),
+            ("Tools", "工具"),
+            ("Other", "其他"),
+            ("Link", "链接"),
+            ("Project Link", "项目链接"),
+            ("Repository", "代码仓库"),
+            ("Highlights", "亮点"),
+            ("Credential ID", "证书编号"),
+            ("Credential", "证书"),
+            ("Verify Credential", "验证证书"),
+            ("Authors", "作者"),
+            ("Publication Link", "出版物链接"),
+            ("Elementary", "入门"),
+            ("Limited working", "有限工作能力"),
+            ("Professional working", "专业工作能力"),
+            ("Full professional", "完全专业能力"),
+            ("Native", "母语"),
+            ("Present", "至今"),
+            ("{0} at {1}", "{1} · {0}"),
+            ("{0} in {1}", "{1} · {0}"),
+            ("From {0}", "{0}起"),
+            ("Until {0}", "至{0}"),
+            ("Issued {0}", "{0}获得"),
+            ("Expires {0}", "{0}到期"),
+            ("Issued {0}, expires {1}", "{0}获得，{1}到期"),
+            ("{0} - Resume", "{0} - 简历"),
+            ("{0}'s Resume", "{0}的简历"),
+        ],
+        common_words: &[],
+    },
+];
+
+impl Locale {
+    pub fn builtin() -> &'static [Locale] {
+        &LOCALES
+    }
+
+    /// Finds a locale by language code. Region subtags are ignored, so
+    /// "de-AT" and "zh_CN" find "de" and "zh".
+    pub fn find(code: &str) -> Option<&'static Locale> {
+        let language = code.split(['-', '_']).next().unwrap_or(code);
+        LOCALES
+            .iter()
+            .find(|locale| locale.code.eq_ignore_ascii_case(language))
+    }
+
+    /// The translation of an English label, or the label itself when the
+    /// locale doesn't translate it.
+    pub fn text(&self, label: &'static str) -> &'static str {
+        self.labels
+            .iter()
+            .find(|(english, _)| *english == label)
+            .map(|(_, translated)| *translated)
+            .unwrap_or(label)
+    }
+
+    /// Guesses the language of `text`, e.g. a job description, from its
+    /// script and most frequent words.
+    pub fn detect(text: &str) -> Option<&'static Locale> {
+        let letters = text.chars().filter(|c| c.is_alphabetic()).count();
+        let han = text.chars().filter(|c| is_han(*c)).count();
+        if letters == 0 {
+            return None;
+        }
+        // Chinese postings mix in English product and technology names
+        if han * 4 >= letters {
+            return Locale::find("zh");
+        }
+
+        let words: Vec<String> = text
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        LOCALES
+            .iter()
+            .map(|locale| {
+                let hits = words
+                    .iter()
+                    .filter(|word| locale.common_words.contains(&word.as_str()))
+                    .count();
+                (locale, hits)
+            })
+            .filter(|(_, hits)| *hits > 0)
+            .rev()
+            .max_by_key(|(_, hits)| *hits)
+            .map(|(locale, _)| locale)
+    }
+
+    /// Translates a label with numbered placeholders and fills them in
+    /// with `values`.
+    pub fn fill(&self, label: &'static str, values: &[&str]) -> String {
+        let pattern = self.text(label);
+        let mut filled = String::with_capacity(pattern.len());
+        let mut rest = pattern;
+        while let Some(start) = rest.find('{') {
+            filled.push_str(&rest[..start]);
+            let placeholder = rest[start + 1..].find('}').and_then(|end| {
+                let index = rest[start + 1..start + 1 + end].parse::<usize>().ok()?;
+                Some((index, start + end + 2))
+            });
+            match placeholder.and_then(|(index, next)| Some((values.get(index)?, next))) {
+                Some((value, next)) => {
+                    filled.push_str(value);
+                    rest = &rest[next..];
+                }
+                None => {
+                    filled.push('{');
+                    rest = &rest[start + 1..];
+                }
+            }
+        }
+        filled.push_str(rest);
+        filled
+    }
+
+    /// Formats a date with a chrono format string, using the locale's
+    /// month names for `%B` and `%b`.
+    pub fn format_date(&self, date: NaiveDate, format: &str) -> String {
+        let month = date.month0() as usize;
+        let format = format
+            .replace("%B", self.months[month])
+            .replace("%b", self.short_months[month]);
+        date.format(&format).to_string()
+    }
+}
+
+fn is_han(c: char) -> bool {
+    matches!(c, '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}' | '\u{F900}'..='\u{FAFF}')
+}
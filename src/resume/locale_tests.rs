@@ -0,0 +1,106 @@
+use crate::resume::locale::Locale;
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+fn locale(code: &str) -> &'static Locale {
+    Locale::find(code).unwrap()
+}
+
+#[rstest]
+#[case("de", "de")]
+#[case("DE", "de")]
+#[case("de-AT", "de")]
+#[case("zh_CN", "zh")]
+#[case("es-419", "es")]
+fn test_find(#[case] code: &str, #[case] expected: &str) {
+    assert_eq!(locale(code).code, expected);
+}
+
+#[test]
+fn test_find_unknown() {
+    assert!(Locale::find("ja").is_none());
+}
+
+#[test]
+fn test_text() {
+    assert_eq!(locale("de").text("Work Experience"), "Berufserfahrung");
+    assert_eq!(locale("en").text("Work Experience"), "Work Experience");
+    // Untranslated labels stay in English
+    assert_eq!(locale("fr").text("Technologies"), "Technologies");
+}
+
+#[test]
+fn test_translations_keep_placeholders() {
+    for locale in Locale::builtin() {
+        for (label, translated) in locale.labels {
+            for placeholder in ["{0}", "{1}"] {
+                assert_eq!(
+                    label.contains(placeholder),
+                    translated.contains(placeholder),
+                    "{} translation of '{}'",
+                    locale.code,
+                    label
+                );
+            }
+        }
+    }
+}
+
+#[rstest]
+#[case("en", "Engineer at Acme")]
+#[case("de", "Engineer bei Acme")]
+#[case("zh", "Acme · Engineer")]
+fn test_fill(#[case] code: &str, #[case] expected: &str) {
+    assert_eq!(
+        locale(code).fill("{0} at {1}", &["Engineer", "Acme"]),
+        expected
+    );
+}
+
+#[test]
+fn test_fill_leaves_values_alone() {
+    assert_eq!(
+        locale("en").fill("{0} at {1}", &["{1}", "{x} Corp"]),
+        "{1} at {x} Corp"
+    );
+}
+
+#[rstest]
+#[case("en", "%B %Y", "March 2020")]
+#[case("de", "%B %Y", "März 2020")]
+#[case("fr", "%b %Y", "mars 2020")]
+#[case("es", "%B de %Y", "marzo de 2020")]
+#[case("zh", "%Y年%-m月", "2020年3月")]
+#[case("de", "%m/%Y", "03/2020")]
+fn test_format_date(#[case] code: &str, #[case] format: &str, #[case] expected: &str) {
+    let date = NaiveDate::from_ymd_opt(2020, 3, 1).unwrap();
+    assert_eq!(locale(code).format_date(date, format), expected);
+}
+
+#[rstest]
+#[case(
+    "We are looking for a backend engineer to join our team. You will own the billing platform.",
+    Some("en")
+)]
+#[case(
+    "Wir suchen eine Backend-Entwicklerin für unser Team. Sie sind verantwortlich für die Plattform.",
+    Some("de")
+)]
+#[case(
+    "Nous recherchons un ingénieur backend pour notre équipe. Vous serez responsable de la plateforme.",
+    Some("fr")
+)]
+#[case(
+    "Buscamos una ingeniera backend para nuestro equipo. Serás responsable del diseño de la plataforma.",
+    Some("es")
+)]
+#[case(
+    "我们正在招聘后端工程师，负责 Rust 和 Kubernetes 平台开发。",
+    Some("zh")
+)]
+#[case("Rust, Kubernetes, AWS", None)]
+#[case("", None)]
+fn test_detect(#[case] text: &str, #[case] expected: Option<&str>) {
+    assert_eq!(Locale::detect(text).map(|locale| locale.code), expected);
+}
@@ -0,0 +1,352 @@
+use crate::resume::model::{Resume, WorkArrangement, WorkAuthorization};
+use regex::Regex;
+
+/// Location constraints stated in a job description.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LocationRequirement {
+    /// Work arrangements the posting accepts; empty when it doesn't say.
+    pub arrangements: Vec<WorkArrangement>,
+    /// Offices the on-site or hybrid role is based in, e.g. "Berlin".
+    pub locations: Vec<String>,
+    /// Regions a remote role is limited to, e.g. "US" for "Remote - US only".
+    pub remote_regions: Vec<String>,
+    /// Regions the candidate must be authorized to work in.
+    pub authorization_regions: Vec<String>,
+    pub sponsorship_available: Option<bool>,
+}
+
+impl LocationRequirement {
+    pub fn is_empty(&self) -> bool {
+        self.arrangements.is_empty()
+            && self.locations.is_empty()
+            && self.remote_regions.is_empty()
+            && self.authorization_regions.is_empty()
+            && self.sponsorship_available.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LocationMatch {
+    pub requirement: LocationRequirement,
+    /// Constraints the profile conflicts with.
+    pub mismatches: Vec<String>,
+    /// Constraints that can't be checked because the profile doesn't say.
+    pub missing_details: Vec<String>,
+}
+
+const REGION_ALIASES: [(&str, &[&str]); 8] = [
+    (
+        "US",
+        &[
+            "us",
+            "u.s",
+            "usa",
+            "u.s.a",
+            "united states",
+            "united states of america",
+        ],
+    ),
+    (
+        "UK",
+        &["uk", "u.k", "united kingdom", "great britain", "britain"],
+    ),
+    ("EU", &["eu", "e.u", "european union", "europe", "eea"]),
+    ("Canada", &["canada"]),
+    ("Australia", &["australia"]),
+    ("India", &["india"]),
+    ("Switzerland", &["switzerland"]),
+    ("Singapore", &["singapore"]),
+];
+
+const EU_MEMBERS: [&str; 27] = [
+    "Austria",
+    "Belgium",
+    "Bulgaria",
+    "Croatia",
+    "Cyprus",
+    "Czech Republic",
+    "Denmark",
+    "Estonia",
+    "Finland",
+    "France",
+    "Germany",
+    "Greece",
+    "Hungary",
+    "Ireland",
+    "Italy",
+    "Latvia",
+    "Lithuania",
+    "Luxembourg",
+    "Malta",
+    "Netherlands",
+    "Poland",
+    "Portugal",
+    "Romania",
+    "Slovakia",
+    "Slovenia",
+    "Spain",
+    "Sweden",
+];
+
+/// Canonical name of a country or region, so "United States" and "USA" both
+/// become "US". Unknown names are returned trimmed.
+pub fn canonical_region(name: &str) -> String {
+    let trimmed = name.trim().trim_end_matches('.');
+    let lower = trimmed.to_lowercase();
+
+    REGION_ALIASES
+        .iter()
+        .find(|(_, aliases)| aliases.contains(&lower.as_str()))
+        .map(|(canonical, _)| canonical.to_string())
+        .or_else(|| {
+            EU_MEMBERS
+                .iter()
+                .find(|member| member.eq_ignore_ascii_case(trimmed))
+                .map(|member| member.to_string())
+        })
+        .unwrap_or_else(|| trimmed.to_string())
+}
+
+fn is_known_region(name: &str) -> bool {
+    let canonical = canonical_region(name);
+    REGION_ALIASES
+        .iter()
+        .any(|(region, _)| *region == canonical)
+        || EU_MEMBERS.contains(&canonical.as_str())
+}
+
+/// Whether `place` lies within `region`, e.g. Germany within the EU.
+pub fn region_contains(region: &str, place: &str) -> bool {
+    let region = canonical_region(region);
+    let place = canonical_region(place);
+    region.eq_ignore_ascii_case(&place) || (region == "EU" && EU_MEMBERS.contains(&place.as_str()))
+}
+
+pub fn extract_location_requirement(job_description: &str) -> LocationRequirement {
+    let mut requirement = LocationRequirement::default();
+
+    // "Not remote" or "remote work is not possible" must not read as a
+    // remote role
+    let negated_remote_re = Regex::new(
+        r"(?i)\b(?:not|no|non)[\s-]+(?:a\s+|fully\s+|100%\s+)?remote\b|\bremote(?:\s+(?:work|working|roles?|positions?))?\s+(?:is\s+|are\s+)?(?:not\s+(?:possible|available|an\s+option|offered|supported)|unavailable)\b",
+    )
+    .unwrap();
+    let job_description = &negated_remote_re.replace_all(job_description, "");
+
+    let arrangement_patterns = [
+        (
+            WorkArrangement::OnSite,
+            r"(?i)\b(?:on-?site|in[- ]office|in[- ]person)\b",
+        ),
+        (WorkArrangement::Hybrid, r"(?i)\bhybrid\b"),
+        (WorkArrangement::Remote, r"(?i)\bremote\b"),
+    ];
+    for (arrangement, pattern) in arrangement_patterns {
+        if Regex::new(pattern).unwrap().is_match(job_description) {
+            requirement.arrangements.push(arrangement);
+        }
+    }
+
+    let place = r"([A-Z](?:[A-Za-z'-]|\.[A-Z])*(?:,?\s+[A-Z](?:[A-Za-z'-]|\.[A-Z])*)*)";
+
+    let office_re = Regex::new(&format!(
+        r"(?i:\b(?:hybrid|on-?site|in[- ]office|based|located)\s+(?:in|at|from|out of)\s+(?:the\s+)?){}",
+        place
+    ))
+    .unwrap();
+    let labeled_re = Regex::new(r"(?im)^\s*location\s*:\s*(.+?)\s*$").unwrap();
+
+    let offices = office_re
+        .captures_iter(job_description)
+        .map(|caps| caps[1].to_string())
+        .chain(
+            labeled_re
+                .captures_iter(job_description)
+                .filter_map(|caps| {
+                    // "Location: Berlin (Hybrid)" names an office, "Location: Remote" doesn't
+                    let value = caps[1].split(['(', '|']).next().unwrap_or_default();
+                    let value = value.split(" - ").next().unwrap_or_default().trim();
+                    let lower = value.to_lowercase();
+                    (!value.is_empty()
+                        && !["remote", "hybrid", "anywhere"].contains(&lower.as_str()))
+                    .then(|| value.to_string())
+                }),
+        );
+    for office in offices {
+        push_unique(&mut requirement.locations, office);
+    }
+
+    let remote_region_re = Regex::new(&format!(
+        r"(?i:\bremote)(?:\s*[-–—:(,/]\s*|\s+(?i:in|within|from|across)\s+(?:the\s+)?){}",
+        place
+    ))
+    .unwrap();
+    let region_only_re = Regex::new(&format!(r"{}[\s-](?i:only|based)\b", place)).unwrap();
+    if requirement.arrangements.contains(&WorkArrangement::Remote) {
+        let regions = remote_region_re
+            .captures_iter(job_description)
+            .chain(region_only_re.captures_iter(job_description))
+            .flat_map(|caps| {
+                caps[1]
+                    .split(',')
+                    .map(|region| region.trim().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .filter(|region| is_known_region(region));
+        for region in regions {
+            push_unique(&mut requirement.remote_regions, canonical_region(&region));
+        }
+    }
+
+    let authorization_re = Regex::new(&format!(
+        r"(?i:\b(?:authori[sz]ed|eligible|permitted|legally able|right)\s+to\s+work\s+in\s+(?:the\s+)?){}",
+        place
+    ))
+    .unwrap();
+    for caps in authorization_re.captures_iter(job_description) {
+        for region in caps[1].split(',') {
+            push_unique(
+                &mut requirement.authorization_regions,
+                canonical_region(region),
+            );
+        }
+    }
+
+    let no_sponsorship_re = Regex::new(
+        r"(?i)\b(?:no|not|unable to|cannot|can't|won't|will not|do not|does not|without)\s+(?:\w+\s+){0,3}?(?:visa\s+)?sponsor",
+    )
+    .unwrap();
+    let sponsorship_re = Regex::new(
+        r"(?i)\b(?:sponsorship\s+(?:is\s+)?(?:available|offered|provided)|(?:will|can)\s+sponsor)",
+    )
+    .unwrap();
+    if no_sponsorship_re.is_match(job_description) {
+        requirement.sponsorship_available = Some(false);
+    } else if sponsorship_re.is_match(job_description) {
+        requirement.sponsorship_available = Some(true);
+    }
+
+    requirement
+}
+
+fn push_unique(values: &mut Vec<String>, value: String) {
+    if !values.iter().any(|v| v.eq_ignore_ascii_case(&value)) {
+        values.push(value);
+    }
+}
+
+pub fn match_location(resume: &Resume, job_description: &str) -> LocationMatch {
+    let requirement = extract_location_requirement(job_description);
+    let profile = &resume.profile;
+    let city = profile.location.city.trim();
+    let country = profile.location.country.trim();
+    let mut mismatches = Vec::new();
+    let mut missing_details = Vec::new();
+
+    if let Some(preference) = profile.remote_preference {
+        if !requirement.arrangements.is_empty() && !requirement.arrangements.contains(&preference) {
+            let labels: Vec<&str> = requirement.arrangements.iter().map(|a| a.label()).collect();
+            mismatches.push(format!(
+                "The job is {}, but you prefer {} work",
+                labels.join(" or "),
+                preference.label()
+            ));
+        }
+    }
+
+    let remote_allowed = requirement.arrangements.contains(&WorkArrangement::Remote);
+    if !remote_allowed && !requirement.locations.is_empty() {
+        if city.is_empty() && country.is_empty() {
+            missing_details.push(format!(
+                "The job is based in {}; add your city and country to your profile",
+                requirement.locations.join(" or ")
+            ));
+        } else {
+            let nearby = requirement.locations.iter().any(|office| {
+                let office = office.to_lowercase();
+                (!city.is_empty() && office.contains(&city.to_lowercase()))
+                    || (!country.is_empty()
+                        && office.split(',').any(|part| region_contains(part, country)))
+            });
+            if !nearby && !profile.willing_to_relocate {
+                mismatches.push(format!(
+                    "The job is based in {}, but you are located in {} and not open to relocation",
+                    requirement.locations.join(" or "),
+                    describe_location(city, country)
+                ));
+            }
+        }
+    }
+
+    if remote_allowed && !requirement.remote_regions.is_empty() {
+        let eligible = requirement.remote_regions.iter().any(|region| {
+            (!country.is_empty() && region_contains(region, country))
+                || find_authorization(&profile.work_authorizations, region).is_some()
+        });
+        // Without a country, authorizations elsewhere don't rule the
+        // candidate out
+        if !eligible && country.is_empty() {
+            missing_details.push(format!(
+                "The remote role is limited to {}; add your country to your profile",
+                requirement.remote_regions.join(" or ")
+            ));
+        } else if !eligible {
+            mismatches.push(format!(
+                "The remote role is limited to {}, but you are based in {}",
+                requirement.remote_regions.join(" or "),
+                describe_location(city, country)
+            ));
+        }
+    }
+
+    for region in &requirement.authorization_regions {
+        match find_authorization(&profile.work_authorizations, region) {
+            Some(authorization) => {
+                if authorization.requires_sponsorship
+                    && requirement.sponsorship_available == Some(false)
+                {
+                    mismatches.push(format!(
+                        "The job does not offer visa sponsorship, but your authorization to work in {} requires it",
+                        authorization.region
+                    ));
+                }
+            }
+            None if profile.work_authorizations.is_empty() => {
+                missing_details.push(format!(
+                    "The job requires authorization to work in {}; state your work authorization in your profile",
+                    region
+                ));
+            }
+            None => {
+                mismatches.push(format!(
+                    "The job requires authorization to work in {}, which is not among your work authorizations",
+                    region
+                ));
+            }
+        }
+    }
+
+    LocationMatch {
+        requirement,
+        mismatches,
+        missing_details,
+    }
+}
+
+fn find_authorization<'a>(
+    authorizations: &'a [WorkAuthorization],
+    region: &str,
+) -> Option<&'a WorkAuthorization> {
+    authorizations.iter().find(|authorization| {
+        region_contains(region, &authorization.region)
+            || region_contains(&authorization.region, region)
+    })
+}
+
+fn describe_location(city: &str, country: &str) -> String {
+    match (city.is_empty(), country.is_empty()) {
+        (false, false) => format!("{}, {}", city, country),
+        (false, true) => city.to_string(),
+        _ => country.to_string(),
+    }
+}
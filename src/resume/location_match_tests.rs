@@ -0,0 +1,189 @@
+use crate::resume::location_match::*;
+use crate::resume::model::{Location, Profile, Resume, WorkArrangement, WorkAuthorization};
+use crate::resume::optimizer::ResumeOptimizer;
+use crate::resume::test_support::profile;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+fn create_test_resume(city: &str, country: &str) -> Resume {
+    Resume::new(Profile {
+        location: Location {
+            city: city.to_string(),
+            country: country.to_string(),
+        },
+        ..profile()
+    })
+}
+
+fn authorization(region: &str, requires_sponsorship: bool) -> WorkAuthorization {
+    WorkAuthorization {
+        region: region.to_string(),
+        requires_sponsorship,
+    }
+}
+
+#[rstest]
+#[case("United States", "US")]
+#[case("U.S.", "US")]
+#[case("european union", "EU")]
+#[case("germany", "Germany")]
+#[case("Narnia", "Narnia")]
+fn test_canonical_region(#[case] name: &str, #[case] expected: &str) {
+    assert_eq!(canonical_region(name), expected);
+}
+
+#[test]
+fn test_region_contains() {
+    assert!(region_contains("EU", "Germany"));
+    assert!(region_contains("USA", "United States"));
+    assert!(!region_contains("Germany", "EU"));
+    assert!(!region_contains("EU", "UK"));
+}
+
+#[test]
+fn test_extract_remote_region() {
+    let requirement = extract_location_requirement("Senior Engineer\nRemote - US only");
+
+    assert_eq!(requirement.arrangements, vec![WorkArrangement::Remote]);
+    assert_eq!(requirement.remote_regions, vec!["US".to_string()]);
+    assert!(requirement.locations.is_empty());
+}
+
+#[rstest]
+#[case("This position is not remote. Work from our Berlin office.")]
+#[case("No remote work: the team is on-site in Berlin.")]
+#[case("Non-remote role based in Berlin")]
+#[case("Remote work is not possible for this role.")]
+fn test_negated_remote_is_not_remote(#[case] job: &str) {
+    let requirement = extract_location_requirement(job);
+    assert!(!requirement.arrangements.contains(&WorkArrangement::Remote));
+}
+
+#[test]
+fn test_extract_hybrid_office() {
+    let requirement =
+        extract_location_requirement("This role is hybrid in Berlin. We meet twice a week.");
+
+    assert_eq!(requirement.arrangements, vec![WorkArrangement::Hybrid]);
+    assert_eq!(requirement.locations, vec!["Berlin".to_string()]);
+}
+
+#[test]
+fn test_extract_authorization_and_sponsorship() {
+    let requirement = extract_location_requirement(
+        "Location: Amsterdam, Netherlands\nYou must be authorized to work in the EU. We are unable to sponsor visas.",
+    );
+
+    assert_eq!(
+        requirement.locations,
+        vec!["Amsterdam, Netherlands".to_string()]
+    );
+    assert_eq!(requirement.authorization_regions, vec!["EU".to_string()]);
+    assert_eq!(requirement.sponsorship_available, Some(false));
+}
+
+#[test]
+fn test_no_constraints() {
+    assert!(extract_location_requirement("Write Rust services with Postgres.").is_empty());
+}
+
+#[test]
+fn test_remote_region_mismatch() {
+    let resume = create_test_resume("Toronto", "Canada");
+    let location_match = match_location(&resume, "Remote (US only)");
+
+    assert_eq!(
+        location_match.mismatches,
+        vec!["The remote role is limited to US, but you are based in Toronto, Canada".to_string()]
+    );
+}
+
+#[test]
+fn test_remote_region_satisfied_by_authorization() {
+    let mut resume = create_test_resume("Toronto", "Canada");
+    resume
+        .profile
+        .work_authorizations
+        .push(authorization("United States", false));
+
+    assert!(match_location(&resume, "Remote - US only")
+        .mismatches
+        .is_empty());
+}
+
+#[test]
+fn test_remote_region_without_country() {
+    let mut resume = create_test_resume("", "");
+    resume
+        .profile
+        .work_authorizations
+        .push(authorization("Canada", false));
+
+    let location_match = match_location(&resume, "Remote (US only)");
+    assert!(location_match.mismatches.is_empty());
+    assert_eq!(
+        location_match.missing_details,
+        vec!["The remote role is limited to US; add your country to your profile".to_string()]
+    );
+}
+
+#[test]
+fn test_office_location_and_relocation() {
+    let mut resume = create_test_resume("Munich", "Germany");
+    resume.profile.remote_preference = Some(WorkArrangement::Hybrid);
+    let job = "Hybrid in Berlin";
+
+    let location_match = match_location(&resume, job);
+    assert_eq!(location_match.mismatches.len(), 1);
+    assert!(location_match.mismatches[0].contains("not open to relocation"));
+
+    resume.profile.willing_to_relocate = true;
+    assert!(match_location(&resume, job).mismatches.is_empty());
+}
+
+#[test]
+fn test_arrangement_preference_mismatch() {
+    let mut resume = create_test_resume("Berlin", "Germany");
+    resume.profile.remote_preference = Some(WorkArrangement::Remote);
+
+    let location_match = match_location(&resume, "Hybrid in Berlin");
+    assert_eq!(
+        location_match.mismatches,
+        vec!["The job is hybrid, but you prefer remote work".to_string()]
+    );
+}
+
+#[test]
+fn test_authorization_requiring_sponsorship() {
+    let mut resume = create_test_resume("Paris", "France");
+    resume
+        .profile
+        .work_authorizations
+        .push(authorization("France", true));
+
+    let job = "Must be authorized to work in the EU without visa sponsorship.";
+    let location_match = match_location(&resume, job);
+    assert_eq!(location_match.mismatches.len(), 1);
+    assert!(location_match.mismatches[0].contains("does not offer visa sponsorship"));
+}
+
+#[test]
+fn test_optimizer_reports_location_constraints() {
+    let optimizer = ResumeOptimizer::new();
+    let resume = create_test_resume("", "");
+
+    let result = optimizer.optimize(
+        &resume,
+        "Remote - US only. Must be authorized to work in the US.",
+    );
+
+    let location_match = result.location_match.unwrap();
+    assert_eq!(location_match.missing_details.len(), 2);
+    assert!(result
+        .suggestions
+        .iter()
+        .any(|s| s.contains("state your work authorization")));
+
+    let result = optimizer.optimize(&resume, "Rust and Postgres experience.");
+    assert!(result.location_match.is_none());
+}
@@ -0,0 +1,780 @@
+use crate::resume::model::{
+    parse_gpa, Certification, Education, Experience, Language, LanguageProficiency, Location,
+    Profile, Project, Publication, Resume, Skill, Volunteer,
+};
+use crate::resume::parser::{PartialDate, ResumeParser, SectionKind};
+use chrono::NaiveDate;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Rebuilds a `Resume` from Markdown laid out the way `format_markdown` and
+/// the bundled templates write it.
+///
+/// Only what the Markdown shows can come back: tags, skill levels and other
+/// fields that are not rendered keep their defaults, and a location without
+/// a comma is read as the city.
+pub struct MarkdownImporter {
+    dates: ResumeParser,
+    link_re: Regex,
+    bold_label_re: Regex,
+    plain_label_re: Regex,
+    classic_entry_re: Regex,
+    minimal_entry_re: Regex,
+    minimal_education_re: Regex,
+}
+
+impl Default for MarkdownImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Where bullets in the current entry go.
+#[derive(Clone, Copy, PartialEq)]
+enum List {
+    Achievements,
+    Courses,
+}
+
+/// Accumulates free text, keeping blank lines between paragraphs.
+#[derive(Default)]
+struct Paragraphs {
+    text: String,
+    after_blank: bool,
+}
+
+impl Paragraphs {
+    fn blank(&mut self) {
+        self.after_blank = true;
+    }
+
+    fn push(&mut self, line: &str) {
+        if !self.text.is_empty() {
+            self.text
+                .push_str(if self.after_blank { "\n\n" } else { "\n" });
+        }
+        self.text.push_str(line);
+        self.after_blank = false;
+    }
+
+    fn take(&mut self) -> String {
+        self.after_blank = false;
+        std::mem::take(&mut self.text)
+    }
+}
+
+impl MarkdownImporter {
+    pub fn new() -> Self {
+        Self {
+            dates: ResumeParser::new(),
+            link_re: Regex::new(r"\[([^\]]*)\]\(([^)]*)\)").unwrap(),
+            bold_label_re: Regex::new(r"^\*\*([^*]+?):\*\*\s*(.*)$|^\*\*([^*]+?)\*\*:\s*(.*)$")
+                .unwrap(),
+            plain_label_re: Regex::new(r"^([A-Z][A-Za-z ]{1,30}):\s*(.*)$").unwrap(),
+            classic_entry_re: Regex::new(r"^\*\*(.+?)\*\*\s*\|\s*(.*?)\s*\|\s*(.*)$").unwrap(),
+            minimal_entry_re: Regex::new(r"^\*\*(.+?)\*\*,\s*(.*?)\s*\((.*)\)$").unwrap(),
+            minimal_education_re: Regex::new(r"^\*\*(.+?)\*\*\s+in\s+(.*?),\s*(.*?)\s*\((.*)\)$")
+                .unwrap(),
+        }
+    }
+
+    pub fn import(&self, markdown: &str) -> Result<Resume, String> {
+        let mut lines = markdown.lines().map(str::trim_end);
+        let name = lines
+            .by_ref()
+            .find_map(|line| line.strip_prefix("# "))
+            .ok_or_else(|| "Markdown resume must start with a '# Name' heading".to_string())?;
+
+        let mut resume = Resume::new(Profile {
+            name: name.trim().to_string(),
+            ..Profile::default()
+        });
+
+        // Lines between the name and the first section, then each section
+        let mut header = vec![];
+        let mut sections: Vec<(&str, Vec<&str>)> = vec![];
+        for line in lines {
+            match line.strip_prefix("## ") {
+                Some(heading) => sections.push((heading.trim(), vec![])),
+                None => match sections.last_mut() {
+                    Some((_, section_lines)) => section_lines.push(line),
+                    None => header.push(line),
+                },
+            }
+        }
+
+        self.import_contact(&header, &mut resume.profile);
+        for (index, (heading, section_lines)) in sections.iter().enumerate() {
+            match SectionKind::from_heading(heading) {
+                Some(SectionKind::Contact) => {
+                    self.import_contact(section_lines, &mut resume.profile)
+                }
+                Some(SectionKind::Summary) => {
+                    resume.profile.summary = self.paragraphs(section_lines)
+                }
+                Some(SectionKind::Experience) => {
+                    resume.experiences = self.import_experiences(section_lines)
+                }
+                Some(SectionKind::Education) => {
+                    resume.education = self.import_education(section_lines)
+                }
+                Some(SectionKind::Skills) => self.import_skills(section_lines, &mut resume),
+                Some(SectionKind::Projects) => {
+                    resume.projects = self.import_projects(section_lines)
+                }
+                Some(SectionKind::Certifications) => {
+                    resume.certifications = self.import_certifications(section_lines)
+                }
+                Some(SectionKind::Languages) => resume.languages = import_languages(section_lines),
+                Some(SectionKind::Publications) => {
+                    resume.publications = self.import_publications(section_lines)
+                }
+                Some(SectionKind::Volunteer) => {
+                    resume.volunteer = self.import_volunteer(section_lines)
+                }
+                // A heading right below the name is the professional title,
+                // possibly followed by the summary as in the modern template
+                None if index == 0 => {
+                    resume.profile.title = heading.to_string();
+                    let summary = self.paragraphs(section_lines);
+                    if !summary.is_empty() {
+                        resume.profile.summary = summary;
+                    }
+                }
+                None => {}
+            }
+        }
+
+        Ok(resume)
+    }
+
+    fn import_contact(&self, lines: &[&str], profile: &mut Profile) {
+        for line in lines {
+            let line = strip_bullet(line.trim());
+            if let Some((label, value)) = self.label(line) {
+                let value = self.link_target(&value);
+                match label.to_lowercase().as_str() {
+                    "email" => profile.email = value,
+                    "phone" => profile.phone = value,
+                    "location" => profile.location = parse_location(&value),
+                    "linkedin" => profile.linkedin = value,
+                    "github" => profile.github = value,
+                    "website" => profile.website = value,
+                    _ => {}
+                }
+                continue;
+            }
+
+            // "jane@example.com | 555 0100 | Berlin" or "[LinkedIn](url) | [GitHub](url)"
+            for part in line.split(" | ").map(str::trim).filter(|p| !p.is_empty()) {
+                if let Some(caps) = self.link_re.captures(part) {
+                    match caps[1].to_lowercase().as_str() {
+                        "linkedin" => profile.linkedin = caps[2].to_string(),
+                        "github" => profile.github = caps[2].to_string(),
+                        _ => profile.website = caps[2].to_string(),
+                    }
+                } else if part.contains('@') {
+                    profile.email = part.to_string();
+                } else if part.chars().filter(char::is_ascii_digit).count() >= 7 {
+                    profile.phone = part.to_string();
+                } else if line.contains(" | ") {
+                    profile.location = parse_location(part);
+                }
+            }
+        }
+    }
+
+    fn import_experiences(&self, lines: &[&str]) -> Vec<Experience> {
+        let mut experiences: Vec<Experience> = vec![];
+        let mut description = Paragraphs::default();
+        let mut expecting_dates = false;
+
+        for line in lines.iter().map(|line| line.trim()) {
+            if line.is_empty() || line == "---" {
+                description.blank();
+                continue;
+            }
+
+            if let Some((title, company, dates)) = self.experience_heading(line) {
+                if let Some(last) = experiences.last_mut() {
+                    last.description = description.take();
+                }
+                let mut experience = Experience {
+                    company,
+                    title,
+                    location: None,
+                    start_date: None,
+                    end_date: None,
+                    current: false,
+                    description: String::new(),
+                    achievements: vec![],
+                    technologies: vec![],
+                    tags: vec![],
+                    achievement_tags: HashMap::new(),
+                    translations: HashMap::new(),
+                };
+                if let Some(dates) = &dates {
+                    (
+                        experience.start_date,
+                        experience.end_date,
+                        experience.current,
+                    ) = self.date_range(dates);
+                }
+                expecting_dates = dates.is_none();
+                experiences.push(experience);
+                continue;
+            }
+
+            let Some(experience) = experiences.last_mut() else {
+                continue;
+            };
+            if std::mem::take(&mut expecting_dates) && is_emphasized(line) {
+                (
+                    experience.start_date,
+                    experience.end_date,
+                    experience.current,
+                ) = self.date_range(line);
+            } else if let Some(item) = bullet(line) {
+                experience.achievements.push(item.to_string());
+            } else if let Some((label, value)) = self.label(line) {
+                match label.to_lowercase().as_str() {
+                    "location" => experience.location = Some(value),
+                    "technologies" => experience.technologies = split_list(&value),
+                    "key achievements" | "achievements" | "responsibilities" => {}
+                    _ => description.push(line),
+                }
+            } else {
+                description.push(line);
+            }
+        }
+
+        if let Some(last) = experiences.last_mut() {
+            last.description = description.take();
+        }
+        experiences
+    }
+
+    /// Title, company and, when on the same line, the date range.
+    fn experience_heading(&self, line: &str) -> Option<(String, String, Option<String>)> {
+        if let Some(heading) = line.strip_prefix("### ") {
+            let heading = heading.strip_suffix(" at").unwrap_or(heading);
+            // Titles may contain " at " ("Engineer at Large"), companies rarely do
+            let (title, company) = heading.rsplit_once(" at ").unwrap_or((heading, ""));
+            return Some((title.trim().to_string(), company.trim().to_string(), None));
+        }
+        self.classic_entry_re
+            .captures(line)
+            .or_else(|| self.minimal_entry_re.captures(line))
+            .map(|caps| {
+                (
+                    caps[1].to_string(),
+                    caps[2].to_string(),
+                    Some(caps[3].to_string()),
+                )
+            })
+    }
+
+    fn import_education(&self, lines: &[&str]) -> Vec<Education> {
+        let mut education: Vec<Education> = vec![];
+        let mut description = Paragraphs::default();
+        let mut list = List::Achievements;
+        let mut expecting = (false, false); // (institution, dates)
+
+        for line in lines.iter().map(|line| line.trim()) {
+            if line.is_empty() || line == "---" {
+                description.blank();
+                continue;
+            }
+
+            if let Some((degree_line, institution, dates)) = self.education_heading(line) {
+                if let Some(last) = education.last_mut() {
+                    last.description = description.take();
+                }
+                // "Certificate in Data Science in Statistics": the field
+                // follows the last " in "
+                let (degree, field_of_study) = match degree_line.rsplit_once(" in ") {
+                    Some((degree, field)) => (degree.to_string(), field.to_string()),
+                    None => (
+                        degree_line.trim_end_matches(" in").to_string(),
+                        String::new(),
+                    ),
+                };
+                let mut entry = Education {
+                    institution: institution.clone().unwrap_or_default(),
+                    degree,
+                    field_of_study,
+                    location: None,
+                    start_date: None,
+                    end_date: None,
+                    current: false,
+                    gpa: None,
+                    gpa_scale: None,
+                    courses: vec![],
+                    achievements: vec![],
+                    description: String::new(),
+                    translations: HashMap::new(),
+                };
+                if let Some(dates) = &dates {
+                    (entry.start_date, entry.end_date, entry.current) = self.date_range(dates);
+                }
+                expecting = (institution.is_none(), dates.is_none());
+                list = List::Achievements;
+                education.push(entry);
+                continue;
+            }
+
+            let Some(entry) = education.last_mut() else {
+                continue;
+            };
+            if std::mem::take(&mut expecting.0) && line.starts_with("**") && line.ends_with("**") {
+                entry.institution = line.trim_matches('*').to_string();
+            } else if std::mem::take(&mut expecting.1) && is_emphasized(line) {
+                (entry.start_date, entry.end_date, entry.current) = self.date_range(line);
+            } else if let Some(item) = bullet(line) {
+                match list {
+                    List::Courses => entry.courses.push(item.to_string()),
+                    _ => entry.achievements.push(item.to_string()),
+                }
+            } else if let Some((label, value)) = self.label(line) {
+                match label.to_lowercase().as_str() {
+                    "gpa" => {
+                        if let Some((gpa, scale)) = parse_gpa(&value) {
+                            entry.gpa = Some(gpa);
+                            entry.gpa_scale = scale;
+                        }
+                    }
+                    "location" => entry.location = Some(value),
+                    "relevant courses" | "courses" => {
+                        list = List::Courses;
+                        entry.courses.extend(split_list(&value));
+                    }
+                    "achievements" => list = List::Achievements,
+                    _ => description.push(line),
+                }
+            } else {
+                description.push(line);
+            }
+        }
+
+        if let Some(last) = education.last_mut() {
+            last.description = description.take();
+        }
+        education
+    }
+
+    /// Degree line, institution and dates, as far as the heading has them.
+    fn education_heading(&self, line: &str) -> Option<(String, Option<String>, Option<String>)> {
+        if let Some(heading) = line.strip_prefix("### ") {
+            return Some((heading.to_string(), None, None));
+        }
+        if let Some(caps) = self.minimal_education_re.captures(line) {
+            return Some((
+                format!("{} in {}", &caps[1], &caps[2]),
+                Some(caps[3].to_string()),
+                Some(caps[4].to_string()),
+            ));
+        }
+        self.classic_entry_re.captures(line).map(|caps| {
+            (
+                caps[1].to_string(),
+                Some(caps[2].to_string()),
+                Some(caps[3].to_string()),
+            )
+        })
+    }
+
+    fn import_skills(&self, lines: &[&str], resume: &mut Resume) {
+        let mut category = "technical";
+
+        for line in lines.iter().map(|line| line.trim()) {
+            if line.is_empty() || line == "---" {
+                continue;
+            }
+
+            let items: Vec<String> = if let Some(heading) = line.strip_prefix("### ") {
+                category = skill_category(heading);
+                continue;
+            } else if let Some(item) = bullet(line) {
+                vec![strip_proficiency(item).to_string()]
+            } else if let Some((label, value)) = self.label(line) {
+                category = skill_category(&label);
+                split_list(&value)
+            } else {
+                split_list(line)
+            };
+
+            let list = match category {
+                "soft" => &mut resume.skills.soft,
+                "tools" => &mut resume.skills.tools,
+                "languages" => &mut resume.skills.languages,
+                "other" => &mut resume.skills.other,
+                _ => &mut resume.skills.technical,
+            };
+            list.extend(items.into_iter().map(|name| Skill {
+                name,
+                level: None,
+                years: None,
+                tags: vec![],
+            }));
+        }
+    }
+
+    fn import_projects(&self, lines: &[&str]) -> Vec<Project> {
+        let mut projects: Vec<Project> = vec![];
+        let mut description = Paragraphs::default();
+
+        for line in lines.iter().map(|line| line.trim()) {
+            if line.is_empty() || line == "---" {
+                description.blank();
+                continue;
+            }
+
+            let heading = line.strip_prefix("### ").map(str::to_string).or_else(|| {
+                // "**Name**" in the classic template, "**Name** ([GitHub](url))" in minimal
+                let rest = line.strip_prefix("**")?;
+                let (name, links) = rest.split_once("**")?;
+                (!name.ends_with(':') && (links.is_empty() || links.trim_start().starts_with('(')))
+                    .then(|| name.to_string())
+            });
+            if let Some(name) = heading {
+                if let Some(last) = projects.last_mut() {
+                    last.description = description.take();
+                }
+                let mut project = Project {
+                    name,
+                    description: String::new(),
+                    url: None,
+                    github: None,
+                    technologies: vec![],
+                    start_date: None,
+                    end_date: None,
+                    highlights: vec![],
+                    tags: vec![],
+                    translations: HashMap::new(),
+                };
+                for caps in self.link_re.captures_iter(line) {
+                    match caps[1].to_lowercase().as_str() {
+                        "github" => project.github = Some(caps[2].to_string()),
+                        _ => project.url = Some(caps[2].to_string()),
+                    }
+                }
+                projects.push(project);
+                continue;
+            }
+
+            let Some(project) = projects.last_mut() else {
+                continue;
+            };
+            if let Some(item) = bullet(line) {
+                project.highlights.push(item.to_string());
+            } else if let Some((label, value)) = self.label(line) {
+                match label.to_lowercase().as_str() {
+                    "link" | "url" => project.url = Some(self.link_target(&value)),
+                    "github" => project.github = Some(self.link_target(&value)),
+                    "technologies" => project.technologies = split_list(&value),
+                    "highlights" => {}
+                    _ => description.push(line),
+                }
+            } else {
+                description.push(line);
+            }
+        }
+
+        if let Some(last) = projects.last_mut() {
+            last.description = description.take();
+        }
+        projects
+    }
+
+    fn import_certifications(&self, lines: &[&str]) -> Vec<Certification> {
+        let mut certifications: Vec<Certification> = vec![];
+        let mut expecting = (false, false); // (issuer, dates)
+
+        for line in lines.iter().map(|line| line.trim()) {
+            if line.is_empty() || line == "---" {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix("### ") {
+                certifications.push(Certification {
+                    name: name.trim().to_string(),
+                    issuer: String::new(),
+                    date_obtained: None,
+                    expiry_date: None,
+                    credential_id: None,
+                    url: None,
+                });
+                expecting = (true, true);
+                continue;
+            }
+
+            let Some(cert) = certifications.last_mut() else {
+                continue;
+            };
+            if std::mem::take(&mut expecting.0) && is_bold(line) {
+                cert.issuer = line.trim_matches('*').to_string();
+                expecting.1 = true;
+            } else if std::mem::take(&mut expecting.1) && is_emphasized(line) {
+                (cert.date_obtained, cert.expiry_date) = self.certification_dates(line);
+            } else if let Some((label, value)) = self.label(line) {
+                match label.to_lowercase().as_str() {
+                    "credential id" => cert.credential_id = Some(value),
+                    "credential" | "url" | "link" => cert.url = Some(self.link_target(&value)),
+                    _ => {}
+                }
+            }
+        }
+        certifications
+    }
+
+    /// "Issued May 2021, expires May 2024", "Issued May 2021" or "Expires May 2024".
+    fn certification_dates(&self, line: &str) -> (Option<NaiveDate>, Option<NaiveDate>) {
+        let text = line.trim_matches(|c| c == '_' || c == '*').trim();
+        let date = |text: &str| self.dates.parse_date(text).and_then(to_date);
+        if let Some(expiry) = text.strip_prefix("Expires ") {
+            return (None, date(expiry));
+        }
+        let text = text.strip_prefix("Issued ").unwrap_or(text);
+        match text.split_once(", expires ") {
+            Some((obtained, expiry)) => (date(obtained), date(expiry)),
+            None => (date(text), None),
+        }
+    }
+
+    fn import_publications(&self, lines: &[&str]) -> Vec<Publication> {
+        let mut publications: Vec<Publication> = vec![];
+        let mut description = Paragraphs::default();
+        let mut expecting = (false, false); // (publisher, date)
+
+        for line in lines.iter().map(|line| line.trim()) {
+            if line.is_empty() || line == "---" {
+                description.blank();
+                continue;
+            }
+
+            if let Some(title) = line.strip_prefix("### ") {
+                if let Some(last) = publications.last_mut() {
+                    last.description = description.take();
+                }
+                publications.push(Publication {
+                    title: title.trim().to_string(),
+                    publisher: String::new(),
+                    published_date: None,
+                    authors: vec![],
+                    url: None,
+                    description: String::new(),
+                    translations: HashMap::new(),
+                });
+                expecting = (true, true);
+                continue;
+            }
+
+            let Some(publication) = publications.last_mut() else {
+                continue;
+            };
+            if std::mem::take(&mut expecting.0) && is_bold(line) {
+                publication.publisher = line.trim_matches('*').to_string();
+                expecting.1 = true;
+            } else if std::mem::take(&mut expecting.1) && is_emphasized(line) {
+                publication.published_date = self
+                    .dates
+                    .parse_date(line.trim_matches(|c| c == '_' || c == '*'))
+                    .and_then(to_date);
+            } else if let Some((label, value)) = self.label(line) {
+                match label.to_lowercase().as_str() {
+                    "authors" => publication.authors = split_list(&value),
+                    "link" | "url" => publication.url = Some(self.link_target(&value)),
+                    _ => description.push(line),
+                }
+            } else {
+                description.push(line);
+            }
+        }
+
+        if let Some(last) = publications.last_mut() {
+            last.description = description.take();
+        }
+        publications
+    }
+
+    fn import_volunteer(&self, lines: &[&str]) -> Vec<Volunteer> {
+        let mut volunteer: Vec<Volunteer> = vec![];
+        let mut description = Paragraphs::default();
+        let mut expecting_dates = false;
+
+        for line in lines.iter().map(|line| line.trim()) {
+            if line.is_empty() || line == "---" {
+                description.blank();
+                continue;
+            }
+
+            if let Some(heading) = line.strip_prefix("### ") {
+                if let Some(last) = volunteer.last_mut() {
+                    last.description = description.take();
+                }
+                let heading = heading.strip_suffix(" at").unwrap_or(heading);
+                let (role, organization) = heading.rsplit_once(" at ").unwrap_or((heading, ""));
+                volunteer.push(Volunteer {
+                    organization: organization.trim().to_string(),
+                    role: role.trim().to_string(),
+                    start_date: None,
+                    end_date: None,
+                    current: false,
+                    description: String::new(),
+                    location: None,
+                    translations: HashMap::new(),
+                });
+                expecting_dates = true;
+                continue;
+            }
+
+            let Some(entry) = volunteer.last_mut() else {
+                continue;
+            };
+            if std::mem::take(&mut expecting_dates) && is_emphasized(line) {
+                (entry.start_date, entry.end_date, entry.current) = self.date_range(line);
+            } else if let Some((label, value)) = self.label(line) {
+                match label.to_lowercase().as_str() {
+                    "location" => entry.location = Some(value),
+                    _ => description.push(line),
+                }
+            } else {
+                description.push(line);
+            }
+        }
+
+        if let Some(last) = volunteer.last_mut() {
+            last.description = description.take();
+        }
+        volunteer
+    }
+
+    /// "**Label:** value", "**Label**: value" or "Label: value".
+    fn label(&self, line: &str) -> Option<(String, String)> {
+        if let Some(caps) = self.bold_label_re.captures(line) {
+            let label = caps.get(1).or_else(|| caps.get(3))?.as_str();
+            let value = caps.get(2).or_else(|| caps.get(4))?.as_str();
+            return Some((label.trim().to_string(), value.trim().to_string()));
+        }
+        self.plain_label_re
+            .captures(line)
+            .map(|caps| (caps[1].trim().to_string(), caps[2].trim().to_string()))
+    }
+
+    /// The URL of a Markdown link, or the text itself when it isn't one.
+    fn link_target(&self, value: &str) -> String {
+        match self.link_re.captures(value) {
+            Some(caps) => caps[2].to_string(),
+            None => value.to_string(),
+        }
+    }
+
+    fn date_range(&self, text: &str) -> (Option<NaiveDate>, Option<NaiveDate>, bool) {
+        let text = text.trim().trim_matches(|c| c == '_' || c == '*').trim();
+        if text.is_empty() {
+            return (None, None, false);
+        }
+        match self.dates.parse_date_range(text) {
+            Some((start, end, current)) => {
+                (start.and_then(to_date), end.and_then(to_date), current)
+            }
+            None => (None, self.dates.parse_date(text).and_then(to_date), false),
+        }
+    }
+
+    fn paragraphs(&self, lines: &[&str]) -> String {
+        let mut paragraphs = Paragraphs::default();
+        for line in lines.iter().map(|line| line.trim()) {
+            if line.is_empty() || line == "---" {
+                paragraphs.blank();
+            } else {
+                paragraphs.push(line);
+            }
+        }
+        paragraphs.take()
+    }
+}
+
+fn to_date(date: PartialDate) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(date.year, date.month.unwrap_or(1), 1)
+}
+
+fn is_bold(line: &str) -> bool {
+    line.len() > 4 && line.starts_with("**") && line.ends_with("**")
+}
+
+fn is_emphasized(line: &str) -> bool {
+    (line.starts_with('_') && line.ends_with('_')) || (line.starts_with('*') && line.ends_with('*'))
+}
+
+fn bullet(line: &str) -> Option<&str> {
+    line.strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .map(str::trim)
+}
+
+fn strip_bullet(line: &str) -> &str {
+    bullet(line).unwrap_or(line)
+}
+
+/// "German (Professional)" in the templates' language lists.
+fn strip_proficiency(item: &str) -> &str {
+    match item.rsplit_once(" (") {
+        Some((name, _)) if item.ends_with(')') => name,
+        _ => item,
+    }
+}
+
+/// "- German (Native)" items; a language without a recognized proficiency
+/// is read as professional working proficiency.
+fn import_languages(lines: &[&str]) -> Vec<Language> {
+    lines
+        .iter()
+        .filter_map(|line| bullet(line.trim()))
+        .map(|item| {
+            let name = strip_proficiency(item);
+            let proficiency = item[name.len()..]
+                .trim()
+                .strip_prefix('(')
+                .and_then(|label| label.strip_suffix(')'))
+                .and_then(LanguageProficiency::from_label)
+                .unwrap_or(LanguageProficiency::Professional);
+            Language {
+                name: name.trim().to_string(),
+                proficiency,
+            }
+        })
+        .collect()
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(", ")
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn skill_category(heading: &str) -> &'static str {
+    match heading.trim().trim_end_matches(':').to_lowercase().as_str() {
+        "soft skills" | "soft" => "soft",
+        "tools" => "tools",
+        "languages" => "languages",
+        "other" | "other skills" => "other",
+        _ => "technical",
+    }
+}
+
+fn parse_location(text: &str) -> Location {
+    match text.rsplit_once(", ") {
+        Some((city, country)) => Location {
+            city: city.trim().to_string(),
+            country: country.trim().to_string(),
+        },
+        None => Location {
+            city: text.trim().to_string(),
+            country: String::new(),
+        },
+    }
+}
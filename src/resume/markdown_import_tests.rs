@@ -0,0 +1,527 @@
+use crate::resume::formatter::{OutputFormat, ResumeFormatter};
+use crate::resume::markdown_import::MarkdownImporter;
+use crate::resume::model::{
+    Certification, Education, Experience, Language, LanguageProficiency, Location, Profile,
+    Project, Publication, Resume, Skill, Volunteer,
+};
+use crate::resume::test_support::{date, experience, sample_resume, skill, skills, strings};
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use proptest::prelude::*;
+use std::collections::HashMap;
+
+fn render(resume: &Resume) -> String {
+    ResumeFormatter::new()
+        .format(resume, OutputFormat::Markdown)
+        .unwrap()
+        .content
+}
+
+/// The formatter doesn't render metadata, so the import gets fresh metadata.
+fn import(markdown: &str, original: &Resume) -> Resume {
+    let mut imported = MarkdownImporter::new().import(markdown).unwrap();
+    imported.metadata = original.metadata.clone();
+    imported
+}
+
+fn create_test_resume() -> Resume {
+    let mut resume = sample_resume();
+    resume.profile.title = "Staff Engineer".to_string();
+    resume.profile.github = "https://github.com/jane".to_string();
+    resume.profile.summary = "Builds payment systems.\n\nMentors new engineers.".to_string();
+    resume.experiences.push(experience("Intern", "Initech"));
+    resume.education[0] = Education {
+        gpa: Some(3.85),
+        courses: strings(&["Algorithms"]),
+        achievements: strings(&["Dean's list"]),
+        description: "Thesis on consensus.".to_string(),
+        ..resume.education[0].clone()
+    };
+    resume.skills.tools = skills(&["Docker"]);
+    resume.skills.languages = skills(&["German"]);
+    resume.projects.push(Project {
+        name: "Ledger".to_string(),
+        description: "Double-entry bookkeeping library.".to_string(),
+        url: Some("https://ledger.dev".to_string()),
+        github: Some("https://github.com/jane/ledger".to_string()),
+        technologies: vec!["Rust".to_string()],
+        start_date: None,
+        end_date: None,
+        highlights: vec!["500 stars".to_string()],
+        tags: vec![],
+        translations: HashMap::new(),
+    });
+    resume.certifications.push(Certification {
+        name: "Certified Kubernetes Administrator".to_string(),
+        issuer: "CNCF".to_string(),
+        date_obtained: date(2021, 5),
+        expiry_date: date(2024, 5),
+        credential_id: Some("LF-12345".to_string()),
+        url: Some("https://verify.cncf.io/LF-12345".to_string()),
+    });
+    resume.languages.push(Language {
+        name: "Portuguese".to_string(),
+        proficiency: LanguageProficiency::Native,
+    });
+    resume.publications.push(Publication {
+        title: "Consensus at Scale".to_string(),
+        publisher: "ACM Queue".to_string(),
+        published_date: date(2022, 7),
+        authors: vec!["Jane Smith".to_string(), "Alan Jones".to_string()],
+        url: Some("https://queue.acm.org/consensus".to_string()),
+        description: "Survey of consensus protocols.".to_string(),
+        translations: HashMap::new(),
+    });
+    resume.volunteer.push(Volunteer {
+        organization: "Code Club".to_string(),
+        role: "Mentor".to_string(),
+        start_date: date(2019, 1),
+        end_date: None,
+        current: true,
+        description: "Teaches kids to code.".to_string(),
+        location: Some("Brooklyn".to_string()),
+        translations: HashMap::new(),
+    });
+    resume
+}
+
+#[test]
+fn test_import_formatted_markdown() {
+    let resume = create_test_resume();
+    assert_eq!(import(&render(&resume), &resume), resume);
+}
+
+#[test]
+fn test_import_requires_name() {
+    assert_eq!(
+        MarkdownImporter::new()
+            .import("## Skills\n\nRust\n")
+            .unwrap_err(),
+        "Markdown resume must start with a '# Name' heading"
+    );
+}
+
+#[test]
+fn test_import_classic_layout() {
+    let markdown = "# Jane Smith
+
+Staff Engineer
+
+---
+
+## CONTACT INFORMATION
+
+-   Email: jane@example.com
+-   Phone: +1 555 123 4567
+
+---
+
+## PROFESSIONAL EXPERIENCE
+
+**Senior Engineer** | Acme Corp | March 2020 - Present
+
+Owns the billing platform.
+
+Key Achievements:
+
+-   Led the billing migration
+
+Technologies: Rust, Postgres
+
+---
+
+## EDUCATION
+
+**BSc in Computer Science** | MIT | September 2012 - June 2016
+GPA: 3.85
+
+---
+
+## SKILLS
+
+**Technical:** Rust, SQL
+
+**Languages:**
+
+-   German (Professional)
+";
+    let resume = MarkdownImporter::new().import(markdown).unwrap();
+
+    assert_eq!(resume.profile.email, "jane@example.com");
+    let experience = &resume.experiences[0];
+    assert_eq!(experience.title, "Senior Engineer");
+    assert_eq!(experience.company, "Acme Corp");
+    assert_eq!(experience.start_date, date(2020, 3));
+    assert!(experience.current);
+    assert_eq!(experience.description, "Owns the billing platform.");
+    assert_eq!(experience.achievements, vec!["Led the billing migration"]);
+    assert_eq!(experience.technologies, vec!["Rust", "Postgres"]);
+
+    let education = &resume.education[0];
+    assert_eq!(education.degree, "BSc");
+    assert_eq!(education.field_of_study, "Computer Science");
+    assert_eq!(education.institution, "MIT");
+    assert_eq!(education.end_date, date(2016, 6));
+    assert_eq!(education.gpa, Some(3.85));
+
+    assert_eq!(resume.skills.technical, vec![skill("Rust"), skill("SQL")]);
+    assert_eq!(resume.skills.languages, vec![skill("German")]);
+}
+
+#[test]
+fn test_import_minimal_layout() {
+    let markdown = "# Jane Smith
+
+jane@example.com | +1 555 123 4567 | Austin, USA
+[LinkedIn](https://linkedin.com/in/jane) | [GitHub](https://github.com/jane) |
+
+## Experience
+
+**Senior Engineer**, Acme Corp (March 2020 - Present)  
+Owns the billing platform.
+
+-   Led the billing migration
+
+## Education
+
+**BSc** in Computer Science, MIT (September 2012 - June 2016)  
+
+## Projects
+
+**Ledger** ([GitHub](https://github.com/jane/ledger))  
+Double-entry bookkeeping library.
+Technologies: Rust
+";
+    let resume = MarkdownImporter::new().import(markdown).unwrap();
+
+    assert_eq!(resume.profile.phone, "+1 555 123 4567");
+    assert_eq!(resume.profile.location.country, "USA");
+    assert_eq!(resume.profile.linkedin, "https://linkedin.com/in/jane");
+    assert_eq!(resume.experiences[0].company, "Acme Corp");
+    assert_eq!(resume.experiences[0].achievements.len(), 1);
+    assert_eq!(resume.education[0].institution, "MIT");
+    assert_eq!(resume.education[0].field_of_study, "Computer Science");
+    assert_eq!(resume.projects[0].name, "Ledger");
+    assert_eq!(
+        resume.projects[0].github.as_deref(),
+        Some("https://github.com/jane/ledger")
+    );
+    assert_eq!(resume.projects[0].technologies, vec!["Rust"]);
+}
+
+fn words() -> impl Strategy<Value = String> {
+    prop::collection::vec("[A-Z][a-z]{2,8}", 1..4).prop_map(|words| words.join(" "))
+}
+
+/// Words that may themselves contain `joiner`, like "Engineer at Large" or
+/// "Certificate in Data", to exercise splitting at the last delimiter.
+fn joined(joiner: &'static str) -> impl Strategy<Value = String> {
+    prop_oneof![
+        words(),
+        (words(), words()).prop_map(move |(head, tail)| format!("{} {} {}", head, joiner, tail)),
+    ]
+}
+
+fn sentence() -> impl Strategy<Value = String> {
+    words().prop_map(|words| format!("{} done.", words))
+}
+
+fn text() -> impl Strategy<Value = String> {
+    prop_oneof![Just(String::new()), sentence()]
+}
+
+fn month() -> impl Strategy<Value = NaiveDate> {
+    (1990i32..2030, 1u32..=12).prop_map(|(year, month)| date(year, month).unwrap())
+}
+
+fn dates() -> impl Strategy<Value = (Option<NaiveDate>, Option<NaiveDate>, bool)> {
+    (
+        prop::option::of(month()),
+        prop::option::of(month()),
+        any::<bool>(),
+    )
+        .prop_map(|(start, end, current)| (start, if current { None } else { end }, current))
+}
+
+fn list(item: impl Strategy<Value = String>) -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec(item, 0..3)
+}
+
+fn skill_group() -> impl Strategy<Value = Vec<Skill>> {
+    prop::collection::vec(words().prop_map(|name| skill(&name)), 0..4)
+}
+
+fn url() -> impl Strategy<Value = String> {
+    "https://[a-z]{3,8}\\.com/[a-z]{1,8}"
+}
+
+fn job() -> impl Strategy<Value = Experience> {
+    (
+        joined("at"),
+        words(),
+        prop::option::of(words()),
+        dates(),
+        text(),
+        list(sentence()),
+        list(words()),
+    )
+        .prop_map(
+            |(
+                title,
+                company,
+                location,
+                (start_date, end_date, current),
+                description,
+                achievements,
+                technologies,
+            )| {
+                Experience {
+                    company,
+                    title,
+                    location,
+                    start_date,
+                    end_date,
+                    current,
+                    description,
+                    achievements,
+                    technologies,
+                    tags: vec![],
+                    achievement_tags: HashMap::new(),
+                    translations: HashMap::new(),
+                }
+            },
+        )
+}
+
+fn education() -> impl Strategy<Value = Education> {
+    (
+        (words(), joined("in"), words()),
+        dates(),
+        prop::option::of(100u32..=400),
+        text(),
+        list(words()),
+        list(sentence()),
+    )
+        .prop_map(
+            |(
+                (institution, degree, field_of_study),
+                (start_date, end_date, current),
+                gpa,
+                description,
+                courses,
+                achievements,
+            )| {
+                Education {
+                    institution,
+                    degree,
+                    field_of_study,
+                    location: None,
+                    start_date,
+                    end_date,
+                    current,
+                    gpa: gpa.map(|hundredths| hundredths as f32 / 100.0),
+                    gpa_scale: None,
+                    courses,
+                    achievements,
+                    description,
+                    translations: HashMap::new(),
+                }
+            },
+        )
+}
+
+fn project() -> impl Strategy<Value = Project> {
+    (
+        words(),
+        text(),
+        prop::option::of(url()),
+        prop::option::of(url()),
+        list(words()),
+        list(sentence()),
+    )
+        .prop_map(
+            |(name, description, url, github, technologies, highlights)| Project {
+                name,
+                description,
+                url,
+                github,
+                technologies,
+                start_date: None,
+                end_date: None,
+                highlights,
+                tags: vec![],
+                translations: HashMap::new(),
+            },
+        )
+}
+
+fn certification() -> impl Strategy<Value = Certification> {
+    (
+        words(),
+        prop_oneof![Just(String::new()), words()],
+        prop::option::of(month()),
+        prop::option::of(month()),
+        prop::option::of("[A-Z]{2}-[0-9]{3,6}"),
+        prop::option::of(url()),
+    )
+        .prop_map(
+            |(name, issuer, date_obtained, expiry_date, credential_id, url)| Certification {
+                name,
+                issuer,
+                date_obtained,
+                expiry_date,
+                credential_id,
+                url,
+            },
+        )
+}
+
+fn language() -> impl Strategy<Value = Language> {
+    (
+        words(),
+        prop_oneof![
+            Just(LanguageProficiency::Elementary),
+            Just(LanguageProficiency::Limited),
+            Just(LanguageProficiency::Professional),
+            Just(LanguageProficiency::FullProfessional),
+            Just(LanguageProficiency::Native),
+        ],
+    )
+        .prop_map(|(name, proficiency)| Language { name, proficiency })
+}
+
+fn publication() -> impl Strategy<Value = Publication> {
+    (
+        words(),
+        prop_oneof![Just(String::new()), words()],
+        prop::option::of(month()),
+        list(words()),
+        prop::option::of(url()),
+        text(),
+    )
+        .prop_map(
+            |(title, publisher, published_date, authors, url, description)| Publication {
+                title,
+                publisher,
+                published_date,
+                authors,
+                url,
+                description,
+                translations: HashMap::new(),
+            },
+        )
+}
+
+fn volunteer() -> impl Strategy<Value = Volunteer> {
+    (
+        joined("at"),
+        words(),
+        dates(),
+        text(),
+        prop::option::of(words()),
+    )
+        .prop_map(
+            |(role, organization, (start_date, end_date, current), description, location)| {
+                Volunteer {
+                    organization,
+                    role,
+                    start_date,
+                    end_date,
+                    current,
+                    description,
+                    location,
+                    translations: HashMap::new(),
+                }
+            },
+        )
+}
+
+fn profile() -> impl Strategy<Value = Profile> {
+    (
+        (words(), prop_oneof![Just(String::new()), words()]),
+        ("[a-z]{3,8}@[a-z]{3,8}\\.com", "\\+1 555 [0-9]{3} [0-9]{4}"),
+        prop::option::of((words(), prop::option::of(words()))),
+        (
+            prop::option::of(url()),
+            prop::option::of(url()),
+            prop::option::of(url()),
+        ),
+        text(),
+    )
+        .prop_map(
+            |((name, title), (email, phone), location, (linkedin, github, website), summary)| {
+                let (city, country) = location.unwrap_or_default();
+                Profile {
+                    name,
+                    title,
+                    email,
+                    phone,
+                    location: Location {
+                        city,
+                        country: country.unwrap_or_default(),
+                    },
+                    linkedin: linkedin.unwrap_or_default(),
+                    github: github.unwrap_or_default(),
+                    website: website.unwrap_or_default(),
+                    summary,
+                    ..Profile::default()
+                }
+            },
+        )
+}
+
+fn resume() -> impl Strategy<Value = Resume> {
+    (
+        profile(),
+        prop::collection::vec(job(), 0..3),
+        prop::collection::vec(education(), 0..2),
+        (
+            skill_group(),
+            skill_group(),
+            skill_group(),
+            skill_group(),
+            skill_group(),
+        ),
+        prop::collection::vec(project(), 0..2),
+        (
+            prop::collection::vec(certification(), 0..2),
+            prop::collection::vec(language(), 0..3),
+            prop::collection::vec(publication(), 0..2),
+            prop::collection::vec(volunteer(), 0..2),
+        ),
+    )
+        .prop_map(
+            |(
+                profile,
+                experiences,
+                education,
+                (technical, soft, tools, languages, other),
+                projects,
+                (certifications, spoken, publications, volunteer),
+            )| {
+                let mut resume = Resume::new(profile);
+                resume.experiences = experiences;
+                resume.education = education;
+                resume.skills.technical = technical;
+                resume.skills.soft = soft;
+                resume.skills.tools = tools;
+                resume.skills.languages = languages;
+                resume.skills.other = other;
+                resume.projects = projects;
+                resume.certifications = certifications;
+                resume.languages = spoken;
+                resume.publications = publications;
+                resume.volunteer = volunteer;
+                resume
+            },
+        )
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_markdown_round_trip(resume in resume()) {
+        prop_assert_eq!(import(&render(&resume), &resume), resume);
+    }
+}
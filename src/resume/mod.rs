@@ -1,6 +1,27 @@
+pub mod ats_profile;
+pub mod certification_match;
+pub mod docx;
+pub mod education_match;
+pub mod experience_match;
 pub mod formatter;
+pub mod html_theme;
+pub mod importer;
+pub mod latex;
+pub mod locale;
+pub mod location_match;
+pub mod markdown_import;
 pub mod model;
 pub mod optimizer;
+pub mod page_fit;
+pub mod parser;
+pub mod pdf_import;
+pub mod round_trip;
+pub mod rules;
+pub mod structured_data;
+pub mod stuffing;
+pub mod tailor;
+pub mod title_match;
+pub mod typst;
 
 #[cfg(test)]
 mod resume_model_tests;
@@ -10,3 +31,69 @@ mod optimizer_tests;
 
 #[cfg(test)]
 mod formatter_tests;
+
+#[cfg(test)]
+mod rules_tests;
+
+#[cfg(test)]
+mod experience_match_tests;
+
+#[cfg(test)]
+mod education_match_tests;
+
+#[cfg(test)]
+mod certification_match_tests;
+
+#[cfg(test)]
+mod title_match_tests;
+
+#[cfg(test)]
+mod location_match_tests;
+
+#[cfg(test)]
+mod tailor_tests;
+
+#[cfg(test)]
+mod stuffing_tests;
+
+#[cfg(test)]
+mod parser_tests;
+
+#[cfg(test)]
+mod round_trip_tests;
+
+#[cfg(test)]
+mod ats_profile_tests;
+
+#[cfg(test)]
+mod importer_tests;
+
+#[cfg(test)]
+mod markdown_import_tests;
+
+#[cfg(test)]
+mod pdf_import_tests;
+
+#[cfg(test)]
+mod docx_tests;
+
+#[cfg(test)]
+mod latex_tests;
+
+#[cfg(test)]
+mod typst_tests;
+
+#[cfg(test)]
+mod locale_tests;
+
+#[cfg(test)]
+mod page_fit_tests;
+
+#[cfg(test)]
+mod html_theme_tests;
+
+#[cfg(test)]
+mod structured_data_tests;
+
+#[cfg(test)]
+mod test_support;
@@ -3,7 +3,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Resume {
     pub profile: Profile,
     pub experiences: Vec<Experience>,
@@ -39,6 +39,138 @@ impl Resume {
         }
     }
 
+    /// Builds the named variant from `metadata.variants`.
+    pub fn variant(&self, name: &str) -> Result<Resume, String> {
+        let variant = self
+            .metadata
+            .variants
+            .get(name)
+            .ok_or_else(|| format!("Variant '{}' not found", name))?;
+        Ok(self.apply_variant(variant))
+    }
+
+    pub fn apply_variant(&self, variant: &ResumeVariant) -> Resume {
+        let mut resume = self.clone();
+
+        resume.experiences = self
+            .experiences
+            .iter()
+            .filter(|exp| variant.includes(&exp.tags))
+            .map(|exp| {
+                let mut exp = exp.clone();
+                exp.retain_achievements(&variant.select_achievements(&exp));
+                exp
+            })
+            .collect();
+
+        resume
+            .projects
+            .retain(|project| variant.includes(&project.tags));
+
+        for list in [
+            &mut resume.skills.technical,
+            &mut resume.skills.soft,
+            &mut resume.skills.languages,
+            &mut resume.skills.tools,
+            &mut resume.skills.other,
+        ] {
+            list.retain(|skill| variant.includes(&skill.tags));
+        }
+
+        resume
+    }
+
+    /// Language codes the resume has text in: its own language first, then
+    /// every language with at least one translation.
+    pub fn content_languages(&self) -> Vec<String> {
+        let mut languages = vec![self.metadata.language.clone()];
+        let keys = self
+            .profile
+            .translations
+            .keys()
+            .chain(self.experiences.iter().flat_map(|e| e.translations.keys()))
+            .chain(self.education.iter().flat_map(|e| e.translations.keys()))
+            .chain(self.projects.iter().flat_map(|p| p.translations.keys()))
+            .chain(self.publications.iter().flat_map(|p| p.translations.keys()))
+            .chain(self.volunteer.iter().flat_map(|v| v.translations.keys()));
+        let mut translated: Vec<String> = keys
+            .filter(|key| !languages.iter().any(|l| same_language(l, key)))
+            .cloned()
+            .collect();
+        translated.sort();
+        translated.dedup();
+        languages.extend(translated);
+        languages
+    }
+
+    pub fn has_language(&self, language: &str) -> bool {
+        self.content_languages()
+            .iter()
+            .any(|l| same_language(l, language))
+    }
+
+    /// The resume with its text in `language` wherever a translation
+    /// exists. Everything else stays in `metadata.language`.
+    pub fn translate(&self, language: &str) -> Resume {
+        let mut resume = self.clone();
+        if same_language(language, &self.metadata.language) || !self.has_language(language) {
+            return resume;
+        }
+        resume.metadata.language = language.to_string();
+
+        let profile = &mut resume.profile;
+        if let Some(t) = translation(&self.profile.translations, language) {
+            replace(&mut profile.title, &t.title);
+            replace(&mut profile.summary, &t.summary);
+        }
+
+        for exp in &mut resume.experiences {
+            let Some(t) = translation(&exp.translations, language) else {
+                continue;
+            };
+            replace(&mut exp.title, &t.title);
+            replace(&mut exp.description, &t.description);
+            replace(&mut exp.achievements, &t.achievements);
+        }
+
+        for edu in &mut resume.education {
+            let Some(t) = translation(&edu.translations, language) else {
+                continue;
+            };
+            replace(&mut edu.degree, &t.degree);
+            replace(&mut edu.field_of_study, &t.field_of_study);
+            replace(&mut edu.description, &t.description);
+            replace(&mut edu.courses, &t.courses);
+            replace(&mut edu.achievements, &t.achievements);
+        }
+
+        for project in &mut resume.projects {
+            let Some(t) = translation(&project.translations, language) else {
+                continue;
+            };
+            replace(&mut project.description, &t.description);
+            replace(&mut project.highlights, &t.highlights);
+        }
+
+        for publication in &mut resume.publications {
+            let Some(t) = translation(&publication.translations, language) else {
+                continue;
+            };
+            replace(&mut publication.title, &t.title);
+            replace(&mut publication.description, &t.description);
+        }
+
+        for volunteer in &mut resume.volunteer {
+            let Some(t) = translation(&volunteer.translations, language) else {
+                continue;
+            };
+            replace(&mut volunteer.role, &t.role);
+            replace(&mut volunteer.description, &t.description);
+        }
+
+        resume
+    }
+
     #[allow(unused_mut, clippy::let_and_return)]
     pub fn count_keywords(&self) -> HashMap<String, usize> {
         let mut keywords_count = HashMap::new();
@@ -176,10 +308,11 @@ impl Resume {
                 }
             }
             if let Some(gpa) = edu.gpa {
-                if !(0.0..=4.0).contains(&gpa) {
+                if !(0.0..=edu.gpa_scale()).contains(&gpa) {
                     return Err(format!(
-                        "Invalid GPA for {}: must be between 0.0 and 4.0",
-                        edu.institution
+                        "Invalid GPA for {}: must be between 0.0 and {:.1}",
+                        edu.institution,
+                        edu.gpa_scale()
                     ));
                 }
             }
@@ -274,13 +407,41 @@ impl Resume {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+/// Whether two language codes name the same language, ignoring case and
+/// region subtags, e.g. "de" and "de-AT".
+pub fn same_language(a: &str, b: &str) -> bool {
+    let primary = |code: &str| code.split(['-', '_']).next().unwrap_or(code).to_lowercase();
+    primary(a) == primary(b)
+}
+
+/// The translation for `language`, preferring an exact match such as
+/// "pt-BR" over the shortest code for the same language, such as "pt".
+fn translation<'a, T>(translations: &'a HashMap<String, T>, language: &str) -> Option<&'a T> {
+    translations
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(language))
+        .or_else(|| {
+            translations
+                .iter()
+                .filter(|(key, _)| same_language(key, language))
+                .min_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then(a.cmp(b)))
+        })
+        .map(|(_, translation)| translation)
+}
+
+fn replace<T: Clone>(field: &mut T, translated: &Option<T>) {
+    if let Some(value) = translated {
+        *field = value.clone();
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct Location {
     pub city: String,
     pub country: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Profile {
     pub name: String,
     pub title: String,
@@ -291,6 +452,15 @@ pub struct Profile {
     pub github: String,
     pub website: String,
     pub summary: String,
+    #[serde(default)]
+    pub remote_preference: Option<WorkArrangement>,
+    #[serde(default)]
+    pub willing_to_relocate: bool,
+    #[serde(default)]
+    pub work_authorizations: Vec<WorkAuthorization>,
+    /// Title and summary in other languages, keyed by language code.
+    #[serde(default)]
+    pub translations: HashMap<String, ProfileTranslation>,
 }
 
 impl Default for Profile {
@@ -305,11 +475,40 @@ impl Default for Profile {
             website: "".to_string(),
             summary: "".to_string(),
             title: "".to_string(),
+            remote_preference: None,
+            willing_to_relocate: false,
+            work_authorizations: vec![],
+            translations: HashMap::new(),
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum WorkArrangement {
+    OnSite,
+    Hybrid,
+    Remote,
+}
+
+impl WorkArrangement {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::OnSite => "on-site",
+            Self::Hybrid => "hybrid",
+            Self::Remote => "remote",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WorkAuthorization {
+    /// Country or region code, e.g. "US", "EU" or "Germany".
+    pub region: String,
+    #[serde(default)]
+    pub requires_sponsorship: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Experience {
     pub company: String,
     pub title: String,
@@ -322,9 +521,52 @@ pub struct Experience {
     pub achievements: Vec<String>,
     #[serde(default)]
     pub technologies: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Tags for individual achievements, keyed by the achievement's index in
+    /// `achievements`.
+    #[serde(default)]
+    pub achievement_tags: HashMap<usize, Vec<String>>,
+    #[serde(default)]
+    pub translations: HashMap<String, ExperienceTranslation>,
+}
+
+impl Experience {
+    pub fn achievement_tags_of(&self, index: usize) -> &[String] {
+        self.achievement_tags
+            .get(&index)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Keeps the achievements at `indexes`, in that order, together with
+    /// their tags.
+    pub fn retain_achievements(&mut self, indexes: &[usize]) {
+        let mut tags = std::mem::take(&mut self.achievement_tags);
+        self.achievement_tags = indexes
+            .iter()
+            .enumerate()
+            .filter_map(|(position, index)| Some((position, tags.remove(index)?)))
+            .collect();
+        self.achievements = indexes
+            .iter()
+            .map(|index| self.achievements[*index].clone())
+            .collect();
+    }
+
+    /// Removes the achievement at `index` and its tags, keeping the tags of
+    /// later achievements in step.
+    pub fn remove_achievement(&mut self, index: usize) -> String {
+        let achievement = self.achievements[index].clone();
+        let rest: Vec<usize> = (0..self.achievements.len())
+            .filter(|other| *other != index)
+            .collect();
+        self.retain_achievements(&rest);
+        achievement
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Education {
     pub institution: String,
     pub degree: String,
@@ -335,15 +577,47 @@ pub struct Education {
     pub current: bool,
     #[serde(default)]
     pub gpa: Option<f32>,
+    /// The top of the scale `gpa` is graded on, e.g. 10.0; `None` is the US
+    /// 4.0 scale.
+    #[serde(default)]
+    pub gpa_scale: Option<f32>,
     #[serde(default)]
     pub courses: Vec<String>,
     #[serde(default)]
     pub achievements: Vec<String>,
     #[serde(default)]
     pub description: String,
+    #[serde(default)]
+    pub translations: HashMap<String, EducationTranslation>,
+}
+
+impl Education {
+    pub fn gpa_scale(&self) -> f32 {
+        self.gpa_scale.unwrap_or(4.0)
+    }
+
+    /// The GPA as shown on rendered resumes: "3.85", or "8.50/10" on a scale
+    /// other than 4.0.
+    pub fn gpa_text(&self) -> Option<String> {
+        let gpa = self.gpa?;
+        Some(match self.gpa_scale {
+            Some(scale) if scale != 4.0 => format!("{:.2}/{}", gpa, scale),
+            _ => format!("{:.2}", gpa),
+        })
+    }
+}
+
+/// Reads a GPA written as "3.85" or "8.5/10" into the grade and, when
+/// given, its scale.
+pub fn parse_gpa(value: &str) -> Option<(f32, Option<f32>)> {
+    let (gpa, scale) = match value.split_once('/') {
+        Some((gpa, scale)) => (gpa, Some(scale.trim().parse().ok()?)),
+        None => (value, None),
+    };
+    Some((gpa.trim().parse().ok()?, scale))
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct Skills {
     #[serde(default)]
     pub technical: Vec<Skill>,
@@ -357,13 +631,29 @@ pub struct Skills {
     pub other: Vec<Skill>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+impl Skills {
+    /// The categories in the order formatted resumes show them, each with
+    /// its English heading, which is also its locale key.
+    pub fn categories(&self) -> [(&'static str, &Vec<Skill>); 5] {
+        [
+            ("Technical Skills", &self.technical),
+            ("Soft Skills", &self.soft),
+            ("Tools", &self.tools),
+            ("Languages", &self.languages),
+            ("Other", &self.other),
+        ]
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Skill {
     pub name: String,
     #[serde(default)]
     pub level: Option<String>,
     #[serde(default)]
     pub years: Option<u32>,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -374,7 +664,7 @@ pub enum SkillLevel {
     Expert,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Project {
     pub name: String,
     pub description: String,
@@ -388,9 +678,13 @@ pub struct Project {
     pub end_date: Option<NaiveDate>,
     #[serde(default)]
     pub highlights: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub translations: HashMap<String, ProjectTranslation>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Certification {
     pub name: String,
     pub issuer: String,
@@ -402,7 +696,7 @@ pub struct Certification {
     pub url: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Language {
     pub name: String,
     pub proficiency: LanguageProficiency,
@@ -417,7 +711,38 @@ pub enum LanguageProficiency {
     Native,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+impl LanguageProficiency {
+    /// The label shown on rendered resumes, e.g. "Full professional".
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Elementary => "Elementary",
+            Self::Limited => "Limited working",
+            Self::Professional => "Professional working",
+            Self::FullProfessional => "Full professional",
+            Self::Native => "Native",
+        }
+    }
+
+    /// Reads a rendered label back, also accepting common variations such
+    /// as "Professional" or "Native or bilingual".
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label
+            .trim()
+            .trim_end_matches(" proficiency")
+            .to_lowercase()
+            .as_str()
+        {
+            "elementary" => Some(Self::Elementary),
+            "limited" | "limited working" => Some(Self::Limited),
+            "professional" | "professional working" => Some(Self::Professional),
+            "full professional" | "fluent" => Some(Self::FullProfessional),
+            "native" | "bilingual" | "native or bilingual" => Some(Self::Native),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Publication {
     pub title: String,
     pub publisher: String,
@@ -428,9 +753,11 @@ pub struct Publication {
     pub url: Option<String>,
     #[serde(default)]
     pub description: String,
+    #[serde(default)]
+    pub translations: HashMap<String, PublicationTranslation>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Volunteer {
     pub organization: String,
     pub role: String,
@@ -441,9 +768,70 @@ pub struct Volunteer {
     pub description: String,
     #[serde(default)]
     pub location: Option<String>,
+    #[serde(default)]
+    pub translations: HashMap<String, VolunteerTranslation>,
+}
+
+/// Text of an entry in another language. Fields left out keep the entry's
+/// own text, so a translation can cover just part of an entry.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProfileTranslation {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ExperienceTranslation {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Achievements in the same order as the entry's own.
+    #[serde(default)]
+    pub achievements: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct EducationTranslation {
+    #[serde(default)]
+    pub degree: Option<String>,
+    #[serde(default)]
+    pub field_of_study: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub courses: Option<Vec<String>>,
+    #[serde(default)]
+    pub achievements: Option<Vec<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProjectTranslation {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub highlights: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct PublicationTranslation {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct VolunteerTranslation {
+    #[serde(default)]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ResumeMetadata {
     #[serde(default = "Utc::now")]
     pub last_updated: DateTime<Utc>,
@@ -453,6 +841,17 @@ pub struct ResumeMetadata {
     pub template: String,
     #[serde(default)]
     pub custom_fields: HashMap<String, String>,
+    /// Named subsets of this resume, e.g. "backend" or "management".
+    #[serde(default)]
+    pub variants: HashMap<String, ResumeVariant>,
+    /// Language code of the resume's own text. Text without a translation
+    /// falls back to it.
+    #[serde(default = "default_language")]
+    pub language: String,
+}
+
+fn default_language() -> String {
+    "en".to_string()
 }
 
 impl Default for ResumeMetadata {
@@ -462,6 +861,68 @@ impl Default for ResumeMetadata {
             version: "1.0.0".to_string(),
             template: "default".to_string(),
             custom_fields: HashMap::new(),
+            variants: HashMap::new(),
+            language: default_language(),
+        }
+    }
+}
+
+/// Selects part of a master resume by tag. Untagged entries are shared by
+/// every variant; an entry with an excluded tag is always left out.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ResumeVariant {
+    #[serde(default)]
+    pub include_tags: Vec<String>,
+    #[serde(default)]
+    pub exclude_tags: Vec<String>,
+    /// Maximum achievements kept per role.
+    #[serde(default)]
+    pub max_bullets: Option<usize>,
+    /// Section names in the order they should be rendered, e.g.
+    /// `["education", "experiences"]`. Sections left out follow in the
+    /// default order.
+    #[serde(default)]
+    pub section_order: Vec<String>,
+}
+
+impl ResumeVariant {
+    fn has_any(tags: &[String], wanted: &[String]) -> bool {
+        tags.iter()
+            .any(|tag| wanted.iter().any(|w| w.eq_ignore_ascii_case(tag)))
+    }
+
+    /// Whether an entry with these tags belongs in the variant.
+    pub fn includes(&self, tags: &[String]) -> bool {
+        if Self::has_any(tags, &self.exclude_tags) {
+            return false;
         }
+        tags.is_empty() || self.include_tags.is_empty() || Self::has_any(tags, &self.include_tags)
+    }
+
+    /// Indexes of the achievements the variant keeps, in their original
+    /// order.
+    fn select_achievements(&self, exp: &Experience) -> Vec<usize> {
+        let candidates: Vec<(usize, bool)> = (0..exp.achievements.len())
+            .filter(|index| self.includes(exp.achievement_tags_of(*index)))
+            .map(|index| {
+                (
+                    index,
+                    Self::has_any(exp.achievement_tags_of(index), &self.include_tags),
+                )
+            })
+            .collect();
+
+        // When bullets have to be dropped, keep the ones tagged for this
+        // variant ahead of untagged ones, but in their original order
+        let limit = self.max_bullets.unwrap_or(candidates.len());
+        let mut kept: Vec<usize> = candidates
+            .iter()
+            .filter(|(_, tagged)| *tagged)
+            .chain(candidates.iter().filter(|(_, tagged)| !*tagged))
+            .take(limit)
+            .map(|(index, _)| *index)
+            .collect();
+        kept.sort_unstable();
+        kept
     }
 }
@@ -1,6 +1,22 @@
+use crate::resume::certification_match::{
+    self, CertificationMatcher, CertificationRequirementMatch, CertificationStatus,
+};
+use crate::resume::education_match::{
+    self, EducationMatcher, EducationRequirementMatch, FieldMatch,
+};
+use crate::resume::experience_match::{self, ExperienceMatcher, ExperienceRequirementMatch};
+use crate::resume::locale::Locale;
+use crate::resume::location_match::{self, LocationMatch};
 use crate::resume::model::Resume;
+use crate::resume::rules::{RuleSet, RuleViolation};
+use crate::resume::stuffing::{
+    KeywordStuffingDetector, StuffingIssue, StuffingKind, StuffingThresholds,
+};
+use crate::resume::title_match::{self, TitleAlignment};
+use chrono::{NaiveDate, Utc};
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 #[derive(Debug, Clone, Default)]
 pub struct OptimizationResult {
@@ -8,14 +24,47 @@ pub struct OptimizationResult {
     pub missing_keywords: Vec<String>,
     pub matching_keywords: HashMap<String, usize>,
     pub overused_keywords: Vec<String>,
+    /// Keyword density, repeated lists and keyword blocks, with the reason
+    /// each was flagged.
+    pub stuffing_issues: Vec<StuffingIssue>,
     pub suggestions: Vec<String>,
     pub section_improvements: HashMap<String, Vec<String>>,
+    pub rule_violations: Vec<RuleViolation>,
+    pub title_alignment: Option<TitleAlignment>,
+    pub experience_requirements: Vec<ExperienceRequirementMatch>,
+    pub education_requirements: Vec<EducationRequirementMatch>,
+    pub certification_requirements: Vec<CertificationRequirementMatch>,
+    pub missing_certifications: Vec<String>,
+    pub expired_certifications: Vec<String>,
+    /// Remote, location and work-authorization constraints from the job
+    /// description, if it states any.
+    pub location_match: Option<LocationMatch>,
+    /// Per-area scores out of 100 that are combined into `score`.
+    pub score_breakdown: HashMap<String, u8>,
+    /// Language of the resume text compared with the job description.
+    pub language: String,
 }
 
+/// Relative weight of each `score_breakdown` component. Components that don't
+/// apply to a job description are left out of the weighted average.
+const SCORE_WEIGHTS: [(&str, f32); 5] = [
+    ("keywords", 0.6),
+    ("title", 0.3),
+    ("experience", 0.25),
+    ("education", 0.15),
+    ("certifications", 0.1),
+];
+
 pub struct ResumeOptimizer {
     industry_keywords: HashMap<String, HashSet<String>>,
     action_verbs: HashSet<String>,
     weak_terms: HashSet<String>,
+    custom_rules: RuleSet,
+    experience_matcher: ExperienceMatcher,
+    education_matcher: EducationMatcher,
+    certification_matcher: CertificationMatcher,
+    stuffing_detector: KeywordStuffingDetector,
+    language: Option<String>,
 }
 
 impl Default for ResumeOptimizer {
@@ -30,6 +79,12 @@ impl ResumeOptimizer {
             industry_keywords: HashMap::new(),
             action_verbs: HashSet::new(),
             weak_terms: HashSet::new(),
+            custom_rules: RuleSet::new(),
+            experience_matcher: ExperienceMatcher::new(),
+            education_matcher: EducationMatcher::new(),
+            certification_matcher: CertificationMatcher::new(),
+            stuffing_detector: KeywordStuffingDetector::new(),
+            language: None,
         };
         optimizer.load_industry_keywords();
         optimizer
@@ -147,9 +202,68 @@ impl ResumeOptimizer {
         self.weak_terms = weak_terms.into_iter().map(String::from).collect();
     }
 
+    pub fn add_rules(&mut self, rules: RuleSet) {
+        self.custom_rules.extend(rules);
+    }
+
+    pub fn load_rules_from_file(&mut self, path: &Path) -> Result<(), String> {
+        let rules = RuleSet::from_file(path)?;
+        self.add_rules(rules);
+        Ok(())
+    }
+
+    /// Treats degrees in `related` as satisfying "or related field" requirements
+    /// for `field`.
+    pub fn add_related_fields(&mut self, field: &str, related: &[&str]) {
+        self.education_matcher.add_related_fields(field, related);
+    }
+
+    /// Teaches the optimizer to recognize a certification (and its aliases) in
+    /// job descriptions.
+    pub fn add_certification(&mut self, name: &str, aliases: &[&str]) {
+        self.certification_matcher.add_certification(name, aliases);
+    }
+
+    pub fn set_stuffing_thresholds(&mut self, thresholds: StuffingThresholds) {
+        self.stuffing_detector = KeywordStuffingDetector::with_thresholds(thresholds);
+    }
+
+    /// Compares job descriptions with the resume's text in `language`
+    /// instead of the language detected from each job description.
+    pub fn set_language(&mut self, language: &str) {
+        self.language = Some(language.to_string());
+    }
+
     pub fn optimize(&self, resume: &Resume, job_description: &str) -> OptimizationResult {
         let mut result = OptimizationResult::default();
 
+        // Postings made of job titles and technology names match no
+        // locale; compare them with the resume's own language
+        let language = self
+            .language
+            .clone()
+            .or_else(|| Locale::detect(job_description).map(|locale| locale.code.to_string()))
+            .unwrap_or_else(|| resume.metadata.language.clone());
+        let translated;
+        let resume = if resume.has_language(&language) {
+            translated = resume.translate(&language);
+            &translated
+        } else {
+            result.suggestions.push(if self.language.is_some() {
+                format!(
+                    "The resume has no '{}' translation for the selected language",
+                    language
+                )
+            } else {
+                format!(
+                    "The job description is in '{}' but the resume has no '{}' translation",
+                    language, language
+                )
+            });
+            resume
+        };
+        result.language = resume.metadata.language.clone();
+
         let job_keywords = self.extract_keywords(job_description);
 
         let resume_keywords = resume.count_keywords();
@@ -167,12 +281,12 @@ impl ResumeOptimizer {
             }
         }
 
-        let mut overused_keywords = Vec::new();
-        for (keyword, count) in &resume_keywords {
-            if *count > 4 {
-                overused_keywords.push(keyword.clone());
-            }
-        }
+        let stuffing_issues = self.stuffing_detector.analyze_resume(resume);
+        let overused_keywords = stuffing_issues
+            .iter()
+            .filter(|issue| issue.kind == StuffingKind::HighDensity)
+            .map(|issue| issue.subject.clone())
+            .collect();
 
         let total_important_keywords = job_keywords
             .iter()
@@ -186,26 +300,64 @@ impl ResumeOptimizer {
             })
             .count();
 
-        let score = if total_important_keywords > 0 {
+        let keyword_score = if total_important_keywords > 0 {
             ((matched_important_keywords as f32 / total_important_keywords as f32) * 100.0) as u8
         } else {
             0
         };
+        result
+            .score_breakdown
+            .insert("keywords".to_string(), keyword_score);
+
+        let today = Utc::now().date_naive();
 
         self.add_general_suggestions(resume, &mut result);
 
         self.analyze_experience_section(resume, &job_keywords, &mut result);
         self.analyze_skills_section(resume, &job_keywords, &mut result);
+        self.analyze_summary_section(resume, &job_keywords, job_description, &mut result);
+        self.analyze_education_section(resume, &job_keywords, &mut result);
+        self.analyze_projects_section(resume, &job_keywords, &mut result);
+        self.analyze_certifications_section(resume, today, &mut result);
+
+        self.analyze_title_alignment(resume, job_description, &mut result);
+        self.analyze_experience_requirements(resume, job_description, today, &mut result);
+        self.analyze_education_requirements(resume, job_description, &mut result);
+        self.analyze_certification_requirements(resume, job_description, today, &mut result);
+        self.analyze_location_requirements(resume, job_description, &mut result);
+
+        result.rule_violations = self.custom_rules.evaluate(resume, job_description);
 
-        result.score = score;
+        result.score = Self::combine_scores(&result.score_breakdown);
         result.missing_keywords = missing_keywords;
         result.matching_keywords = matching_keywords;
         result.overused_keywords = overused_keywords;
+        result.suggestions.extend(
+            stuffing_issues
+                .iter()
+                .map(|issue| issue.explanation.clone()),
+        );
+        result.stuffing_issues = stuffing_issues;
 
         result
     }
 
-    fn extract_keywords(&self, text: &str) -> HashMap<String, usize> {
+    fn combine_scores(breakdown: &HashMap<String, u8>) -> u8 {
+        let (weighted, total_weight) = SCORE_WEIGHTS
+            .iter()
+            .filter_map(|(name, weight)| breakdown.get(*name).map(|score| (*score, *weight)))
+            .fold((0.0, 0.0), |(sum, total), (score, weight)| {
+                (sum + score as f32 * weight, total + weight)
+            });
+
+        if total_weight > 0.0 {
+            (weighted / total_weight).round() as u8
+        } else {
+            0
+        }
+    }
+
+    pub(crate) fn extract_keywords(&self, text: &str) -> HashMap<String, usize> {
         let mut keywords = HashMap::new();
         let text = text.to_lowercase();
 
@@ -392,12 +544,425 @@ impl ResumeOptimizer {
                 .insert("Skills".to_string(), improvements);
         }
     }
+
+    fn analyze_title_alignment(
+        &self,
+        resume: &Resume,
+        job_description: &str,
+        result: &mut OptimizationResult,
+    ) {
+        let Some(alignment) = title_match::analyze_title_alignment(resume, job_description) else {
+            return;
+        };
+
+        if alignment.resume_title.is_empty() {
+            result.suggestions.push(format!(
+                "Add a headline title to your profile that matches the job title \"{}\"",
+                alignment.target_title
+            ));
+        } else if alignment.similarity < 0.5 {
+            let mut suggestion = format!(
+                "Adjust your headline \"{}\" to match the job title \"{}\"",
+                alignment.resume_title, alignment.target_title
+            );
+            if alignment.closest_title != alignment.resume_title
+                && alignment.closest_similarity >= 0.5
+            {
+                suggestion.push_str(&format!(
+                    "; your role as \"{}\" is a closer match",
+                    alignment.closest_title
+                ));
+            }
+            result.suggestions.push(suggestion);
+        }
+
+        if alignment.seniority_gap <= -2 {
+            result.suggestions.push(format!(
+                "The job is at {} level, which is more senior than your headline suggests",
+                alignment.target_seniority.label()
+            ));
+        } else if alignment.seniority_gap >= 2 {
+            result.suggestions.push(format!(
+                "The job is at {} level, which is more junior than your headline suggests",
+                alignment.target_seniority.label()
+            ));
+        }
+
+        let score = alignment
+            .similarity
+            .max(alignment.closest_similarity * 0.75);
+        result
+            .score_breakdown
+            .insert("title".to_string(), (score * 100.0).round() as u8);
+        result.title_alignment = Some(alignment);
+    }
+
+    fn analyze_experience_requirements(
+        &self,
+        resume: &Resume,
+        job_description: &str,
+        today: NaiveDate,
+        result: &mut OptimizationResult,
+    ) {
+        let matches = self
+            .experience_matcher
+            .match_requirements(resume, job_description, today);
+
+        for m in matches.iter().filter(|m| !m.met) {
+            let subject = match &m.requirement.skill {
+                Some(skill) => format!("of {}", skill),
+                None => "of experience".to_string(),
+            };
+            result.suggestions.push(format!(
+                "The job asks for {}+ years {}, but your resume shows {:.1}",
+                m.requirement.min_years, subject, m.candidate_years
+            ));
+        }
+
+        if let Some(score) = experience_match::requirements_score(&matches) {
+            result
+                .score_breakdown
+                .insert("experience".to_string(), score);
+        }
+        result.experience_requirements = matches;
+    }
+
+    fn analyze_education_requirements(
+        &self,
+        resume: &Resume,
+        job_description: &str,
+        result: &mut OptimizationResult,
+    ) {
+        let matches = self
+            .education_matcher
+            .match_requirements(resume, job_description);
+
+        for m in matches.iter().filter(|m| !m.met) {
+            let requirement = &m.requirement;
+            let wanted = if requirement.fields.is_empty() {
+                requirement.level.label().to_string()
+            } else {
+                format!(
+                    "{} in {}",
+                    requirement.level.label(),
+                    requirement.fields.join(" or ")
+                )
+            };
+            let article = if wanted.starts_with('A') { "an" } else { "a" };
+            let kind = if requirement.preferred {
+                "prefers"
+            } else {
+                "requires"
+            };
+
+            let suggestion = if !m.level_met {
+                format!(
+                    "The job {} {} {}; no matching degree was found on your resume",
+                    kind, article, wanted
+                )
+            } else if m.field_match == FieldMatch::Related {
+                format!(
+                    "The job {} {} {}; explain how your related field of study applies",
+                    kind, article, wanted
+                )
+            } else {
+                format!(
+                    "The job {} {} {}; your degree is in a different field",
+                    kind, article, wanted
+                )
+            };
+            result.suggestions.push(suggestion);
+        }
+
+        if let Some(score) = education_match::requirements_score(&matches) {
+            result
+                .score_breakdown
+                .insert("education".to_string(), score);
+        }
+        result.education_requirements = matches;
+    }
+
+    fn analyze_certification_requirements(
+        &self,
+        resume: &Resume,
+        job_description: &str,
+        today: NaiveDate,
+        result: &mut OptimizationResult,
+    ) {
+        let matches = self
+            .certification_matcher
+            .match_requirements(resume, job_description, today);
+
+        for m in &matches {
+            match m.status {
+                CertificationStatus::Missing => {
+                    result.missing_certifications.push(m.certification.clone());
+                }
+                CertificationStatus::Expired => {
+                    result.expired_certifications.push(m.certification.clone());
+                    result.suggestions.push(format!(
+                        "The job asks for {}, but your certification expired{}; renew it",
+                        m.certification,
+                        m.expiry_date
+                            .map(|date| format!(" on {}", date))
+                            .unwrap_or_default()
+                    ));
+                }
+                CertificationStatus::Held => {}
+            }
+        }
+
+        if !result.missing_certifications.is_empty() {
+            result.suggestions.push(format!(
+                "Add these certifications from the job description if you hold them: {}",
+                result.missing_certifications.join(", ")
+            ));
+        }
+
+        if let Some(score) = certification_match::requirements_score(&matches) {
+            result
+                .score_breakdown
+                .insert("certifications".to_string(), score);
+        }
+        result.certification_requirements = matches;
+    }
+
+    fn analyze_location_requirements(
+        &self,
+        resume: &Resume,
+        job_description: &str,
+        result: &mut OptimizationResult,
+    ) {
+        let location_match = location_match::match_location(resume, job_description);
+        if location_match.requirement.is_empty() {
+            return;
+        }
+
+        result
+            .suggestions
+            .extend(location_match.mismatches.iter().cloned());
+        result
+            .suggestions
+            .extend(location_match.missing_details.iter().cloned());
+        result.location_match = Some(location_match);
+    }
+
+    fn analyze_summary_section(
+        &self,
+        resume: &Resume,
+        job_keywords: &HashMap<String, usize>,
+        job_description: &str,
+        result: &mut OptimizationResult,
+    ) {
+        let summary = resume.profile.summary.to_lowercase();
+        if summary.is_empty() {
+            // Covered by the general "add a professional summary" suggestion
+            return;
+        }
+
+        let mut improvements = Vec::new();
+
+        let word_count = summary.split_whitespace().count();
+        if word_count > 100 {
+            improvements.push(format!(
+                "Shorten your summary to under 100 words (currently {})",
+                word_count
+            ));
+        }
+
+        let important_keywords: Vec<&String> = job_keywords
+            .iter()
+            .filter(|(_, importance)| **importance > 1)
+            .map(|(keyword, _)| keyword)
+            .collect();
+
+        if !important_keywords.is_empty() {
+            let mut missing: Vec<String> = important_keywords
+                .iter()
+                .filter(|keyword| !summary.contains(&keyword.to_lowercase()))
+                .map(|keyword| keyword.to_string())
+                .collect();
+            missing.sort_by(|a, b| job_keywords[b].cmp(&job_keywords[a]).then(a.cmp(b)));
+
+            let matched = important_keywords.len() - missing.len();
+            if matched == 0 {
+                improvements.push(format!(
+                    "Your summary doesn't mention any key terms from the job description, such as: {}",
+                    missing.iter().take(5).cloned().collect::<Vec<_>>().join(", ")
+                ));
+            } else if (matched as f32 / important_keywords.len() as f32) < 0.25 {
+                improvements.push(format!(
+                    "Work more of the job's key terms into your summary, such as: {}",
+                    missing
+                        .iter()
+                        .take(5)
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
+
+        let target_title = title_match::extract_job_title(job_description)
+            .unwrap_or_else(|| resume.profile.title.clone());
+        if !target_title.is_empty() && !summary.contains(&target_title.to_lowercase()) {
+            improvements.push(format!(
+                "Mention your target title \"{}\" in your summary",
+                target_title
+            ));
+        }
+
+        if !improvements.is_empty() {
+            result
+                .section_improvements
+                .insert("Summary".to_string(), improvements);
+        }
+    }
+
+    fn analyze_education_section(
+        &self,
+        resume: &Resume,
+        job_keywords: &HashMap<String, usize>,
+        result: &mut OptimizationResult,
+    ) {
+        let mut improvements = Vec::new();
+
+        for edu in &resume.education {
+            if edu.end_date.is_none() && !edu.current {
+                improvements.push(format!(
+                    "Add a graduation date (or mark as current) for {}",
+                    edu.institution
+                ));
+            } else if edu.start_date.is_none() && edu.end_date.is_none() {
+                improvements.push(format!("Add dates for {}", edu.institution));
+            }
+
+            // 3.0 on the 4.0 scale, in proportion on other scales
+            if let Some(gpa) = edu.gpa {
+                if gpa / edu.gpa_scale() < 0.75 {
+                    improvements.push(format!(
+                        "Consider leaving out your GPA for {} since it is below 3.0 out of 4.0",
+                        edu.institution
+                    ));
+                }
+            }
+
+            if !edu.courses.is_empty() {
+                let relevant = edu.courses.iter().any(|course| {
+                    let course = course.to_lowercase();
+                    job_keywords.iter().any(|(keyword, importance)| {
+                        *importance > 1 && course.contains(&keyword.to_lowercase())
+                    })
+                });
+
+                if !relevant {
+                    improvements.push(format!(
+                        "None of the courses listed for {} relate to the job description; list relevant courses or remove them",
+                        edu.institution
+                    ));
+                }
+            }
+        }
+
+        if !improvements.is_empty() {
+            result
+                .section_improvements
+                .insert("Education".to_string(), improvements);
+        }
+    }
+
+    fn analyze_projects_section(
+        &self,
+        resume: &Resume,
+        job_keywords: &HashMap<String, usize>,
+        result: &mut OptimizationResult,
+    ) {
+        let mut improvements = Vec::new();
+
+        for project in &resume.projects {
+            if project.url.is_none() && project.github.is_none() {
+                improvements.push(format!(
+                    "Add a link or repository for project \"{}\"",
+                    project.name
+                ));
+            }
+
+            if project.highlights.is_empty() {
+                improvements.push(format!(
+                    "Add highlights describing the impact of project \"{}\"",
+                    project.name
+                ));
+            }
+
+            if !project.technologies.is_empty() {
+                let relevant = project.technologies.iter().any(|tech| {
+                    let tech = tech.to_lowercase();
+                    job_keywords.iter().any(|(keyword, importance)| {
+                        *importance > 1 && tech.contains(&keyword.to_lowercase())
+                    })
+                });
+
+                if !relevant {
+                    improvements.push(format!(
+                        "The tech stack of project \"{}\" doesn't match the job's requirements",
+                        project.name
+                    ));
+                }
+            }
+        }
+
+        if !improvements.is_empty() {
+            result
+                .section_improvements
+                .insert("Projects".to_string(), improvements);
+        }
+    }
+
+    fn analyze_certifications_section(
+        &self,
+        resume: &Resume,
+        today: NaiveDate,
+        result: &mut OptimizationResult,
+    ) {
+        let mut improvements = Vec::new();
+
+        for cert in &resume.certifications {
+            if let Some(expiry) = cert.expiry_date {
+                if expiry < today {
+                    improvements.push(format!(
+                        "Certification \"{}\" expired on {}; renew it or remove it",
+                        cert.name, expiry
+                    ));
+                }
+            }
+
+            if cert.issuer.is_empty() {
+                improvements.push(format!(
+                    "Add the issuer for certification \"{}\"",
+                    cert.name
+                ));
+            }
+
+            if cert.credential_id.is_none() {
+                improvements.push(format!(
+                    "Add a credential ID for certification \"{}\" so it can be verified",
+                    cert.name
+                ));
+            }
+        }
+
+        if !improvements.is_empty() {
+            result
+                .section_improvements
+                .insert("Certifications".to_string(), improvements);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::resume::model::{Profile, Skill};
+    use crate::resume::model::{Certification, Profile, Skill};
 
     #[test]
     fn test_optimizer_creation() {
@@ -435,6 +1000,7 @@ mod tests {
             name: "Python".to_string(),
             level: None,
             years: None,
+            tags: vec![],
         });
 
         let job_description = "Looking for a Python developer with experience in Django and React.";
@@ -449,4 +1015,33 @@ mod tests {
                 || result.missing_keywords.contains(&"react".to_string())
         );
     }
+
+    #[test]
+    fn test_certification_expiry_is_judged_on_the_given_day() {
+        let optimizer = ResumeOptimizer::new();
+        let mut resume = Resume::new(Profile {
+            name: "John Doe".to_string(),
+            ..Profile::default()
+        });
+        resume.certifications.push(Certification {
+            name: "CKA".to_string(),
+            issuer: "CNCF".to_string(),
+            date_obtained: NaiveDate::from_ymd_opt(2021, 6, 1),
+            expiry_date: NaiveDate::from_ymd_opt(2024, 6, 1),
+            credential_id: Some("LF-1".to_string()),
+            url: None,
+        });
+
+        let mut result = OptimizationResult::default();
+        let before = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+        optimizer.analyze_certifications_section(&resume, before, &mut result);
+        assert!(!result.section_improvements.contains_key("Certifications"));
+
+        let after = NaiveDate::from_ymd_opt(2024, 6, 2).unwrap();
+        optimizer.analyze_certifications_section(&resume, after, &mut result);
+        assert_eq!(
+            result.section_improvements["Certifications"],
+            vec!["Certification \"CKA\" expired on 2024-06-01; renew it or remove it"]
+        );
+    }
 }
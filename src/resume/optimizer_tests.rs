@@ -1,9 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use crate::resume::model::{Experience, Profile, Resume, Skill, Skills};
+    use crate::resume::model::{
+        Certification, Education, Experience, ExperienceTranslation, Profile, Project, Resume,
+        Skill,
+    };
     use crate::resume::optimizer::ResumeOptimizer;
     use chrono::NaiveDate;
+    use std::collections::HashMap;
 
     fn create_test_resume() -> Resume {
         let mut resume = Resume::new(Profile {
@@ -18,16 +21,19 @@ mod tests {
             name: "Python".to_string(),
             level: Some("Advanced".to_string()),
             years: Some(5),
+            tags: vec![],
         });
         resume.skills.technical.push(Skill {
             name: "JavaScript".to_string(),
             level: Some("Intermediate".to_string()),
             years: Some(3),
+            tags: vec![],
         });
         resume.skills.technical.push(Skill {
             name: "React".to_string(),
             level: Some("Intermediate".to_string()),
             years: Some(2),
+            tags: vec![],
         });
 
         // Add experience
@@ -48,6 +54,9 @@ mod tests {
                 "AWS".to_string(),
                 "Docker".to_string(),
             ],
+            tags: vec![],
+            achievement_tags: HashMap::new(),
+            translations: HashMap::new(),
         });
 
         resume.experiences.push(Experience {
@@ -68,6 +77,9 @@ mod tests {
                 "React".to_string(),
                 "Node.js".to_string(),
             ],
+            tags: vec![],
+            achievement_tags: HashMap::new(),
+            translations: HashMap::new(),
         });
 
         resume.profile.summary =
@@ -83,7 +95,7 @@ mod tests {
         let resume = create_test_resume();
         let job_description = "Test job description";
         let result = optimizer.optimize(&resume, job_description);
-        assert!(result.score >= 0);
+        assert!(result.score <= 100);
     }
 
     #[test]
@@ -209,4 +221,240 @@ mod tests {
 
         assert!(result.score <= 40, "Score should be low for a poor match");
     }
+
+    #[test]
+    fn test_summary_analysis() {
+        let optimizer = ResumeOptimizer::new();
+        let resume = create_test_resume();
+
+        let job_description = "Platform Engineer. Rust, Kubernetes and Kubernetes operators.";
+        let result = optimizer.optimize(&resume, job_description);
+
+        let improvements = result.section_improvements.get("Summary").unwrap();
+        assert!(improvements.iter().any(|s| s.contains("rust")));
+        assert!(improvements
+            .iter()
+            .any(|s| s.contains("\"Platform Engineer\"")));
+    }
+
+    #[test]
+    fn test_education_analysis() {
+        let optimizer = ResumeOptimizer::new();
+        let mut resume = create_test_resume();
+        resume.education.push(Education {
+            institution: "State University".to_string(),
+            degree: "Bachelor of Science".to_string(),
+            field_of_study: "Computer Science".to_string(),
+            location: None,
+            start_date: Some(NaiveDate::from_ymd_opt(2011, 9, 1).unwrap()),
+            end_date: None,
+            current: false,
+            gpa: Some(2.7),
+            gpa_scale: None,
+            courses: vec!["Art History".to_string()],
+            achievements: vec![],
+            description: "".to_string(),
+            translations: HashMap::new(),
+        });
+
+        let job_description = "Looking for a Python developer. Python and SQL required.";
+        let result = optimizer.optimize(&resume, job_description);
+
+        let improvements = result.section_improvements.get("Education").unwrap();
+        assert!(improvements.iter().any(|s| s.contains("graduation date")));
+        assert!(improvements.iter().any(|s| s.contains("GPA")));
+        assert!(improvements.iter().any(|s| s.contains("courses")));
+    }
+
+    #[test]
+    fn test_gpa_judged_on_its_own_scale() {
+        let optimizer = ResumeOptimizer::new();
+        let mut resume = create_test_resume();
+        resume.education.push(Education {
+            institution: "TU Delft".to_string(),
+            degree: "MSc".to_string(),
+            field_of_study: "Computer Science".to_string(),
+            location: None,
+            start_date: Some(NaiveDate::from_ymd_opt(2014, 9, 1).unwrap()),
+            end_date: Some(NaiveDate::from_ymd_opt(2016, 6, 1).unwrap()),
+            current: false,
+            gpa: Some(8.5),
+            gpa_scale: Some(10.0),
+            courses: vec![],
+            achievements: vec![],
+            description: "".to_string(),
+            translations: HashMap::new(),
+        });
+
+        let job_description = "Looking for a Python developer. Python and SQL required.";
+        let result = optimizer.optimize(&resume, job_description);
+        let improvements = result.section_improvements.get("Education");
+        assert!(!improvements.is_some_and(|list| list.iter().any(|s| s.contains("GPA"))));
+
+        resume.education[0].gpa = Some(6.0);
+        let result = optimizer.optimize(&resume, job_description);
+        let improvements = result.section_improvements.get("Education").unwrap();
+        assert!(improvements.iter().any(|s| s.contains("GPA")));
+    }
+
+    #[test]
+    fn test_project_tech_stack_matches_partially() {
+        let optimizer = ResumeOptimizer::new();
+        let mut resume = create_test_resume();
+        resume.projects.push(Project {
+            name: "Data Pipeline".to_string(),
+            description: "Nightly ETL jobs".to_string(),
+            url: Some("https://example.com".to_string()),
+            github: None,
+            technologies: vec!["Python 3.12".to_string()],
+            start_date: None,
+            end_date: None,
+            highlights: vec!["Cut load times in half".to_string()],
+            tags: vec![],
+            translations: HashMap::new(),
+        });
+
+        let job_description = "Looking for a Python developer. Python and SQL required.";
+        let result = optimizer.optimize(&resume, job_description);
+        assert!(!result.section_improvements.contains_key("Projects"));
+    }
+
+    #[test]
+    fn test_projects_analysis() {
+        let optimizer = ResumeOptimizer::new();
+        let mut resume = create_test_resume();
+        resume.projects.push(Project {
+            name: "Side Project".to_string(),
+            description: "A small game".to_string(),
+            url: None,
+            github: None,
+            technologies: vec!["Lua".to_string()],
+            start_date: None,
+            end_date: None,
+            highlights: vec![],
+            tags: vec![],
+            translations: HashMap::new(),
+        });
+
+        let job_description = "Looking for a Python developer. Python and SQL required.";
+        let result = optimizer.optimize(&resume, job_description);
+
+        let improvements = result.section_improvements.get("Projects").unwrap();
+        assert_eq!(improvements.len(), 3);
+        assert!(improvements.iter().any(|s| s.contains("link")));
+        assert!(improvements.iter().any(|s| s.contains("highlights")));
+        assert!(improvements.iter().any(|s| s.contains("tech stack")));
+    }
+
+    #[test]
+    fn test_certifications_analysis() {
+        let optimizer = ResumeOptimizer::new();
+        let mut resume = create_test_resume();
+        resume.certifications.push(Certification {
+            name: "Old Cert".to_string(),
+            issuer: "".to_string(),
+            date_obtained: Some(NaiveDate::from_ymd_opt(2015, 1, 1).unwrap()),
+            expiry_date: Some(NaiveDate::from_ymd_opt(2018, 1, 1).unwrap()),
+            credential_id: None,
+            url: None,
+        });
+        resume.certifications.push(Certification {
+            name: "Valid Cert".to_string(),
+            issuer: "Issuer".to_string(),
+            date_obtained: Some(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()),
+            expiry_date: Some(NaiveDate::from_ymd_opt(2999, 1, 1).unwrap()),
+            credential_id: Some("ABC-123".to_string()),
+            url: None,
+        });
+
+        let result = optimizer.optimize(&resume, "Looking for a developer.");
+
+        let improvements = result.section_improvements.get("Certifications").unwrap();
+        assert_eq!(improvements.len(), 3);
+        assert!(improvements.iter().any(|s| s.contains("expired")));
+        assert!(improvements.iter().any(|s| s.contains("issuer")));
+        assert!(improvements.iter().any(|s| s.contains("credential ID")));
+        assert!(improvements.iter().all(|s| !s.contains("Valid Cert")));
+    }
+
+    const GERMAN_JOB: &str = "Wir suchen eine Entwicklerin mit Erfahrung in Cloud-Anwendungen \
+        und Python. Sie sind verantwortlich für die Plattform und das Team.";
+
+    fn add_german_translation(resume: &mut Resume) {
+        resume.experiences[0].translations.insert(
+            "de".to_string(),
+            ExperienceTranslation {
+                description: Some("Entwicklung von Cloud-Anwendungen geleitet.".to_string()),
+                ..ExperienceTranslation::default()
+            },
+        );
+    }
+
+    #[test]
+    fn test_compares_with_detected_language() {
+        let optimizer = ResumeOptimizer::new();
+        let mut resume = create_test_resume();
+        add_german_translation(&mut resume);
+
+        let result = optimizer.optimize(&resume, GERMAN_JOB);
+        assert_eq!(result.language, "de");
+        assert!(result.matching_keywords.contains_key("cloud-anwendungen"));
+    }
+
+    #[test]
+    fn test_missing_translation_for_job_language() {
+        let optimizer = ResumeOptimizer::new();
+        let result = optimizer.optimize(&create_test_resume(), GERMAN_JOB);
+
+        assert_eq!(result.language, "en");
+        assert!(!result.matching_keywords.contains_key("cloud-anwendungen"));
+        assert!(result.suggestions.contains(
+            &"The job description is in 'de' but the resume has no 'de' translation".to_string()
+        ));
+    }
+
+    #[test]
+    fn test_selected_language_overrides_detection() {
+        let mut optimizer = ResumeOptimizer::new();
+        optimizer.set_language("en");
+        let mut resume = create_test_resume();
+        add_german_translation(&mut resume);
+
+        let result = optimizer.optimize(&resume, GERMAN_JOB);
+        assert_eq!(result.language, "en");
+        assert!(!result.matching_keywords.contains_key("cloud-anwendungen"));
+    }
+
+    #[test]
+    fn test_missing_translation_for_selected_language() {
+        let mut optimizer = ResumeOptimizer::new();
+        optimizer.set_language("fr");
+        let result = optimizer.optimize(&create_test_resume(), GERMAN_JOB);
+
+        assert_eq!(result.language, "en");
+        assert!(result
+            .suggestions
+            .contains(&"The resume has no 'fr' translation for the selected language".to_string()));
+        assert!(result
+            .suggestions
+            .iter()
+            .all(|s| !s.starts_with("The job description is in")));
+    }
+
+    #[test]
+    fn test_undetected_language_uses_resume_language() {
+        let optimizer = ResumeOptimizer::new();
+        let mut resume = create_test_resume();
+        resume.metadata.language = "de".to_string();
+
+        let result = optimizer.optimize(
+            &resume,
+            "Senior Rust Engineer (m/w/d) - Remote. Kubernetes, AWS, Terraform",
+        );
+        assert_eq!(result.language, "de");
+        assert!(result
+            .suggestions
+            .iter()
+            .all(|s| !s.contains("translation")));
+    }
 }
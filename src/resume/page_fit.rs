@@ -0,0 +1,221 @@
+use crate::resume::model::Resume;
+use std::cmp::Reverse;
+
+/// Width and height of US Letter in inches, the paper the built-in metrics
+/// are measured on.
+pub const LETTER: (f32, f32) = (8.5, 11.0);
+
+/// How much text fits on one printed page of an output format.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageMetrics {
+    pub lines_per_page: f32,
+    pub chars_per_line: usize,
+}
+
+impl PageMetrics {
+    /// US Letter with 1in margins and 11pt type: HTML, DOCX and PDF.
+    pub const DOCUMENT: PageMetrics = PageMetrics {
+        lines_per_page: 47.0,
+        chars_per_line: 88,
+    };
+
+    /// A printed text file in 80 columns.
+    pub const PLAIN_TEXT: PageMetrics = PageMetrics {
+        lines_per_page: 66.0,
+        chars_per_line: 80,
+    };
+
+    /// These metrics, measured on US Letter with `margin` inches on every
+    /// side, scaled to the text area of a `paper` page (width and height in
+    /// inches) with `new_margin` inches on every side.
+    pub fn on_page(&self, margin: f32, paper: (f32, f32), new_margin: f32) -> PageMetrics {
+        let width = (paper.0 - 2.0 * new_margin).max(0.0) / (LETTER.0 - 2.0 * margin);
+        let height = (paper.1 - 2.0 * new_margin).max(0.0) / (LETTER.1 - 2.0 * margin);
+        PageMetrics {
+            lines_per_page: (self.lines_per_page * height).max(1.0),
+            chars_per_line: ((self.chars_per_line as f32 * width).round() as usize).max(1),
+        }
+    }
+
+    /// Estimated pages for Markdown written by the formatter. Headings take
+    /// extra space, blank lines count as paragraph spacing and long lines
+    /// wrap.
+    pub fn estimate(&self, markdown: &str) -> f32 {
+        let lines: f32 = markdown
+            .lines()
+            .map(|line| {
+                let line = line.trim_end();
+                if line.is_empty() {
+                    0.5
+                } else if line.starts_with("# ") {
+                    2.0
+                } else if line.starts_with("## ") {
+                    1.6
+                } else {
+                    let width = display_width(line.trim_start_matches("### "));
+                    width.div_ceil(self.chars_per_line).max(1) as f32
+                }
+            })
+            .sum();
+        lines / self.lines_per_page
+    }
+}
+
+/// A paper the `page_size` custom option accepts, under its Typst name
+/// (e.g. "us-letter") or its CSS name (e.g. "letter").
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Paper {
+    pub typst: &'static str,
+    pub css: &'static str,
+    /// Width and height in inches.
+    pub size: (f32, f32),
+}
+
+const PAPERS: [Paper; 7] = [
+    Paper {
+        typst: "us-letter",
+        css: "letter",
+        size: LETTER,
+    },
+    Paper {
+        typst: "us-legal",
+        css: "legal",
+        size: (8.5, 14.0),
+    },
+    Paper {
+        typst: "a3",
+        css: "A3",
+        size: (11.69, 16.54),
+    },
+    Paper {
+        typst: "a4",
+        css: "A4",
+        size: (8.27, 11.69),
+    },
+    Paper {
+        typst: "a5",
+        css: "A5",
+        size: (5.83, 8.27),
+    },
+    Paper {
+        typst: "b4",
+        css: "B4",
+        size: (9.84, 13.9),
+    },
+    Paper {
+        typst: "b5",
+        css: "B5",
+        size: (6.93, 9.84),
+    },
+];
+
+/// The paper for a `page_size` name, in any case.
+pub fn paper(name: &str) -> Option<&'static Paper> {
+    PAPERS.iter().find(|paper| {
+        name.eq_ignore_ascii_case(paper.typst) || name.eq_ignore_ascii_case(paper.css)
+    })
+}
+
+/// Width and height in inches of a paper named as in the `page_size`
+/// custom option, e.g. "us-letter" or "a4".
+pub fn paper_size(name: &str) -> Option<(f32, f32)> {
+    paper(name).map(|paper| paper.size)
+}
+
+/// A length such as "0.75in" or "2cm" in inches. An em counts as 11pt,
+/// the body size the metrics assume.
+pub fn inches(length: &str) -> Option<f32> {
+    [
+        ("in", 1.0),
+        ("cm", 1.0 / 2.54),
+        ("mm", 1.0 / 25.4),
+        ("pt", 1.0 / 72.0),
+        ("em", 11.0 / 72.0),
+    ]
+    .iter()
+    .find_map(|(unit, per_unit)| {
+        let number: f32 = length.strip_suffix(unit)?.parse().ok()?;
+        (number.is_finite() && number >= 0.0).then_some(number * per_unit)
+    })
+}
+
+/// Width in monospace columns; Chinese characters take two.
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| {
+            if c > '\u{2E7F}' && c.is_alphabetic() {
+                2
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+/// Removes the next least important piece of the resume and describes it,
+/// or returns `None` when nothing more can go. In order:
+/// 1. the last course of the longest course list
+/// 2. the achievement with the lowest `relevance` among roles with more
+///    than one; ties go to the oldest role and then its last achievement,
+///    since tailored resumes list the most relevant achievements first
+/// 3. whole sections from `optional_sections`, given as section name and
+///    heading, in the order they should be hidden
+pub(crate) fn trim_once(
+    resume: &mut Resume,
+    optional_sections: &[(&str, &str)],
+    relevance: impl Fn(&str) -> usize,
+) -> Option<String> {
+    if let Some(edu) = resume
+        .education
+        .iter_mut()
+        .filter(|edu| !edu.courses.is_empty())
+        .max_by_key(|edu| edu.courses.len())
+    {
+        let course = edu.courses.pop()?;
+        return Some(format!(
+            "Page limit: removed course '{}' from {}",
+            course, edu.institution
+        ));
+    }
+
+    let least_relevant = resume
+        .experiences
+        .iter()
+        .enumerate()
+        .filter(|(_, exp)| exp.achievements.len() > 1)
+        .flat_map(|(role, exp)| {
+            let relevance = &relevance;
+            exp.achievements
+                .iter()
+                .enumerate()
+                .map(move |(index, text)| {
+                    let age = (exp.current, exp.end_date.or(exp.start_date));
+                    ((relevance(text), age, Reverse(index)), role, index)
+                })
+        })
+        .min();
+    if let Some((_, role, index)) = least_relevant {
+        let exp = &mut resume.experiences[role];
+        let achievement = exp.remove_achievement(index);
+        return Some(format!(
+            "Page limit: removed achievement '{}' from {} at {}",
+            achievement, exp.title, exp.company
+        ));
+    }
+
+    for (section, heading) in optional_sections {
+        let hidden = match *section {
+            "volunteer" => !std::mem::take(&mut resume.volunteer).is_empty(),
+            "publications" => !std::mem::take(&mut resume.publications).is_empty(),
+            "languages" => !std::mem::take(&mut resume.languages).is_empty(),
+            "certifications" => !std::mem::take(&mut resume.certifications).is_empty(),
+            "projects" => !std::mem::take(&mut resume.projects).is_empty(),
+            _ => false,
+        };
+        if hidden {
+            return Some(format!("Page limit: hid the {} section", heading));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,172 @@
+use crate::resume::model::{Education, Experience, Profile, Resume, Volunteer};
+use crate::resume::page_fit::{inches, paper_size, trim_once, PageMetrics, LETTER};
+use crate::resume::test_support::{date, education, experience, strings};
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use std::collections::HashMap;
+
+const METRICS: PageMetrics = PageMetrics {
+    lines_per_page: 10.0,
+    chars_per_line: 20,
+};
+
+#[rstest]
+#[case("", 0.0)]
+#[case("short line", 0.1)]
+#[case("a line of exactly 20", 0.1)]
+#[case("a line longer than twenty characters", 0.2)]
+#[case("# Jane Smith", 0.2)]
+#[case("## Work Experience", 0.16)]
+#[case("### Engineer at Acme", 0.1)]
+#[case("one\n\ntwo", 0.25)]
+#[case("工程师工程师工程师工程师", 0.2)]
+fn test_estimate(#[case] markdown: &str, #[case] expected: f32) {
+    assert!((METRICS.estimate(markdown) - expected).abs() < 1e-5);
+}
+
+#[test]
+fn test_plain_text_fits_more_than_documents() {
+    let markdown = "- a bullet point\n".repeat(100);
+    assert!(
+        PageMetrics::PLAIN_TEXT.estimate(&markdown) < PageMetrics::DOCUMENT.estimate(&markdown)
+    );
+}
+
+#[rstest]
+#[case("1in", Some(1.0))]
+#[case("2.54cm", Some(1.0))]
+#[case("36pt", Some(0.5))]
+#[case("0mm", Some(0.0))]
+#[case("-1in", None)]
+#[case("1px", None)]
+fn test_inches(#[case] length: &str, #[case] expected: Option<f32>) {
+    match (inches(length), expected) {
+        (Some(actual), Some(expected)) => assert!((actual - expected).abs() < 1e-4),
+        (actual, expected) => assert_eq!(actual, expected),
+    }
+}
+
+#[test]
+fn test_metrics_follow_paper_and_margin() {
+    let letter = PageMetrics::DOCUMENT.on_page(1.0, LETTER, 1.0);
+    assert_eq!(letter, PageMetrics::DOCUMENT);
+
+    let a4 = PageMetrics::DOCUMENT.on_page(1.0, paper_size("A4").unwrap(), 1.0);
+    assert!(a4.lines_per_page > letter.lines_per_page);
+    assert!(a4.chars_per_line < letter.chars_per_line);
+
+    let wide_margins = PageMetrics::DOCUMENT.on_page(1.0, LETTER, 2.0);
+    assert_eq!(wide_margins.chars_per_line, 61);
+    assert!((wide_margins.lines_per_page - 47.0 * 7.0 / 9.0).abs() < 1e-4);
+    assert_eq!(paper_size("tabloid"), None);
+}
+
+fn job(company: &str, start: i32, current: bool, achievements: &[&str]) -> Experience {
+    Experience {
+        start_date: date(start, 1),
+        end_date: if current { None } else { date(start + 2, 1) },
+        current,
+        achievements: strings(achievements),
+        ..experience("Engineer", company)
+    }
+}
+
+fn create_test_resume() -> Resume {
+    let mut resume = Resume::new(Profile::default());
+    resume
+        .experiences
+        .push(job("Current", 2021, true, &["c1", "c2"]));
+    resume
+        .experiences
+        .push(job("Oldest", 2015, false, &["o1", "o2", "o3"]));
+    resume
+        .experiences
+        .push(job("Middle", 2018, false, &["m1", "m2"]));
+    resume.education.push(Education {
+        start_date: date(2011, 1),
+        end_date: date(2015, 1),
+        courses: strings(&["Algorithms"]),
+        ..education("MIT", "BSc", "Computer Science")
+    });
+    resume.volunteer.push(Volunteer {
+        organization: "Code Club".to_string(),
+        role: "Mentor".to_string(),
+        start_date: date(2019, 1),
+        end_date: None,
+        current: true,
+        description: "".to_string(),
+        location: None,
+        translations: HashMap::new(),
+    });
+    resume
+}
+
+#[test]
+fn test_trim_order() {
+    let mut resume = create_test_resume();
+    let sections = [("projects", "Projects"), ("volunteer", "Volunteering")];
+
+    let mut removed = Vec::new();
+    while let Some(description) = trim_once(&mut resume, &sections, |_| 0) {
+        removed.push(description);
+    }
+
+    assert_eq!(
+        removed,
+        vec![
+            "Page limit: removed course 'Algorithms' from MIT",
+            "Page limit: removed achievement 'o3' from Engineer at Oldest",
+            "Page limit: removed achievement 'o2' from Engineer at Oldest",
+            "Page limit: removed achievement 'm2' from Engineer at Middle",
+            "Page limit: removed achievement 'c2' from Engineer at Current",
+            "Page limit: hid the Volunteering section",
+        ]
+    );
+    let kept: Vec<&Vec<String>> = resume
+        .experiences
+        .iter()
+        .map(|exp| &exp.achievements)
+        .collect();
+    assert_eq!(
+        kept,
+        vec![
+            &vec!["c1".to_string()],
+            &vec!["o1".to_string()],
+            &vec!["m1".to_string()]
+        ]
+    );
+}
+
+#[test]
+fn test_trim_skips_sections_not_listed() {
+    let mut resume = create_test_resume();
+    resume.education.clear();
+    for exp in &mut resume.experiences {
+        exp.achievements.truncate(1);
+    }
+
+    assert_eq!(trim_once(&mut resume, &[], |_| 0), None);
+    assert_eq!(resume.volunteer.len(), 1);
+}
+
+#[test]
+fn test_trim_least_relevant_achievements_first() {
+    let mut resume = create_test_resume();
+    let relevance = |text: &str| if text == "o3" { 2 } else { 0 };
+
+    let mut removed = Vec::new();
+    while let Some(description) = trim_once(&mut resume, &[], relevance) {
+        removed.push(description);
+    }
+
+    assert_eq!(
+        removed[1..],
+        [
+            "Page limit: removed achievement 'o2' from Engineer at Oldest",
+            "Page limit: removed achievement 'o1' from Engineer at Oldest",
+            "Page limit: removed achievement 'm2' from Engineer at Middle",
+            "Page limit: removed achievement 'c2' from Engineer at Current",
+        ]
+    );
+    assert_eq!(resume.experiences[1].achievements, vec!["o3"]);
+}
@@ -0,0 +1,379 @@
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SectionKind {
+    Contact,
+    Summary,
+    Experience,
+    Education,
+    Skills,
+    Projects,
+    Certifications,
+    Languages,
+    Publications,
+    Volunteer,
+}
+
+impl SectionKind {
+    /// Recognizes the section headings most resumes use, ignoring case,
+    /// markup and a trailing colon.
+    pub fn from_heading(line: &str) -> Option<SectionKind> {
+        let heading = line
+            .trim()
+            .trim_start_matches('#')
+            .trim()
+            .trim_end_matches(':')
+            .to_lowercase();
+
+        match heading.as_str() {
+            "contact" | "contact information" | "contact details" => Some(Self::Contact),
+            "summary" | "professional summary" | "profile" | "about me" | "objective" => {
+                Some(Self::Summary)
+            }
+            "experience"
+            | "work experience"
+            | "professional experience"
+            | "employment"
+            | "employment history"
+            | "work history" => Some(Self::Experience),
+            "education" | "academic background" => Some(Self::Education),
+            "skills" | "technical skills" | "core competencies" | "skills & tools" => {
+                Some(Self::Skills)
+            }
+            "projects" | "personal projects" | "selected projects" => Some(Self::Projects),
+            "certifications" | "certificates" | "licenses & certifications" => {
+                Some(Self::Certifications)
+            }
+            "languages" => Some(Self::Languages),
+            "publications" => Some(Self::Publications),
+            "volunteer" | "volunteering" | "volunteer experience" => Some(Self::Volunteer),
+            _ => None,
+        }
+    }
+}
+
+/// Start, end and whether the entry is current, as read from a date range.
+pub type DateRange = (Option<PartialDate>, Option<PartialDate>, bool);
+
+/// A date as precise as the text it was read from, e.g. "2020" has no month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialDate {
+    pub year: i32,
+    pub month: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedExperience {
+    pub title: String,
+    pub company: String,
+    pub start_date: Option<PartialDate>,
+    pub end_date: Option<PartialDate>,
+    pub current: bool,
+    /// The lines below the entry heading, up to the next entry.
+    pub lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedResume {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    /// Recognized sections in document order with their lines.
+    pub sections: Vec<(SectionKind, Vec<String>)>,
+    pub experiences: Vec<ParsedExperience>,
+    pub skills: Vec<String>,
+}
+
+impl ParsedResume {
+    pub fn section(&self, kind: SectionKind) -> Option<&[String]> {
+        self.sections
+            .iter()
+            .find(|(section, _)| *section == kind)
+            .map(|(_, lines)| lines.as_slice())
+    }
+}
+
+/// Reads resumes the way a generic ATS does: from plain text, finding
+/// sections by their headings and fields by their shape rather than by
+/// any markup.
+pub struct ResumeParser {
+    email_re: Regex,
+    phone_re: Regex,
+    month_year_re: Regex,
+    numeric_date_re: Regex,
+    year_re: Regex,
+    range_re: Regex,
+}
+
+impl Default for ResumeParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const SKILL_SUBHEADINGS: [&str; 6] = [
+    "technical skills",
+    "soft skills",
+    "tools",
+    "languages",
+    "other",
+    "other skills",
+];
+
+impl ResumeParser {
+    pub fn new() -> Self {
+        Self {
+            email_re: Regex::new(r"[\w.+-]+@[\w-]+(?:\.[\w-]+)+").unwrap(),
+            phone_re: Regex::new(r"\+?\(?\d[\d\s().-]{6,}\d").unwrap(),
+            month_year_re: Regex::new(r"(?i)\b([a-z]{3,9})\.?\s+(\d{4})\b").unwrap(),
+            numeric_date_re: Regex::new(
+                r"\b(?:(\d{1,2})/(\d{4})|(\d{4})-(\d{1,2})(?:-\d{1,2})?)\b",
+            )
+            .unwrap(),
+            year_re: Regex::new(r"\b(19|20)\d{2}\b").unwrap(),
+            range_re: Regex::new(
+                r"(?i)\b(?:[a-z]{3,9}\.?\s+\d{4}|\d{1,2}/\d{4}|\d{4}-\d{1,2}|\d{4})\s+(?:-|–|—|to)\s+(?:[a-z]{3,9}\.?\s+\d{4}|\d{1,2}/\d{4}|\d{4}-\d{1,2}|\d{4}|present|current|now|today)\b",
+            )
+            .unwrap(),
+        }
+    }
+
+    pub fn parse_text(&self, text: &str) -> ParsedResume {
+        let lines: Vec<&str> = text.lines().map(str::trim).collect();
+        let mut parsed = ParsedResume {
+            email: self.email_re.find(text).map(|m| m.as_str().to_string()),
+            phone: self
+                .phone_re
+                .find_iter(text)
+                .map(|m| m.as_str().trim().to_string())
+                .find(|candidate| candidate.chars().filter(char::is_ascii_digit).count() >= 7),
+            ..ParsedResume::default()
+        };
+
+        parsed.name = lines
+            .iter()
+            .find(|line| !line.is_empty())
+            .filter(|line| {
+                SectionKind::from_heading(line).is_none()
+                    && !line.contains('@')
+                    && line.split_whitespace().count() <= 5
+            })
+            .map(|line| line.to_string());
+
+        for line in &lines {
+            if let Some(kind) = SectionKind::from_heading(line) {
+                // "Technical Skills" under "Skills" continues the same section
+                if parsed.sections.last().map(|(last, _)| *last) != Some(kind) {
+                    parsed.sections.push((kind, vec![]));
+                }
+            } else if let Some((_, section_lines)) = parsed.sections.last_mut() {
+                if !line.is_empty() {
+                    section_lines.push(line.to_string());
+                }
+            }
+        }
+
+        if let Some(lines) = parsed.section(SectionKind::Experience) {
+            parsed.experiences = self.parse_experiences(lines);
+        }
+        if let Some(lines) = parsed.section(SectionKind::Skills) {
+            parsed.skills = Self::parse_skills(lines);
+        }
+
+        parsed
+    }
+
+    fn parse_experiences(&self, lines: &[String]) -> Vec<ParsedExperience> {
+        let mut experiences: Vec<ParsedExperience> = vec![];
+        let mut date_line = None;
+
+        for (index, line) in lines.iter().enumerate() {
+            if date_line == Some(index) {
+                continue;
+            }
+            let followed_by_dates = lines
+                .get(index + 1)
+                .is_some_and(|next| self.parse_date_range(next).is_some());
+            let entry = if followed_by_dates {
+                Self::split_entry_heading(line)
+            } else {
+                None
+            };
+
+            match entry {
+                Some((title, company)) => {
+                    let (start_date, end_date, current) = self
+                        .parse_date_range(&lines[index + 1])
+                        .unwrap_or((None, None, false));
+                    date_line = Some(index + 1);
+                    experiences.push(ParsedExperience {
+                        title,
+                        company,
+                        start_date,
+                        end_date,
+                        current,
+                        lines: vec![],
+                    });
+                }
+                None => {
+                    if let Some(experience) = experiences.last_mut() {
+                        experience.lines.push(line.clone());
+                    }
+                }
+            }
+        }
+
+        experiences
+    }
+
+    /// Splits "Title at Company", "Title | Company", "Title, Company" or
+    /// "Title - Company" into its parts.
+    fn split_entry_heading(line: &str) -> Option<(String, String)> {
+        let line = line.trim_start_matches('#').trim();
+        [" at ", " | ", " — ", " – ", " - ", ", "]
+            .iter()
+            .find_map(|separator| line.split_once(separator))
+            .map(|(title, company)| (title.trim().to_string(), company.trim().to_string()))
+            .filter(|(title, company)| !title.is_empty() && !company.is_empty())
+    }
+
+    fn parse_skills(lines: &[String]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.trim_start_matches(['-', '*', '•', '#']).trim())
+            .filter(|line| {
+                !line.ends_with(':') && !SKILL_SUBHEADINGS.contains(&line.to_lowercase().as_str())
+            })
+            .flat_map(|line| line.split([',', ';', '|', '•']))
+            .map(|skill| skill.trim().to_string())
+            .filter(|skill| !skill.is_empty())
+            .collect()
+    }
+
+    pub fn parse_date(&self, text: &str) -> Option<PartialDate> {
+        if let Some(caps) = self.month_year_re.captures(text) {
+            if let Some(month) = month_number(&caps[1]) {
+                return Some(PartialDate {
+                    year: caps[2].parse().ok()?,
+                    month: Some(month),
+                });
+            }
+        }
+        if let Some(caps) = self.numeric_date_re.captures(text) {
+            let (month, year) = match (caps.get(1), caps.get(2)) {
+                (Some(month), Some(year)) => (month.as_str(), year.as_str()),
+                _ => (caps.get(4)?.as_str(), caps.get(3)?.as_str()),
+            };
+            let month: u32 = month.parse().ok()?;
+            if (1..=12).contains(&month) {
+                return Some(PartialDate {
+                    year: year.parse().ok()?,
+                    month: Some(month),
+                });
+            }
+        }
+        self.year_re.find(text).map(|year| PartialDate {
+            year: year.as_str().parse().unwrap(),
+            month: None,
+        })
+    }
+
+    /// Parses "January 2020 - Present", "01/2019 – 12/2021", "From 2020" or
+    /// "Until 2021" into start, end and whether the role is current.
+    pub fn parse_date_range(&self, line: &str) -> Option<DateRange> {
+        let line = line.trim().trim_matches(|c| c == '_' || c == '*').trim();
+        let lower = line.to_lowercase();
+
+        if let Some(rest) = lower.strip_prefix("from ") {
+            return self
+                .parse_date(rest)
+                .map(|start| (Some(start), None, false));
+        }
+        if let Some(rest) = lower.strip_prefix("until ") {
+            let current = is_present(rest);
+            let end = self.parse_date(rest);
+            return (current || end.is_some()).then_some((None, end, current));
+        }
+
+        let (start, end) = [" - ", " – ", " — ", " to "]
+            .iter()
+            .find_map(|separator| lower.split_once(separator))?;
+        let start = self.parse_date(start)?;
+        // The range should be the whole line, not part of a sentence
+        if line.split_whitespace().count() > 8 {
+            return None;
+        }
+        if is_present(end) {
+            return Some((Some(start), None, true));
+        }
+        Some((Some(start), Some(self.parse_date(end)?), false))
+    }
+
+    /// Finds a date range anywhere in the line, e.g. in
+    /// "Engineer, Acme (2019 - 2021)", and returns it with the rest of the
+    /// line, whose parts are joined with " | ".
+    pub fn find_date_range(&self, line: &str) -> Option<(DateRange, String)> {
+        let found = self.range_re.find_iter(line).find_map(|found| {
+            let range = self.parse_date_range(found.as_str())?;
+            let rest = [&line[..found.start()], &line[found.end()..]]
+                .iter()
+                .map(|part| {
+                    part.trim_matches(|c: char| c.is_whitespace() || "|,()[]–—-·•".contains(c))
+                })
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join(" | ");
+            Some((range, rest))
+        });
+
+        // "From 2020" and "Until 2021" have no range for the pattern to find
+        found.or_else(|| {
+            self.parse_date_range(line)
+                .map(|range| (range, String::new()))
+        })
+    }
+
+    /// Turns HTML into the text an ATS would extract: tags become line
+    /// breaks or disappear, and common entities are decoded.
+    pub fn html_to_text(html: &str) -> String {
+        let head_re = Regex::new(r"(?is)<(head|script|style)\b.*?</(head|script|style)>").unwrap();
+        let block_re =
+            Regex::new(r"(?i)</?(p|div|h[1-6]|li|ul|ol|tr|br|section|header|footer)\b[^>]*>")
+                .unwrap();
+        let tag_re = Regex::new(r"<[^>]*>").unwrap();
+
+        let text = head_re.replace_all(html, "");
+        let text = block_re.replace_all(&text, "\n");
+        let text = tag_re.replace_all(&text, "");
+        text.replace("&nbsp;", " ")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&amp;", "&")
+    }
+}
+
+fn is_present(text: &str) -> bool {
+    matches!(
+        text.trim().to_lowercase().as_str(),
+        "present" | "current" | "now" | "today"
+    )
+}
+
+fn month_number(name: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let name = name.to_lowercase();
+    if name.len() < 3 {
+        return None;
+    }
+    let position = MONTHS.iter().position(|month| name.starts_with(month))?;
+    // Reject words that merely start like a month, e.g. "market"
+    let full = chrono::Month::try_from(position as u8 + 1)
+        .ok()?
+        .name()
+        .to_lowercase();
+    full.starts_with(&name).then_some(position as u32 + 1)
+}
@@ -0,0 +1,152 @@
+use crate::resume::parser::*;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+const RESUME_TEXT: &str = "Jane Smith
+jane.smith@example.com | (555) 123-4567
+
+Professional Summary
+Backend engineer with a focus on payments.
+
+Work Experience
+
+Senior Engineer at Acme Corp
+March 2020 - Present
+- Led the billing migration
+
+Software Engineer | Initech
+01/2017 – 02/2020
+- Built internal tools
+
+Skills:
+Technical Skills
+Rust, Go, SQL
+- Kubernetes
+";
+
+#[test]
+fn test_parse_contact_details() {
+    let parsed = ResumeParser::new().parse_text(RESUME_TEXT);
+    assert_eq!(parsed.name.as_deref(), Some("Jane Smith"));
+    assert_eq!(parsed.email.as_deref(), Some("jane.smith@example.com"));
+    assert_eq!(parsed.phone.as_deref(), Some("(555) 123-4567"));
+}
+
+#[test]
+fn test_parse_sections() {
+    let parsed = ResumeParser::new().parse_text(RESUME_TEXT);
+    let kinds: Vec<SectionKind> = parsed.sections.iter().map(|(kind, _)| *kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            SectionKind::Summary,
+            SectionKind::Experience,
+            SectionKind::Skills
+        ]
+    );
+    assert_eq!(
+        parsed.section(SectionKind::Summary),
+        Some(&["Backend engineer with a focus on payments.".to_string()][..])
+    );
+    assert_eq!(parsed.section(SectionKind::Education), None);
+}
+
+#[test]
+fn test_parse_experiences() {
+    let parsed = ResumeParser::new().parse_text(RESUME_TEXT);
+    assert_eq!(parsed.experiences.len(), 2);
+
+    let first = &parsed.experiences[0];
+    assert_eq!(first.title, "Senior Engineer");
+    assert_eq!(first.company, "Acme Corp");
+    assert_eq!(
+        first.start_date,
+        Some(PartialDate {
+            year: 2020,
+            month: Some(3)
+        })
+    );
+    assert!(first.current);
+    assert_eq!(first.lines, vec!["- Led the billing migration".to_string()]);
+
+    let second = &parsed.experiences[1];
+    assert_eq!(second.company, "Initech");
+    assert_eq!(
+        second.end_date,
+        Some(PartialDate {
+            year: 2020,
+            month: Some(2)
+        })
+    );
+    assert!(!second.current);
+}
+
+#[test]
+fn test_parse_skills() {
+    let parsed = ResumeParser::new().parse_text(RESUME_TEXT);
+    assert_eq!(parsed.skills, vec!["Rust", "Go", "SQL", "Kubernetes"]);
+}
+
+#[rstest]
+#[case("June 2019", Some((2019, Some(6))))]
+#[case("Sept. 2019", Some((2019, Some(9))))]
+#[case("2019-06", Some((2019, Some(6))))]
+#[case("06/2019", Some((2019, Some(6))))]
+#[case("2019", Some((2019, None)))]
+#[case("Market 2019", Some((2019, None)))]
+#[case("Recently", None)]
+fn test_parse_date(#[case] text: &str, #[case] expected: Option<(i32, Option<u32>)>) {
+    let expected = expected.map(|(year, month)| PartialDate { year, month });
+    assert_eq!(ResumeParser::new().parse_date(text), expected);
+}
+
+#[rstest]
+#[case("January 2020 - Present", true, true, false)]
+#[case("From 2020", true, false, false)]
+#[case("Until 2021", false, true, false)]
+#[case("2018 to 2019", true, true, false)]
+#[case(
+    "Worked on 2018 - 2019 roadmap planning with several teams across the org",
+    false,
+    false,
+    true
+)]
+fn test_parse_date_range(
+    #[case] line: &str,
+    #[case] has_start: bool,
+    #[case] has_end_or_current: bool,
+    #[case] rejected: bool,
+) {
+    let range = ResumeParser::new().parse_date_range(line);
+    if rejected {
+        assert_eq!(range, None);
+        return;
+    }
+    let (start, end, current) = range.unwrap();
+    assert_eq!(start.is_some(), has_start);
+    assert_eq!(end.is_some() || current, has_end_or_current);
+}
+
+#[test]
+fn test_html_to_text() {
+    let html = "<html><head><title>Jane</title><style>h1 { color: red; }</style></head>\
+        <body><h1>Jane Smith</h1><p>Tom &amp; Jerry&nbsp;Inc</p><ul><li>Rust</li></ul></body></html>";
+    let lines: Vec<String> = ResumeParser::html_to_text(html)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+    assert_eq!(lines, vec!["Jane Smith", "Tom & Jerry Inc", "Rust"]);
+}
+
+#[rstest]
+#[case("Acme | Engineer | 2019 - 2021", "Acme | Engineer")]
+#[case("Engineer, Acme (Jan 2019 - Present)", "Engineer, Acme")]
+#[case("Engineer | 2019 - 2021 | Berlin", "Engineer | Berlin")]
+#[case("From 2020", "")]
+fn test_find_date_range(#[case] line: &str, #[case] rest: &str) {
+    let (range, found_rest) = ResumeParser::new().find_date_range(line).unwrap();
+    assert!(range.0.is_some() || range.1.is_some());
+    assert_eq!(found_rest, rest);
+}
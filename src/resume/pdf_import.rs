@@ -0,0 +1,452 @@
+use lopdf::content::Content;
+use lopdf::{Dictionary, Document, Encoding, Object};
+use std::collections::BTreeMap;
+
+/// Width of a glyph in thousandths of the font size when the font doesn't
+/// say, roughly the average for proportional fonts.
+const DEFAULT_GLYPH_WIDTH: f32 = 500.0;
+
+type Matrix = [f32; 6];
+
+const IDENTITY: Matrix = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
+fn multiply(m: &Matrix, n: &Matrix) -> Matrix {
+    [
+        m[0] * n[0] + m[1] * n[2],
+        m[0] * n[1] + m[1] * n[3],
+        m[2] * n[0] + m[3] * n[2],
+        m[2] * n[1] + m[3] * n[3],
+        m[4] * n[0] + m[5] * n[2] + n[4],
+        m[4] * n[1] + m[5] * n[3] + n[5],
+    ]
+}
+
+fn translation(tx: f32, ty: f32) -> Matrix {
+    [1.0, 0.0, 0.0, 1.0, tx, ty]
+}
+
+/// Moves to the start of the next line, offset from the current line start.
+fn next_line(text_matrix: &mut Matrix, line_matrix: &mut Matrix, tx: f32, ty: f32) {
+    *line_matrix = multiply(&translation(tx, ty), line_matrix);
+    *text_matrix = *line_matrix;
+}
+
+/// A piece of text drawn by one text-showing operator, in page space.
+#[derive(Debug, Clone)]
+pub(crate) struct TextRun {
+    x: f32,
+    y: f32,
+    width: f32,
+    /// Font size in points, after the text and transformation matrices.
+    pub(crate) size: f32,
+    pub(crate) text: String,
+    /// Drawn in text render mode 3, neither filled nor stroked.
+    pub(crate) invisible: bool,
+    /// Filled in white.
+    pub(crate) white: bool,
+}
+
+/// The parts of the graphics state that `q` saves and `Q` restores.
+#[derive(Clone, Copy)]
+struct GraphicsState<'a> {
+    ctm: Matrix,
+    font: Option<&'a PageFont<'a>>,
+    size: f32,
+    leading: f32,
+    render_mode: i64,
+    white: bool,
+}
+
+impl Default for GraphicsState<'_> {
+    fn default() -> Self {
+        Self {
+            ctm: IDENTITY,
+            font: None,
+            size: 0.0,
+            leading: 0.0,
+            render_mode: 0,
+            white: false,
+        }
+    }
+}
+
+impl TextRun {
+    fn end(&self) -> f32 {
+        self.x + self.width
+    }
+}
+
+struct PageFont<'a> {
+    encoding: Option<Encoding<'a>>,
+    first_char: i64,
+    widths: Vec<f32>,
+}
+
+impl PageFont<'_> {
+    fn decode(&self, bytes: &[u8]) -> String {
+        match &self.encoding {
+            Some(encoding) => Document::decode_text(encoding, bytes)
+                .unwrap_or_else(|_| String::from_utf8_lossy(bytes).to_string()),
+            None => String::from_utf8_lossy(bytes).to_string(),
+        }
+    }
+
+    /// Advance of the string in thousandths of the font size.
+    fn advance(&self, bytes: &[u8], text: &str) -> f32 {
+        match &self.encoding {
+            Some(Encoding::OneByteEncoding(_)) if !self.widths.is_empty() => bytes
+                .iter()
+                .map(|byte| {
+                    usize::try_from(*byte as i64 - self.first_char)
+                        .ok()
+                        .and_then(|index| self.widths.get(index))
+                        .copied()
+                        .unwrap_or(DEFAULT_GLYPH_WIDTH)
+                })
+                .sum(),
+            _ => text.chars().count() as f32 * DEFAULT_GLYPH_WIDTH,
+        }
+    }
+}
+
+/// Recovers the text of a PDF in reading order. Runs are grouped into lines
+/// by baseline, and a page whose lines leave a consistent vertical gap is
+/// read one column at a time, so two-column resumes don't come out with
+/// their columns interleaved.
+pub struct PdfTextExtractor;
+
+impl Default for PdfTextExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PdfTextExtractor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn extract_text(&self, bytes: &[u8]) -> Result<String, String> {
+        let document =
+            Document::load_mem(bytes).map_err(|e| format!("Failed to read PDF: {}", e))?;
+        if document.is_encrypted() {
+            return Err("Failed to read PDF: the document is encrypted".to_string());
+        }
+
+        let mut pages = vec![];
+        for (page_number, page_id) in document.get_pages() {
+            let runs = Self::page_runs(&document, page_id)
+                .map_err(|e| format!("Failed to read page {}: {}", page_number, e))?;
+            let width = Self::page_width(&document, page_id);
+            pages.push(Self::reading_order(runs, width).join("\n"));
+        }
+        Ok(pages.join("\n\n"))
+    }
+
+    /// The text runs of each page in drawing order, with the page number.
+    pub(crate) fn text_runs(&self, bytes: &[u8]) -> Result<Vec<(u32, Vec<TextRun>)>, String> {
+        let document =
+            Document::load_mem(bytes).map_err(|e| format!("Failed to read PDF: {}", e))?;
+        document
+            .get_pages()
+            .into_iter()
+            .map(|(page_number, page_id)| {
+                Self::page_runs(&document, page_id)
+                    .map(|runs| (page_number, runs))
+                    .map_err(|e| format!("Failed to read page {}: {}", page_number, e))
+            })
+            .collect()
+    }
+
+    fn page_width(document: &Document, page_id: lopdf::ObjectId) -> f32 {
+        let mut dictionary = document.get_dictionary(page_id).ok();
+        // MediaBox may be inherited from the page tree
+        while let Some(dict) = dictionary {
+            if let Ok(media_box) = dict.get(b"MediaBox").and_then(Object::as_array) {
+                let values: Vec<f32> = media_box.iter().filter_map(|v| v.as_float().ok()).collect();
+                if values.len() == 4 {
+                    return (values[2] - values[0]).abs();
+                }
+            }
+            dictionary = dict
+                .get(b"Parent")
+                .and_then(Object::as_reference)
+                .and_then(|id| document.get_dictionary(id))
+                .ok();
+        }
+        612.0
+    }
+
+    fn page_fonts(
+        document: &Document,
+        page_id: lopdf::ObjectId,
+    ) -> Result<BTreeMap<Vec<u8>, PageFont<'_>>, String> {
+        let fonts = document
+            .get_page_fonts(page_id)
+            .map_err(|e| format!("Failed to read fonts: {}", e))?;
+        Ok(fonts
+            .into_iter()
+            .map(|(name, font): (Vec<u8>, &Dictionary)| {
+                let widths = font
+                    .get(b"Widths")
+                    .and_then(|widths| match widths {
+                        Object::Reference(id) => document.get_object(*id),
+                        other => Ok(other),
+                    })
+                    .and_then(Object::as_array)
+                    .map(|widths| widths.iter().filter_map(|w| w.as_float().ok()).collect())
+                    .unwrap_or_default();
+                let font = PageFont {
+                    encoding: font.get_font_encoding(document).ok(),
+                    first_char: font.get(b"FirstChar").and_then(Object::as_i64).unwrap_or(0),
+                    widths,
+                };
+                (name, font)
+            })
+            .collect())
+    }
+
+    fn page_runs(document: &Document, page_id: lopdf::ObjectId) -> Result<Vec<TextRun>, String> {
+        let fonts = Self::page_fonts(document, page_id)?;
+        let data = document
+            .get_page_content(page_id)
+            .map_err(|e| e.to_string())?;
+        let content = Content::decode(&data).map_err(|e| e.to_string())?;
+
+        let mut runs = vec![];
+        let mut state = GraphicsState::default();
+        let mut stack = vec![];
+        let mut text_matrix = IDENTITY;
+        let mut line_matrix = IDENTITY;
+
+        for operation in &content.operations {
+            let numbers: Vec<f32> = operation
+                .operands
+                .iter()
+                .filter_map(|operand| operand.as_float().ok())
+                .collect();
+            match operation.operator.as_str() {
+                "q" => stack.push(state),
+                "Q" => state = stack.pop().unwrap_or_default(),
+                "cm" if numbers.len() == 6 => {
+                    let m = [
+                        numbers[0], numbers[1], numbers[2], numbers[3], numbers[4], numbers[5],
+                    ];
+                    state.ctm = multiply(&m, &state.ctm);
+                }
+                "rg" | "g" => {
+                    state.white = !numbers.is_empty() && numbers.iter().all(|c| *c >= 0.99)
+                }
+                "k" => state.white = numbers.len() == 4 && numbers.iter().all(|c| *c <= 0.01),
+                "Tr" => {
+                    state.render_mode = numbers
+                        .first()
+                        .map(|mode| *mode as i64)
+                        .unwrap_or(state.render_mode)
+                }
+                "BT" => {
+                    text_matrix = IDENTITY;
+                    line_matrix = IDENTITY;
+                }
+                "Tf" => {
+                    state.font = operation
+                        .operands
+                        .first()
+                        .and_then(|name| name.as_name().ok())
+                        .and_then(|name| fonts.get(name));
+                    state.size = numbers.first().copied().unwrap_or(state.size);
+                }
+                "TL" => state.leading = numbers.first().copied().unwrap_or(state.leading),
+                "Td" if numbers.len() == 2 => {
+                    next_line(&mut text_matrix, &mut line_matrix, numbers[0], numbers[1])
+                }
+                "TD" if numbers.len() == 2 => {
+                    state.leading = -numbers[1];
+                    next_line(&mut text_matrix, &mut line_matrix, numbers[0], numbers[1]);
+                }
+                "Tm" if numbers.len() == 6 => {
+                    text_matrix = [
+                        numbers[0], numbers[1], numbers[2], numbers[3], numbers[4], numbers[5],
+                    ];
+                    line_matrix = text_matrix;
+                }
+                "T*" => next_line(&mut text_matrix, &mut line_matrix, 0.0, -state.leading),
+                "Tj" | "TJ" | "'" | "\"" => {
+                    if matches!(operation.operator.as_str(), "'" | "\"") {
+                        next_line(&mut text_matrix, &mut line_matrix, 0.0, -state.leading);
+                    }
+
+                    let mut text = String::new();
+                    let mut advance = 0.0;
+                    for operand in &operation.operands {
+                        let parts = match operand {
+                            Object::Array(parts) => parts.as_slice(),
+                            other => std::slice::from_ref(other),
+                        };
+                        for part in parts {
+                            match part {
+                                Object::String(bytes, _) => {
+                                    let decoded = match state.font {
+                                        Some(font) => font.decode(bytes),
+                                        None => String::from_utf8_lossy(bytes).to_string(),
+                                    };
+                                    advance += match state.font {
+                                        Some(font) => font.advance(bytes, &decoded),
+                                        None => {
+                                            decoded.chars().count() as f32 * DEFAULT_GLYPH_WIDTH
+                                        }
+                                    };
+                                    text.push_str(&decoded);
+                                }
+                                // Kerning in a TJ array; a large gap is a word break
+                                Object::Integer(_) | Object::Real(_)
+                                    if operation.operator == "TJ" =>
+                                {
+                                    let adjustment = part.as_float().unwrap_or(0.0);
+                                    advance -= adjustment;
+                                    if adjustment < -200.0 && !text.ends_with(' ') {
+                                        text.push(' ');
+                                    }
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+
+                    let size = state.size;
+                    let rendering = multiply(&text_matrix, &state.ctm);
+                    let scale = (rendering[0].powi(2) + rendering[1].powi(2)).sqrt();
+                    let width = advance / 1000.0 * size * scale;
+                    if !text.trim().is_empty() {
+                        runs.push(TextRun {
+                            x: rendering[4],
+                            y: rendering[5],
+                            width,
+                            size: size * (rendering[2].powi(2) + rendering[3].powi(2)).sqrt(),
+                            text,
+                            invisible: state.render_mode == 3,
+                            white: state.white,
+                        });
+                    }
+                    text_matrix =
+                        multiply(&translation(advance / 1000.0 * size, 0.0), &text_matrix);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(runs)
+    }
+
+    /// Lines of the page, top to bottom, reading each column in turn where
+    /// the page is split into columns.
+    fn reading_order(runs: Vec<TextRun>, page_width: f32) -> Vec<String> {
+        let lines = group_lines(runs);
+        let Some(gutter) = find_gutter(&lines, page_width) else {
+            return lines.iter().map(|line| join_runs(line)).collect();
+        };
+
+        let mut output = vec![];
+        let mut left = vec![];
+        let mut right = vec![];
+        for line in &lines {
+            if line.iter().any(|run| run.x < gutter && run.end() > gutter) {
+                // A full-width line, e.g. a header above both columns
+                output.append(&mut left);
+                output.append(&mut right);
+                output.push(join_runs(line));
+                continue;
+            }
+            let (in_left, in_right): (Vec<TextRun>, Vec<TextRun>) =
+                line.iter().cloned().partition(|run| run.end() <= gutter);
+            if !in_left.is_empty() {
+                left.push(join_runs(&in_left));
+            }
+            if !in_right.is_empty() {
+                right.push(join_runs(&in_right));
+            }
+        }
+        output.append(&mut left);
+        output.append(&mut right);
+        output
+    }
+}
+
+/// Groups runs sharing a baseline, top of the page first.
+fn group_lines(mut runs: Vec<TextRun>) -> Vec<Vec<TextRun>> {
+    runs.sort_by(|a, b| b.y.total_cmp(&a.y).then(a.x.total_cmp(&b.x)));
+
+    let mut lines: Vec<Vec<TextRun>> = vec![];
+    for run in runs {
+        match lines.last_mut() {
+            Some(line) if (line[0].y - run.y).abs() <= line[0].size.max(run.size) * 0.4 => {
+                line.push(run)
+            }
+            _ => lines.push(vec![run]),
+        }
+    }
+    for line in &mut lines {
+        line.sort_by(|a, b| a.x.total_cmp(&b.x));
+    }
+    lines
+}
+
+fn join_runs(runs: &[TextRun]) -> String {
+    let mut text = String::new();
+    let mut previous_end: Option<f32> = None;
+    for run in runs {
+        if let Some(end) = previous_end {
+            if run.x - end > run.size * 0.15 && !text.ends_with(' ') && !run.text.starts_with(' ') {
+                text.push(' ');
+            }
+        }
+        text.push_str(&run.text);
+        previous_end = Some(run.end());
+    }
+    text.trim().to_string()
+}
+
+/// Finds an x position in the middle half of the page that no text crosses
+/// (allowing for the odd full-width line) with text on both sides of it on
+/// several lines.
+fn find_gutter(lines: &[Vec<TextRun>], page_width: f32) -> Option<f32> {
+    const MIN_SPLIT_LINES: usize = 3;
+
+    let run_count: usize = lines.iter().map(Vec::len).sum();
+    let max_crossings = (run_count / 10).max(1);
+
+    let mut best: Option<(usize, f32, f32)> = None; // (split lines, band start, band end)
+    let mut band: Option<(f32, usize)> = None;
+    let mut x = page_width * 0.25;
+    while x <= page_width * 0.75 {
+        let crossings = lines
+            .iter()
+            .flatten()
+            .filter(|run| run.x < x && run.end() > x)
+            .count();
+        let split = lines
+            .iter()
+            .filter(|line| {
+                line.iter().any(|run| run.end() <= x) && line.iter().any(|run| run.x >= x)
+            })
+            .count();
+
+        if crossings <= max_crossings && split >= MIN_SPLIT_LINES {
+            let (start, max_split) = band.unwrap_or((x, 0));
+            band = Some((start, max_split.max(split)));
+            let (start, max_split) = band.unwrap();
+            let wider = best.is_none_or(|(best_split, best_start, best_end)| {
+                max_split > best_split
+                    || (max_split == best_split && x - start > best_end - best_start)
+            });
+            if wider {
+                best = Some((max_split, start, x));
+            }
+        } else {
+            band = None;
+        }
+        x += 2.0;
+    }
+
+    best.map(|(_, start, end)| (start + end) / 2.0)
+}
@@ -0,0 +1,181 @@
+use crate::resume::importer::ResumeImporter;
+use crate::resume::optimizer::ResumeOptimizer;
+use crate::resume::pdf_import::PdfTextExtractor;
+use crate::resume::test_support::pdf_with_operations;
+use lopdf::content::Operation;
+use lopdf::Object;
+use pretty_assertions::assert_eq;
+
+fn text_at(x: i64, y: i64, size: i64, text: &str) -> Vec<Operation> {
+    vec![
+        Operation::new("BT", vec![]),
+        Operation::new("Tf", vec!["F1".into(), size.into()]),
+        Operation::new(
+            "Tm",
+            vec![1.into(), 0.into(), 0.into(), 1.into(), x.into(), y.into()],
+        ),
+        Operation::new("Tj", vec![Object::string_literal(text)]),
+        Operation::new("ET", vec![]),
+    ]
+}
+
+/// One text object per column, moving down with `T*`.
+fn column(x: i64, y: i64, lines: &[&str]) -> Vec<Operation> {
+    let mut operations = vec![
+        Operation::new("BT", vec![]),
+        Operation::new("Tf", vec!["F1".into(), 10.into()]),
+        Operation::new("TL", vec![15.into()]),
+        Operation::new("Td", vec![x.into(), y.into()]),
+    ];
+    for (index, line) in lines.iter().enumerate() {
+        if index > 0 {
+            operations.push(Operation::new("T*", vec![]));
+        }
+        operations.push(Operation::new("Tj", vec![Object::string_literal(*line)]));
+    }
+    operations.push(Operation::new("ET", vec![]));
+    operations
+}
+
+fn two_column_resume() -> Vec<u8> {
+    let mut operations = vec![];
+    operations.extend(text_at(50, 750, 18, "Jane Smith"));
+    operations.extend(text_at(
+        50,
+        730,
+        10,
+        "jane.smith@example.com | +1 555 123 4567",
+    ));
+    operations.extend(text_at(50, 700, 10, "SUMMARY"));
+    operations.extend(text_at(
+        50,
+        685,
+        10,
+        "Backend engineer who builds payment systems and mentors teams across time zones.",
+    ));
+    operations.extend(column(
+        50,
+        650,
+        &[
+            "EXPERIENCE",
+            "Senior Engineer at Acme Corp",
+            "March 2020 - Present",
+            "- Led the billing migration",
+            "Developer at Initech",
+            "2016 - 2019",
+            "- Kept the lights on",
+        ],
+    ));
+    operations.extend(column(
+        360,
+        650,
+        &[
+            "SKILLS",
+            "Kubernetes",
+            "",
+            "EDUCATION",
+            "BSc in Computer Science",
+            "MIT",
+            "2012 - 2016",
+        ],
+    ));
+    // A TJ array whose large kerning gap separates two words
+    operations.extend(vec![
+        Operation::new("BT", vec![]),
+        Operation::new("Tf", vec!["F1".into(), 10.into()]),
+        Operation::new("Td", vec![360.into(), 620.into()]),
+        Operation::new(
+            "TJ",
+            vec![Object::Array(vec![
+                Object::string_literal("Rust,"),
+                (-300).into(),
+                Object::string_literal("PostgreSQL"),
+            ])],
+        ),
+        Operation::new("ET", vec![]),
+    ]);
+    pdf_with_operations(operations)
+}
+
+#[test]
+fn test_extract_two_columns_in_reading_order() {
+    let text = PdfTextExtractor::new()
+        .extract_text(&two_column_resume())
+        .unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "Jane Smith",
+            "jane.smith@example.com | +1 555 123 4567",
+            "SUMMARY",
+            "Backend engineer who builds payment systems and mentors teams across time zones.",
+            "EXPERIENCE",
+            "Senior Engineer at Acme Corp",
+            "March 2020 - Present",
+            "- Led the billing migration",
+            "Developer at Initech",
+            "2016 - 2019",
+            "- Kept the lights on",
+            "SKILLS",
+            "Kubernetes",
+            "Rust, PostgreSQL",
+            "EDUCATION",
+            "BSc in Computer Science",
+            "MIT",
+            "2012 - 2016",
+        ]
+    );
+}
+
+#[test]
+fn test_single_column_lines_are_joined() {
+    let mut operations = text_at(50, 700, 10, "Senior Engineer");
+    operations.extend(text_at(135, 700, 10, "at Acme"));
+    operations.extend(text_at(50, 685, 10, "Second line"));
+
+    let text = PdfTextExtractor::new()
+        .extract_text(&pdf_with_operations(operations))
+        .unwrap();
+    assert_eq!(text, "Senior Engineer at Acme\nSecond line");
+}
+
+#[test]
+fn test_invalid_pdf() {
+    assert!(PdfTextExtractor::new()
+        .extract_text(b"%PDF-1.5 garbage")
+        .unwrap_err()
+        .starts_with("Failed to read PDF"));
+}
+
+#[test]
+fn test_import_pdf_and_optimize() {
+    let result = ResumeImporter::new()
+        .import_pdf(&two_column_resume())
+        .unwrap();
+    let resume = result.resume;
+
+    assert_eq!(resume.profile.name, "Jane Smith");
+    assert_eq!(resume.profile.email, "jane.smith@example.com");
+    let titles: Vec<&str> = resume
+        .experiences
+        .iter()
+        .map(|exp| exp.title.as_str())
+        .collect();
+    assert_eq!(titles, vec!["Senior Engineer", "Developer"]);
+    assert_eq!(resume.experiences[0].company, "Acme Corp");
+    assert!(resume.experiences[0].current);
+    let skills: Vec<&str> = resume
+        .skills
+        .technical
+        .iter()
+        .map(|skill| skill.name.as_str())
+        .collect();
+    assert_eq!(skills, vec!["Kubernetes", "Rust", "PostgreSQL"]);
+    assert_eq!(resume.education[0].institution, "MIT");
+
+    let optimization =
+        ResumeOptimizer::new().optimize(&resume, "Backend engineer: Rust, PostgreSQL");
+    assert!(optimization.matching_keywords.contains_key("rust"));
+    assert!(optimization.score > 0);
+}
@@ -2,6 +2,7 @@ use crate::resume::model::*;
 use chrono::NaiveDate;
 use pretty_assertions::assert_eq;
 use rstest::rstest;
+use std::collections::HashMap;
 
 #[test]
 fn test_create_empty_resume() {
@@ -50,6 +51,7 @@ fn test_valid_resume() {
         name: "Rust".to_string(),
         level: None,
         years: None,
+        tags: vec![],
     });
     assert!(resume.validate().is_ok());
 }
@@ -67,6 +69,7 @@ fn test_experience_date_validation() {
         name: "Rust".to_string(),
         level: None,
         years: None,
+        tags: vec![],
     });
 
     resume.experiences.push(Experience {
@@ -79,6 +82,9 @@ fn test_experience_date_validation() {
         description: "Work description".to_string(),
         achievements: vec![],
         technologies: vec![],
+        tags: vec![],
+        achievement_tags: HashMap::new(),
+        translations: HashMap::new(),
     });
 
     assert_eq!(
@@ -100,6 +106,7 @@ fn test_education_validation() {
         name: "Rust".to_string(),
         level: None,
         years: None,
+        tags: vec![],
     });
 
     resume.education.push(Education {
@@ -111,9 +118,11 @@ fn test_education_validation() {
         end_date: Some(NaiveDate::from_ymd_opt(2022, 1, 1).unwrap()),
         current: false,
         gpa: Some(4.5),
+        gpa_scale: None,
         courses: vec![],
         achievements: vec![],
         description: "".to_string(),
+        translations: HashMap::new(),
     });
 
     let result = resume.validate();
@@ -128,6 +137,9 @@ fn test_education_validation() {
     let result = resume.validate();
     assert!(result.is_err());
     assert!(result.unwrap_err().contains("Invalid GPA for University"));
+
+    resume.education[0].gpa_scale = Some(5.0);
+    assert!(resume.validate().is_ok());
 }
 
 #[test]
@@ -145,6 +157,7 @@ fn test_url_validation() {
         name: "Rust".to_string(),
         level: None,
         years: None,
+        tags: vec![],
     });
 
     assert_eq!(
@@ -158,6 +171,7 @@ fn test_url_validation() {
         name: "Rust".to_string(),
         level: None,
         years: None,
+        tags: vec![],
     });
 
     assert_eq!(
@@ -181,6 +195,7 @@ fn test_keyword_counting() {
         name: "AWS".to_string(),
         level: None,
         years: None,
+        tags: vec![],
     });
 
     let keywords = resume.count_keywords();
@@ -240,3 +255,311 @@ fn test_skills_validation() {
         Err("At least one technical skill is required".to_string())
     );
 }
+
+fn tagged_experience(title: &str, tags: &[&str], achievements: &[(&str, &[&str])]) -> Experience {
+    Experience {
+        company: "Company".to_string(),
+        title: title.to_string(),
+        location: None,
+        start_date: None,
+        end_date: None,
+        current: false,
+        description: "".to_string(),
+        achievements: achievements.iter().map(|(a, _)| a.to_string()).collect(),
+        technologies: vec![],
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        achievement_tags: achievements
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, tags))| !tags.is_empty())
+            .map(|(index, (_, tags))| (index, tags.iter().map(|t| t.to_string()).collect()))
+            .collect(),
+        translations: HashMap::new(),
+    }
+}
+
+fn create_master_resume() -> Resume {
+    let mut resume = Resume::new(Profile {
+        name: "John Gao".to_string(),
+        email: "john.gao@example.com".to_string(),
+        ..Profile::default()
+    });
+    resume.experiences = vec![
+        tagged_experience(
+            "Engineering Lead",
+            &[],
+            &[
+                ("Hired and mentored six engineers", &["management"]),
+                ("Designed the payments API", &["backend"]),
+                ("Cut cloud spend by 30%", &[]),
+                ("Built the metrics pipeline", &["data"]),
+            ],
+        ),
+        tagged_experience("Data Analyst", &["data"], &[("Built dashboards", &[])]),
+        tagged_experience("Intern", &["backend", "legacy"], &[]),
+    ];
+    for (name, tags) in [
+        ("Rust", vec!["backend"]),
+        ("SQL", vec![]),
+        ("Tableau", vec!["data"]),
+    ] {
+        resume.skills.technical.push(Skill {
+            name: name.to_string(),
+            level: None,
+            years: None,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        });
+    }
+    resume.metadata.variants.insert(
+        "backend".to_string(),
+        ResumeVariant {
+            include_tags: vec!["backend".to_string()],
+            exclude_tags: vec!["legacy".to_string()],
+            max_bullets: Some(2),
+            section_order: vec!["skills".to_string()],
+        },
+    );
+    resume
+}
+
+#[test]
+fn test_variant_selects_tagged_entries() {
+    let resume = create_master_resume();
+    let backend = resume.variant("backend").unwrap();
+
+    let titles: Vec<&str> = backend
+        .experiences
+        .iter()
+        .map(|e| e.title.as_str())
+        .collect();
+    assert_eq!(titles, vec!["Engineering Lead"]);
+    assert_eq!(
+        backend.experiences[0].achievements,
+        vec![
+            "Designed the payments API".to_string(),
+            "Cut cloud spend by 30%".to_string(),
+        ]
+    );
+
+    let skills: Vec<&str> = backend
+        .skills
+        .technical
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(skills, vec!["Rust", "SQL"]);
+
+    // The master resume itself is left untouched
+    assert_eq!(resume.experiences.len(), 3);
+    assert_eq!(resume.experiences[0].achievements.len(), 4);
+}
+
+#[test]
+fn test_variant_without_include_tags_keeps_everything_not_excluded() {
+    let resume = create_master_resume();
+    let variant = ResumeVariant {
+        exclude_tags: vec!["data".to_string()],
+        ..ResumeVariant::default()
+    };
+
+    let selected = resume.apply_variant(&variant);
+    assert_eq!(selected.experiences.len(), 2);
+    assert_eq!(selected.experiences[0].achievements.len(), 3);
+    assert_eq!(selected.skills.technical.len(), 2);
+}
+
+#[test]
+fn test_unknown_variant() {
+    let resume = create_master_resume();
+    assert_eq!(
+        resume.variant("sales").unwrap_err(),
+        "Variant 'sales' not found"
+    );
+}
+
+fn create_bilingual_resume() -> Resume {
+    let mut resume = create_master_resume();
+    resume.profile.title = "Engineering Lead".to_string();
+    resume.profile.summary = "Builds payment platforms.".to_string();
+    resume.profile.translations.insert(
+        "de".to_string(),
+        ProfileTranslation {
+            summary: Some("Baut Zahlungsplattformen.".to_string()),
+            ..ProfileTranslation::default()
+        },
+    );
+    resume.experiences[0].translations.insert(
+        "de".to_string(),
+        ExperienceTranslation {
+            title: Some("Leiter Softwareentwicklung".to_string()),
+            description: None,
+            achievements: Some(vec![
+                "Sechs Entwickler eingestellt und betreut".to_string(),
+                "Die Zahlungs-API entworfen".to_string(),
+                "Cloud-Kosten um 30% gesenkt".to_string(),
+                "Die Metrik-Pipeline gebaut".to_string(),
+            ]),
+        },
+    );
+    resume.experiences[1].translations.insert(
+        "fr-CA".to_string(),
+        ExperienceTranslation {
+            title: Some("Analyste de données".to_string()),
+            ..ExperienceTranslation::default()
+        },
+    );
+    resume
+}
+
+#[test]
+fn test_translate_falls_back_to_own_text() {
+    let german = create_bilingual_resume().translate("de");
+
+    assert_eq!(german.metadata.language, "de");
+    assert_eq!(german.profile.summary, "Baut Zahlungsplattformen.");
+    // No translation for the title or the other roles
+    assert_eq!(german.profile.title, "Engineering Lead");
+    assert_eq!(german.experiences[0].title, "Leiter Softwareentwicklung");
+    assert_eq!(
+        german.experiences[0].achievements[1],
+        "Die Zahlungs-API entworfen"
+    );
+    assert_eq!(german.experiences[1].title, "Data Analyst");
+}
+
+#[rstest]
+#[case("fr", "Analyste de données")]
+#[case("FR-ca", "Analyste de données")]
+#[case("en", "Data Analyst")]
+#[case("ja", "Data Analyst")]
+fn test_translate_matches_language_codes(#[case] language: &str, #[case] expected: &str) {
+    let translated = create_bilingual_resume().translate(language);
+    assert_eq!(translated.experiences[1].title, expected);
+}
+
+#[test]
+fn test_translate_prefers_exact_region() {
+    let mut resume = create_bilingual_resume();
+    for (code, title) in [
+        ("pt", "Analista"),
+        ("pt-BR", "Analista (BR)"),
+        ("pt-PT", "Analista (PT)"),
+    ] {
+        resume.experiences[1].translations.insert(
+            code.to_string(),
+            ExperienceTranslation {
+                title: Some(title.to_string()),
+                ..ExperienceTranslation::default()
+            },
+        );
+    }
+
+    assert_eq!(
+        resume.translate("pt-BR").experiences[1].title,
+        "Analista (BR)"
+    );
+    assert_eq!(resume.translate("pt-AO").experiences[1].title, "Analista");
+}
+
+#[test]
+fn test_content_languages() {
+    let resume = create_bilingual_resume();
+    assert_eq!(resume.content_languages(), vec!["en", "de", "fr-CA"]);
+    assert!(resume.has_language("fr"));
+    assert!(!resume.has_language("es"));
+}
+
+#[test]
+fn test_variant_of_translation_keeps_achievement_tags() {
+    let resume = create_bilingual_resume();
+    let variant = resume.metadata.variants["backend"].clone();
+
+    let selected = resume.translate("de").apply_variant(&variant);
+    assert_eq!(
+        selected.experiences[0].achievements,
+        vec![
+            "Die Zahlungs-API entworfen".to_string(),
+            "Cloud-Kosten um 30% gesenkt".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_achievement_tags_follow_their_achievement() {
+    let mut exp = tagged_experience(
+        "Engineer",
+        &[],
+        &[
+            ("Shipped the API", &["backend"]),
+            ("Shipped the API", &["management"]),
+            ("Ran the on-call rota", &[]),
+        ],
+    );
+
+    exp.retain_achievements(&[2, 1]);
+    assert_eq!(
+        exp.achievements,
+        vec!["Ran the on-call rota", "Shipped the API"]
+    );
+    assert!(exp.achievement_tags_of(0).is_empty());
+    assert_eq!(exp.achievement_tags_of(1), ["management".to_string()]);
+
+    assert_eq!(exp.remove_achievement(0), "Ran the on-call rota");
+    assert_eq!(exp.achievement_tags_of(0), ["management".to_string()]);
+}
+
+#[test]
+fn test_translations_deserialize() {
+    let json = r#"{
+        "profile": {
+            "name": "Ana", "title": "Engineer", "email": "ana@example.com", "phone": "",
+            "location": {"city": "", "country": ""}, "linkedin": "", "github": "",
+            "website": "", "summary": "Builds things.",
+            "translations": {"es": {"summary": "Construye cosas."}}
+        },
+        "experiences": [],
+        "education": [],
+        "skills": {}
+    }"#;
+
+    let resume: Resume = serde_json::from_str(json).unwrap();
+    assert_eq!(resume.metadata.language, "en");
+    assert_eq!(resume.translate("es").profile.summary, "Construye cosas.");
+}
+
+#[rstest]
+#[case("3.85", Some((3.85, None)))]
+#[case("8.5/10", Some((8.5, Some(10.0))))]
+#[case("3.6 / 4.0", Some((3.6, Some(4.0))))]
+#[case("first class", None)]
+fn test_parse_gpa(#[case] value: &str, #[case] expected: Option<(f32, Option<f32>)>) {
+    assert_eq!(parse_gpa(value), expected);
+}
+
+#[rstest]
+#[case(Some(3.85), None, Some("3.85"))]
+#[case(Some(3.6), Some(4.0), Some("3.60"))]
+#[case(Some(8.5), Some(10.0), Some("8.50/10"))]
+#[case(None, Some(10.0), None)]
+fn test_gpa_text(
+    #[case] gpa: Option<f32>,
+    #[case] scale: Option<f32>,
+    #[case] expected: Option<&str>,
+) {
+    let education = Education {
+        institution: "University".to_string(),
+        degree: "BS".to_string(),
+        field_of_study: "Computer Science".to_string(),
+        location: None,
+        start_date: None,
+        end_date: None,
+        current: false,
+        gpa,
+        gpa_scale: scale,
+        courses: vec![],
+        achievements: vec![],
+        description: "".to_string(),
+        translations: HashMap::new(),
+    };
+    assert_eq!(education.gpa_text().as_deref(), expected);
+}
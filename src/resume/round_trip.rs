@@ -0,0 +1,277 @@
+use crate::resume::docx::DocxReader;
+use crate::resume::formatter::{OutputFormat, ResumeFormatter};
+use crate::resume::model::Resume;
+use crate::resume::parser::{ParsedResume, PartialDate, ResumeParser, SectionKind};
+use chrono::{Datelike, NaiveDate};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldIssueKind {
+    /// The field could not be found in the parsed output at all.
+    Lost,
+    /// The field was found, but its value differs from the source.
+    Garbled,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldIssue {
+    /// Path of the field in the source resume, e.g. "experiences[0].company".
+    pub field: String,
+    pub expected: String,
+    pub found: Option<String>,
+    pub kind: FieldIssueKind,
+}
+
+#[derive(Debug, Clone)]
+pub struct FormatRoundTrip {
+    pub format: OutputFormat,
+    /// Set when the resume could not be rendered or read back in this format.
+    pub error: Option<String>,
+    pub issues: Vec<FieldIssue>,
+}
+
+impl FormatRoundTrip {
+    pub fn is_clean(&self) -> bool {
+        self.error.is_none() && self.issues.is_empty()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RoundTripReport {
+    pub results: Vec<FormatRoundTrip>,
+}
+
+impl RoundTripReport {
+    pub fn is_clean(&self) -> bool {
+        self.results.iter().all(FormatRoundTrip::is_clean)
+    }
+
+    pub fn result(&self, format: OutputFormat) -> Option<&FormatRoundTrip> {
+        self.results.iter().find(|result| result.format == format)
+    }
+}
+
+/// Renders a resume the way it will be submitted, reads it back with the
+/// generic `ResumeParser`, and compares what an ATS would recover with the
+/// source resume.
+pub struct RoundTripChecker {
+    parser: ResumeParser,
+}
+
+impl Default for RoundTripChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The formats `check` renders and reads back. PDF is out of scope until
+/// the formatter can render it: `check` leaves it out, and `check_format`
+/// reports it as an error rather than a clean result.
+const CHECKED_FORMATS: [OutputFormat; 3] = [
+    OutputFormat::PlainText,
+    OutputFormat::HTML,
+    OutputFormat::DOCX,
+];
+
+impl RoundTripChecker {
+    pub fn new() -> Self {
+        Self {
+            parser: ResumeParser::new(),
+        }
+    }
+
+    pub fn check(&self, resume: &Resume, formatter: &ResumeFormatter) -> RoundTripReport {
+        RoundTripReport {
+            results: CHECKED_FORMATS
+                .iter()
+                .map(|format| self.check_format(resume, formatter, *format))
+                .collect(),
+        }
+    }
+
+    pub fn check_format(
+        &self,
+        resume: &Resume,
+        formatter: &ResumeFormatter,
+        format: OutputFormat,
+    ) -> FormatRoundTrip {
+        let text = formatter
+            .format(resume, format)
+            .and_then(|rendered| match format {
+                OutputFormat::PlainText | OutputFormat::Markdown => Ok(rendered.content),
+                OutputFormat::HTML => Ok(ResumeParser::html_to_text(&rendered.content)),
+                OutputFormat::DOCX => match rendered.bytes {
+                    Some(bytes) => DocxReader::new().read_text(&bytes),
+                    None => Err("DOCX output has no document".to_string()),
+                },
+                _ => Err(format!(
+                    "Reading {} output back is not supported",
+                    format.extension()
+                )),
+            });
+
+        match text {
+            Ok(text) => FormatRoundTrip {
+                format,
+                error: None,
+                issues: self.compare(resume, &self.parser.parse_text(&text)),
+            },
+            Err(e) => FormatRoundTrip {
+                format,
+                error: Some(e),
+                issues: vec![],
+            },
+        }
+    }
+
+    /// Lists every checked field that the parsed resume lost or garbled.
+    pub fn compare(&self, resume: &Resume, parsed: &ParsedResume) -> Vec<FieldIssue> {
+        let mut issues = vec![];
+        let profile = &resume.profile;
+
+        compare_text(
+            &mut issues,
+            "profile.name",
+            &profile.name,
+            parsed.name.as_deref(),
+        );
+        compare_text(
+            &mut issues,
+            "profile.email",
+            &profile.email,
+            parsed.email.as_deref(),
+        );
+        compare_text(
+            &mut issues,
+            "profile.phone",
+            &profile.phone,
+            parsed.phone.as_deref(),
+        );
+
+        for (index, exp) in resume.experiences.iter().enumerate() {
+            let field = |name: &str| format!("experiences[{}].{}", index, name);
+            let found = parsed.experiences.get(index);
+
+            compare_text(
+                &mut issues,
+                &field("title"),
+                &exp.title,
+                found.map(|f| f.title.as_str()),
+            );
+            compare_text(
+                &mut issues,
+                &field("company"),
+                &exp.company,
+                found.map(|f| f.company.as_str()),
+            );
+            compare_date(
+                &mut issues,
+                &field("start_date"),
+                exp.start_date,
+                found.and_then(|f| f.start_date),
+            );
+            if exp.current {
+                if !found.is_some_and(|f| f.current) {
+                    issues.push(FieldIssue {
+                        field: field("current"),
+                        expected: "Present".to_string(),
+                        found: found.and_then(|f| f.end_date).map(describe_partial),
+                        kind: FieldIssueKind::Lost,
+                    });
+                }
+            } else {
+                compare_date(
+                    &mut issues,
+                    &field("end_date"),
+                    exp.end_date,
+                    found.and_then(|f| f.end_date),
+                );
+            }
+        }
+
+        // Languages listed with the skills often end up in their own section
+        let languages = parsed.section(SectionKind::Languages).unwrap_or_default();
+        let skills = &resume.skills;
+        for (category, list) in [
+            ("technical", &skills.technical),
+            ("soft", &skills.soft),
+            ("tools", &skills.tools),
+            ("languages", &skills.languages),
+            ("other", &skills.other),
+        ] {
+            for (index, skill) in list.iter().enumerate() {
+                let recovered = parsed.skills.iter().chain(languages).any(|found| {
+                    normalize(found.trim_start_matches(['-', '*', ' '])) == normalize(&skill.name)
+                });
+                if !recovered {
+                    issues.push(FieldIssue {
+                        field: format!("skills.{}[{}]", category, index),
+                        expected: skill.name.clone(),
+                        found: None,
+                        kind: FieldIssueKind::Lost,
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn compare_text(issues: &mut Vec<FieldIssue>, field: &str, expected: &str, found: Option<&str>) {
+    if expected.trim().is_empty() {
+        return;
+    }
+    let kind = match found {
+        None => FieldIssueKind::Lost,
+        Some(found) if found.trim().is_empty() => FieldIssueKind::Lost,
+        Some(found) if normalize(found) == normalize(expected) => return,
+        Some(_) => FieldIssueKind::Garbled,
+    };
+    issues.push(FieldIssue {
+        field: field.to_string(),
+        expected: expected.to_string(),
+        found: found
+            .filter(|found| !found.trim().is_empty())
+            .map(str::to_string),
+        kind,
+    });
+}
+
+fn compare_date(
+    issues: &mut Vec<FieldIssue>,
+    field: &str,
+    expected: Option<NaiveDate>,
+    found: Option<PartialDate>,
+) {
+    let Some(expected) = expected else {
+        return;
+    };
+    let kind = match found {
+        None => FieldIssueKind::Lost,
+        // A lost month is a garbled date, ATSs use it to compute experience
+        Some(found) if found.year == expected.year() && found.month == Some(expected.month()) => {
+            return
+        }
+        Some(_) => FieldIssueKind::Garbled,
+    };
+    issues.push(FieldIssue {
+        field: field.to_string(),
+        expected: expected.format("%Y-%m").to_string(),
+        found: found.map(describe_partial),
+        kind,
+    });
+}
+
+fn describe_partial(date: PartialDate) -> String {
+    match date.month {
+        Some(month) => format!("{}-{:02}", date.year, month),
+        None => date.year.to_string(),
+    }
+}
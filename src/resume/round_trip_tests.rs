@@ -0,0 +1,147 @@
+use crate::resume::formatter::{OutputFormat, ResumeFormatter};
+use crate::resume::model::{Experience, Profile, Resume};
+use crate::resume::parser::ResumeParser;
+use crate::resume::round_trip::*;
+use crate::resume::test_support::{date, experience, skills, strings};
+use pretty_assertions::assert_eq;
+
+fn create_test_resume(email: &str) -> Resume {
+    let mut resume = Resume::new(Profile {
+        name: "Jane Smith".to_string(),
+        email: email.to_string(),
+        phone: "(555) 123-4567".to_string(),
+        ..Profile::default()
+    });
+    resume.experiences = vec![
+        Experience {
+            start_date: date(2020, 3),
+            current: true,
+            description: "Owns the billing platform".to_string(),
+            achievements: strings(&["Led the billing migration"]),
+            ..experience("Senior Engineer", "Acme Corp")
+        },
+        Experience {
+            start_date: date(2017, 1),
+            end_date: date(2020, 2),
+            ..experience("Software Engineer", "Initech")
+        },
+    ];
+    resume.skills.technical = skills(&["Rust", "PostgreSQL"]);
+    resume.skills.tools = skills(&["Docker"]);
+    resume
+}
+
+fn fields(issues: &[FieldIssue]) -> Vec<&str> {
+    issues.iter().map(|issue| issue.field.as_str()).collect()
+}
+
+#[test]
+fn test_plain_text_round_trip_is_clean() {
+    let report = RoundTripChecker::new().check(
+        &create_test_resume("jane.smith@example.com"),
+        &ResumeFormatter::new(),
+    );
+    let plain = report.result(OutputFormat::PlainText).unwrap();
+    assert!(plain.is_clean(), "{:?}", plain.issues);
+}
+
+#[test]
+fn test_plain_text_garbles_underscores() {
+    let report = RoundTripChecker::new().check(
+        &create_test_resume("jane_smith@example.com"),
+        &ResumeFormatter::new(),
+    );
+    let plain = report.result(OutputFormat::PlainText).unwrap();
+    assert_eq!(
+        plain.issues,
+        vec![FieldIssue {
+            field: "profile.email".to_string(),
+            expected: "jane_smith@example.com".to_string(),
+            found: Some("janesmith@example.com".to_string()),
+            kind: FieldIssueKind::Garbled,
+        }]
+    );
+}
+
+#[test]
+fn test_html_round_trip() {
+    let report = RoundTripChecker::new().check(
+        &create_test_resume("jane.smith@example.com"),
+        &ResumeFormatter::new(),
+    );
+    let html = report.result(OutputFormat::HTML).unwrap();
+    assert_eq!(html.error, None);
+    assert!(html.is_clean(), "{:?}", html.issues);
+}
+
+#[test]
+fn test_default_check_is_clean() {
+    let report = RoundTripChecker::new().check(
+        &create_test_resume("jane.smith@example.com"),
+        &ResumeFormatter::new(),
+    );
+    assert!(report.is_clean(), "{:?}", report.results);
+}
+
+#[test]
+fn test_docx_round_trip_keeps_underscores() {
+    let report = RoundTripChecker::new().check(
+        &create_test_resume("jane_smith@example.com"),
+        &ResumeFormatter::new(),
+    );
+    let docx = report.result(OutputFormat::DOCX).unwrap();
+    assert_eq!(docx.error, None);
+    assert!(docx.is_clean(), "{:?}", docx.issues);
+}
+
+#[test]
+fn test_unsupported_format_is_reported() {
+    let pdf = RoundTripChecker::new().check_format(
+        &create_test_resume("jane.smith@example.com"),
+        &ResumeFormatter::new(),
+        OutputFormat::PDF,
+    );
+    assert!(pdf.error.is_some());
+    assert!(!pdf.is_clean());
+
+    let report = RoundTripChecker::new().check(
+        &create_test_resume("jane.smith@example.com"),
+        &ResumeFormatter::new(),
+    );
+    assert!(report.result(OutputFormat::PDF).is_none());
+}
+
+#[test]
+fn test_compare_reports_lost_and_garbled_fields() {
+    let resume = create_test_resume("jane.smith@example.com");
+    let text = "Jane Smith
+jane.smith@example.com
+
+Experience
+Senior Engineer at Acme
+2020 - Present
+
+Skills
+Rust, Docker
+";
+    let parsed = ResumeParser::new().parse_text(text);
+    let issues = RoundTripChecker::new().compare(&resume, &parsed);
+
+    assert_eq!(
+        fields(&issues),
+        vec![
+            "profile.phone",
+            "experiences[0].company",
+            "experiences[0].start_date",
+            "experiences[1].title",
+            "experiences[1].company",
+            "experiences[1].start_date",
+            "experiences[1].end_date",
+            "skills.technical[1]",
+        ]
+    );
+    assert_eq!(issues[0].kind, FieldIssueKind::Lost);
+    assert_eq!(issues[1].kind, FieldIssueKind::Garbled);
+    assert_eq!(issues[2].expected, "2020-03");
+    assert_eq!(issues[2].found.as_deref(), Some("2020"));
+}
@@ -0,0 +1,454 @@
+use crate::resume::model::Resume;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    #[default]
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSection {
+    #[default]
+    All,
+    Profile,
+    Summary,
+    Experience,
+    Education,
+    Skills,
+    Projects,
+    Certifications,
+}
+
+impl RuleSection {
+    /// The names a rule's `field` may take in this section. The summary is a
+    /// single value and has none; `All` accepts a field from any section.
+    pub fn fields(&self) -> &'static [&'static str] {
+        match self {
+            RuleSection::All => &[
+                "name",
+                "title",
+                "email",
+                "phone",
+                "location",
+                "linkedin",
+                "github",
+                "website",
+                "summary",
+                "company",
+                "description",
+                "achievements",
+                "technologies",
+                "institution",
+                "degree",
+                "field_of_study",
+                "courses",
+                "technical",
+                "soft",
+                "languages",
+                "tools",
+                "other",
+                "url",
+                "highlights",
+                "issuer",
+                "credential_id",
+            ],
+            RuleSection::Summary => &[],
+            RuleSection::Profile => &[
+                "name", "title", "email", "phone", "location", "linkedin", "github", "website",
+                "summary",
+            ],
+            RuleSection::Experience => &[
+                "company",
+                "title",
+                "description",
+                "achievements",
+                "technologies",
+            ],
+            RuleSection::Education => &[
+                "institution",
+                "degree",
+                "field_of_study",
+                "description",
+                "courses",
+                "achievements",
+            ],
+            RuleSection::Skills => &["technical", "soft", "languages", "tools", "other"],
+            RuleSection::Projects => &[
+                "name",
+                "description",
+                "url",
+                "github",
+                "technologies",
+                "highlights",
+            ],
+            RuleSection::Certifications => &["name", "issuer", "credential_id", "url"],
+        }
+    }
+}
+
+/// A check declared in a TOML or YAML file rather than in Rust code.
+///
+/// Exactly one predicate must be set: `forbid` / `require` take a regex that is
+/// matched against the target section, while `required`, `max_words` and
+/// `min_words` apply to the values of `field` (or the whole entry when no field
+/// is given).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct CustomRule {
+    pub id: String,
+    #[serde(default)]
+    pub section: RuleSection,
+    #[serde(default)]
+    pub field: Option<String>,
+    #[serde(default)]
+    pub forbid: Option<String>,
+    #[serde(default)]
+    pub require: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub max_words: Option<usize>,
+    #[serde(default)]
+    pub min_words: Option<usize>,
+    /// Only evaluate the rule when the job description matches this regex.
+    #[serde(default)]
+    pub when_job_matches: Option<String>,
+    pub message: String,
+    #[serde(default)]
+    pub severity: Severity,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleViolation {
+    pub rule_id: String,
+    pub section: RuleSection,
+    pub message: String,
+    pub severity: Severity,
+}
+
+#[derive(Debug, Clone)]
+enum Check {
+    Forbid(Regex),
+    Require(Regex),
+    Required,
+    MaxWords(usize),
+    MinWords(usize),
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    rule: CustomRule,
+    check: Check,
+    job_filter: Option<Regex>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleFile {
+    #[serde(default, alias = "rules")]
+    rule: Vec<CustomRule>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    rules: Vec<CompiledRule>,
+}
+
+impl RuleSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_toml_str(content: &str) -> Result<Self, String> {
+        let file: RuleFile =
+            toml::from_str(content).map_err(|e| format!("Failed to parse rules: {}", e))?;
+        Self::from_rules(file.rule)
+    }
+
+    pub fn from_yaml_str(content: &str) -> Result<Self, String> {
+        let file: RuleFile =
+            serde_yaml::from_str(content).map_err(|e| format!("Failed to parse rules: {}", e))?;
+        Self::from_rules(file.rule)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read rules file: {}", e))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml_str(&content),
+            Some("yaml") | Some("yml") => Self::from_yaml_str(&content),
+            _ => Err(format!("Unsupported rules file format: {}", path.display())),
+        }
+    }
+
+    pub fn from_rules(rules: Vec<CustomRule>) -> Result<Self, String> {
+        let mut set = Self::new();
+        for rule in rules {
+            set.add_rule(rule)?;
+        }
+        Ok(set)
+    }
+
+    pub fn add_rule(&mut self, rule: CustomRule) -> Result<(), String> {
+        let compiled = Self::compile(rule)?;
+        self.rules.push(compiled);
+        Ok(())
+    }
+
+    pub fn extend(&mut self, other: RuleSet) {
+        self.rules.extend(other.rules);
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    fn compile(rule: CustomRule) -> Result<CompiledRule, String> {
+        let compile_regex = |pattern: &str| {
+            Regex::new(pattern).map_err(|e| format!("Invalid pattern in rule '{}': {}", rule.id, e))
+        };
+
+        if let Some(field) = &rule.field {
+            if rule.section == RuleSection::Summary {
+                return Err(format!(
+                    "Rule '{}' sets field '{}', but the summary section has no fields",
+                    rule.id, field
+                ));
+            }
+            if !rule.section.fields().contains(&field.as_str()) {
+                return Err(format!(
+                    "Unknown field '{}' in rule '{}', expected one of: {}",
+                    field,
+                    rule.id,
+                    rule.section.fields().join(", ")
+                ));
+            }
+        }
+
+        let mut checks = Vec::new();
+        if let Some(pattern) = &rule.forbid {
+            checks.push(Check::Forbid(compile_regex(pattern)?));
+        }
+        if let Some(pattern) = &rule.require {
+            checks.push(Check::Require(compile_regex(pattern)?));
+        }
+        if rule.required {
+            checks.push(Check::Required);
+        }
+        if let Some(max) = rule.max_words {
+            checks.push(Check::MaxWords(max));
+        }
+        if let Some(min) = rule.min_words {
+            checks.push(Check::MinWords(min));
+        }
+
+        if checks.len() != 1 {
+            return Err(format!(
+                "Rule '{}' must define exactly one of forbid, require, required, max_words or min_words",
+                rule.id
+            ));
+        }
+
+        let job_filter = match &rule.when_job_matches {
+            Some(pattern) => Some(compile_regex(pattern)?),
+            None => None,
+        };
+
+        Ok(CompiledRule {
+            check: checks.remove(0),
+            job_filter,
+            rule,
+        })
+    }
+
+    pub fn evaluate(&self, resume: &Resume, job_description: &str) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for compiled in &self.rules {
+            if let Some(filter) = &compiled.job_filter {
+                if !filter.is_match(job_description) {
+                    continue;
+                }
+            }
+
+            let values = section_values(
+                resume,
+                compiled.rule.section,
+                compiled.rule.field.as_deref(),
+            );
+
+            let violated = match &compiled.check {
+                Check::Forbid(re) => values.iter().any(|value| re.is_match(value)),
+                Check::Require(re) => !values.iter().any(|value| re.is_match(value)),
+                Check::Required => !values.iter().any(|value| !value.trim().is_empty()),
+                Check::MaxWords(max) => values
+                    .iter()
+                    .any(|value| value.split_whitespace().count() > *max),
+                Check::MinWords(min) => values
+                    .iter()
+                    .filter(|value| !value.trim().is_empty())
+                    .any(|value| value.split_whitespace().count() < *min),
+            };
+
+            if violated {
+                violations.push(RuleViolation {
+                    rule_id: compiled.rule.id.clone(),
+                    section: compiled.rule.section,
+                    message: compiled.rule.message.clone(),
+                    severity: compiled.rule.severity,
+                });
+            }
+        }
+
+        violations
+    }
+}
+
+fn section_values(resume: &Resume, section: RuleSection, field: Option<&str>) -> Vec<String> {
+    match section {
+        RuleSection::All => [
+            RuleSection::Profile,
+            RuleSection::Experience,
+            RuleSection::Education,
+            RuleSection::Skills,
+            RuleSection::Projects,
+            RuleSection::Certifications,
+        ]
+        .iter()
+        .flat_map(|section| section_values(resume, *section, field))
+        .collect(),
+        RuleSection::Summary => vec![resume.profile.summary.clone()],
+        RuleSection::Profile => {
+            let profile = &resume.profile;
+            let location = format!("{} {}", profile.location.city, profile.location.country);
+            let fields = [
+                ("name", profile.name.clone()),
+                ("title", profile.title.clone()),
+                ("email", profile.email.clone()),
+                ("phone", profile.phone.clone()),
+                ("location", location.trim().to_string()),
+                ("linkedin", profile.linkedin.clone()),
+                ("github", profile.github.clone()),
+                ("website", profile.website.clone()),
+                ("summary", profile.summary.clone()),
+            ];
+            match field {
+                Some(field) => fields
+                    .into_iter()
+                    .filter(|(name, _)| *name == field)
+                    .map(|(_, value)| value)
+                    .collect(),
+                None => vec![fields
+                    .into_iter()
+                    .map(|(_, value)| value)
+                    .collect::<Vec<_>>()
+                    .join(" ")],
+            }
+        }
+        RuleSection::Experience => resume
+            .experiences
+            .iter()
+            .flat_map(|exp| {
+                let fields = vec![
+                    ("company", vec![exp.company.clone()]),
+                    ("title", vec![exp.title.clone()]),
+                    ("description", vec![exp.description.clone()]),
+                    ("achievements", exp.achievements.clone()),
+                    ("technologies", vec![exp.technologies.join(", ")]),
+                ];
+                select_fields(fields, field)
+            })
+            .collect(),
+        RuleSection::Education => resume
+            .education
+            .iter()
+            .flat_map(|edu| {
+                let fields = vec![
+                    ("institution", vec![edu.institution.clone()]),
+                    ("degree", vec![edu.degree.clone()]),
+                    ("field_of_study", vec![edu.field_of_study.clone()]),
+                    ("description", vec![edu.description.clone()]),
+                    ("courses", vec![edu.courses.join(", ")]),
+                    ("achievements", edu.achievements.clone()),
+                ];
+                select_fields(fields, field)
+            })
+            .collect(),
+        RuleSection::Skills => {
+            let skills = &resume.skills;
+            let names = |skills: &[crate::resume::model::Skill]| {
+                skills
+                    .iter()
+                    .map(|skill| skill.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let fields = vec![
+                ("technical", vec![names(&skills.technical)]),
+                ("soft", vec![names(&skills.soft)]),
+                ("languages", vec![names(&skills.languages)]),
+                ("tools", vec![names(&skills.tools)]),
+                ("other", vec![names(&skills.other)]),
+            ];
+            select_fields(fields, field)
+        }
+        RuleSection::Projects => resume
+            .projects
+            .iter()
+            .flat_map(|project| {
+                let fields = vec![
+                    ("name", vec![project.name.clone()]),
+                    ("description", vec![project.description.clone()]),
+                    ("url", vec![project.url.clone().unwrap_or_default()]),
+                    ("github", vec![project.github.clone().unwrap_or_default()]),
+                    ("technologies", vec![project.technologies.join(", ")]),
+                    ("highlights", project.highlights.clone()),
+                ];
+                select_fields(fields, field)
+            })
+            .collect(),
+        RuleSection::Certifications => resume
+            .certifications
+            .iter()
+            .flat_map(|cert| {
+                let fields = vec![
+                    ("name", vec![cert.name.clone()]),
+                    ("issuer", vec![cert.issuer.clone()]),
+                    (
+                        "credential_id",
+                        vec![cert.credential_id.clone().unwrap_or_default()],
+                    ),
+                    ("url", vec![cert.url.clone().unwrap_or_default()]),
+                ];
+                select_fields(fields, field)
+            })
+            .collect(),
+    }
+}
+
+fn select_fields(fields: Vec<(&str, Vec<String>)>, field: Option<&str>) -> Vec<String> {
+    match field {
+        Some(field) => fields
+            .into_iter()
+            .filter(|(name, _)| *name == field)
+            .flat_map(|(_, values)| values)
+            .collect(),
+        None => vec![fields
+            .into_iter()
+            .flat_map(|(_, values)| values)
+            .collect::<Vec<_>>()
+            .join(" ")],
+    }
+}
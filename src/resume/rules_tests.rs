@@ -0,0 +1,210 @@
+use crate::resume::model::{Experience, Profile, Resume};
+use crate::resume::optimizer::ResumeOptimizer;
+use crate::resume::rules::{CustomRule, RuleSection, RuleSet, Severity};
+use crate::resume::test_support::{experience, profile, skill, strings};
+use pretty_assertions::assert_eq;
+
+const TOML_RULES: &str = r#"
+[[rule]]
+id = "no-rockstar"
+forbid = "(?i)rockstar"
+message = "Avoid buzzwords like 'rockstar'"
+severity = "warning"
+
+[[rule]]
+id = "github-for-engineers"
+section = "profile"
+field = "github"
+required = true
+when_job_matches = "(?i)engineer"
+message = "Add a GitHub link for engineering roles"
+severity = "error"
+
+[[rule]]
+id = "short-summary"
+section = "summary"
+max_words = 60
+message = "Keep your summary under 60 words"
+severity = "info"
+"#;
+
+fn create_test_resume() -> Resume {
+    let mut resume = Resume::new(Profile {
+        title: "Software Engineer".to_string(),
+        summary: "Backend engineer building reliable services.".to_string(),
+        ..profile()
+    });
+
+    resume.skills.technical.push(skill("Rust"));
+
+    resume.experiences.push(Experience {
+        current: true,
+        description: "Built APIs as the team's resident rockstar.".to_string(),
+        achievements: strings(&["Reduced latency by 30%"]),
+        technologies: strings(&["Rust"]),
+        ..experience("Developer", "Tech Corp")
+    });
+
+    resume
+}
+
+#[test]
+fn test_load_rules_from_toml() {
+    let rules = RuleSet::from_toml_str(TOML_RULES).unwrap();
+    assert_eq!(rules.len(), 3);
+}
+
+#[test]
+fn test_load_rules_from_yaml() {
+    let yaml = r#"
+rules:
+  - id: no-ninja
+    section: experience
+    field: description
+    forbid: "(?i)ninja"
+    message: Avoid 'ninja'
+"#;
+    let rules = RuleSet::from_yaml_str(yaml).unwrap();
+    assert_eq!(rules.len(), 1);
+}
+
+#[test]
+fn test_rule_requires_exactly_one_predicate() {
+    let rule = CustomRule {
+        id: "broken".to_string(),
+        message: "No predicate".to_string(),
+        ..CustomRule::default()
+    };
+    assert!(RuleSet::from_rules(vec![rule]).is_err());
+
+    let rule = CustomRule {
+        id: "broken".to_string(),
+        forbid: Some("a".to_string()),
+        max_words: Some(3),
+        message: "Two predicates".to_string(),
+        ..CustomRule::default()
+    };
+    assert!(RuleSet::from_rules(vec![rule]).is_err());
+}
+
+#[test]
+fn test_invalid_regex_is_rejected() {
+    let rule = CustomRule {
+        id: "bad-regex".to_string(),
+        forbid: Some("(unclosed".to_string()),
+        message: "Bad".to_string(),
+        ..CustomRule::default()
+    };
+    let err = RuleSet::from_rules(vec![rule]).unwrap_err();
+    assert!(err.contains("bad-regex"));
+}
+
+#[test]
+fn test_unknown_field_is_rejected() {
+    let rule = CustomRule {
+        id: "typo".to_string(),
+        section: RuleSection::Experience,
+        field: Some("achievement".to_string()),
+        max_words: Some(30),
+        message: "Too long".to_string(),
+        ..CustomRule::default()
+    };
+    let err = RuleSet::from_rules(vec![rule]).unwrap_err();
+    assert!(err.starts_with("Unknown field 'achievement' in rule 'typo'"));
+    assert!(err.contains("achievements"));
+}
+
+#[test]
+fn test_misspelled_key_is_rejected() {
+    let toml = r#"
+[[rule]]
+id = "no-rockstar"
+forbid = "(?i)rockstar"
+message = "Avoid buzzwords like 'rockstar'"
+severty = "error"
+"#;
+    let err = RuleSet::from_toml_str(toml).unwrap_err();
+    assert!(err.contains("unknown field `severty`"), "{}", err);
+
+    let yaml = r#"
+rules:
+  - id: no-ninja
+    forbid: "(?i)ninja"
+    message: Avoid 'ninja'
+    severty: error
+"#;
+    let err = RuleSet::from_yaml_str(yaml).unwrap_err();
+    assert!(err.contains("unknown field `severty`"), "{}", err);
+}
+
+#[test]
+fn test_field_on_summary_is_rejected() {
+    let rule = CustomRule {
+        id: "summary-title".to_string(),
+        section: RuleSection::Summary,
+        field: Some("title".to_string()),
+        required: true,
+        message: "Missing".to_string(),
+        ..CustomRule::default()
+    };
+    let err = RuleSet::from_rules(vec![rule]).unwrap_err();
+    assert!(err.contains("summary section has no fields"));
+}
+
+#[test]
+fn test_field_from_any_section_allowed_for_all() {
+    let rule = CustomRule {
+        id: "no-tbd".to_string(),
+        field: Some("highlights".to_string()),
+        forbid: Some("TBD".to_string()),
+        message: "Placeholder".to_string(),
+        ..CustomRule::default()
+    };
+    assert!(RuleSet::from_rules(vec![rule]).is_ok());
+}
+
+#[test]
+fn test_rule_evaluation() {
+    let rules = RuleSet::from_toml_str(TOML_RULES).unwrap();
+    let resume = create_test_resume();
+
+    let violations = rules.evaluate(&resume, "Senior Software Engineer");
+    let ids: Vec<&str> = violations.iter().map(|v| v.rule_id.as_str()).collect();
+    assert_eq!(ids, vec!["no-rockstar", "github-for-engineers"]);
+    assert_eq!(violations[1].severity, Severity::Error);
+    assert_eq!(violations[1].section, RuleSection::Profile);
+
+    // The GitHub rule only applies to engineering roles
+    let violations = rules.evaluate(&resume, "Product Designer");
+    assert!(violations
+        .iter()
+        .all(|v| v.rule_id != "github-for-engineers"));
+}
+
+#[test]
+fn test_word_count_rules() {
+    let rules = RuleSet::from_toml_str(TOML_RULES).unwrap();
+    let mut resume = create_test_resume();
+    resume.profile.summary = "word ".repeat(61);
+
+    let violations = rules.evaluate(&resume, "");
+    assert!(violations.iter().any(|v| v.rule_id == "short-summary"));
+}
+
+#[test]
+fn test_optimizer_reports_rule_violations() {
+    let mut optimizer = ResumeOptimizer::new();
+    optimizer.add_rules(RuleSet::from_toml_str(TOML_RULES).unwrap());
+
+    let resume = create_test_resume();
+    let result = optimizer.optimize(&resume, "Looking for a Rust engineer");
+
+    assert!(result
+        .rule_violations
+        .iter()
+        .any(|v| v.rule_id == "no-rockstar"));
+    assert!(result
+        .rule_violations
+        .iter()
+        .any(|v| v.rule_id == "github-for-engineers"));
+}
@@ -0,0 +1,180 @@
+use crate::resume::model::Resume;
+use chrono::NaiveDate;
+use serde_json::{json, Map, Value};
+
+/// A schema.org `Person` describing the resume's owner: contact details,
+/// current employers (`worksFor`), schools (`alumniOf`), degrees and
+/// certifications (`hasCredential`) and the skills the formatted resume
+/// lists (`knowsAbout`).
+pub fn json_ld(resume: &Resume) -> Value {
+    let profile = &resume.profile;
+    let mut person = Map::new();
+    person.insert("@context".to_string(), json!("https://schema.org"));
+    person.insert("@type".to_string(), json!("Person"));
+    person.insert("name".to_string(), json!(profile.name));
+    insert_text(&mut person, "jobTitle", &profile.title);
+    insert_text(&mut person, "description", &profile.summary);
+    insert_text(&mut person, "email", &profile.email);
+    insert_text(&mut person, "telephone", &profile.phone);
+    insert_text(&mut person, "url", &profile.website);
+
+    let mut address = Map::new();
+    insert_text(&mut address, "addressLocality", &profile.location.city);
+    insert_text(&mut address, "addressCountry", &profile.location.country);
+    if !address.is_empty() {
+        address.insert("@type".to_string(), json!("PostalAddress"));
+        person.insert("address".to_string(), Value::Object(address));
+    }
+
+    let profiles: Vec<&String> = [&profile.linkedin, &profile.github]
+        .into_iter()
+        .filter(|url| !url.is_empty())
+        .collect();
+    insert_list(
+        &mut person,
+        "sameAs",
+        profiles.into_iter().map(|url| json!(url)),
+    );
+
+    insert_list(
+        &mut person,
+        "worksFor",
+        resume
+            .experiences
+            .iter()
+            .filter(|exp| exp.current)
+            .map(|exp| json!({"@type": "Organization", "name": exp.company})),
+    );
+    insert_list(
+        &mut person,
+        "alumniOf",
+        resume
+            .education
+            .iter()
+            .map(|edu| json!({"@type": "EducationalOrganization", "name": edu.institution})),
+    );
+
+    let degrees = resume.education.iter().map(|edu| {
+        let mut credential = credential("degree", &degree(&edu.degree, &edu.field_of_study));
+        credential.insert(
+            "recognizedBy".to_string(),
+            json!({"@type": "EducationalOrganization", "name": edu.institution}),
+        );
+        insert_date(
+            &mut credential,
+            "dateCreated",
+            edu.end_date.filter(|_| !edu.current),
+        );
+        Value::Object(credential)
+    });
+    let certifications = resume.certifications.iter().map(|cert| {
+        let mut credential = credential("certification", &cert.name);
+        insert_text(
+            &mut credential,
+            "identifier",
+            cert.credential_id.as_deref().unwrap_or(""),
+        );
+        insert_text(&mut credential, "url", cert.url.as_deref().unwrap_or(""));
+        if !cert.issuer.is_empty() {
+            credential.insert(
+                "recognizedBy".to_string(),
+                json!({"@type": "Organization", "name": cert.issuer}),
+            );
+        }
+        insert_date(&mut credential, "dateCreated", cert.date_obtained);
+        insert_date(&mut credential, "expires", cert.expiry_date);
+        Value::Object(credential)
+    });
+    insert_list(&mut person, "hasCredential", degrees.chain(certifications));
+
+    insert_list(
+        &mut person,
+        "knowsAbout",
+        skill_names(resume).into_iter().map(|name| json!(name)),
+    );
+
+    Value::Object(person)
+}
+
+/// `json_ld` as a `<script>` element, written at the end of the page's
+/// `<body>` after the content it describes.
+pub fn json_ld_script(resume: &Resume) -> String {
+    // "</" can't appear inside a script element; "<\/" is the same JSON
+    let json = serde_json::to_string_pretty(&json_ld(resume))
+        .unwrap_or_default()
+        .replace("</", "<\\/");
+    format!(
+        "<script type=\"application/ld+json\">\n{}\n</script>\n",
+        json
+    )
+}
+
+/// Escapes text for HTML element content and attribute values.
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Resume dates are month precision, so "2019-03" rather than a full date.
+pub(crate) fn iso_month(date: NaiveDate) -> String {
+    date.format("%Y-%m").to_string()
+}
+
+/// "Master of Science, Computer Science"; the comma reads the same in
+/// every locale.
+fn degree(degree: &str, field_of_study: &str) -> String {
+    if field_of_study.is_empty() {
+        degree.to_string()
+    } else {
+        format!("{}, {}", degree, field_of_study)
+    }
+}
+
+fn credential(category: &str, name: &str) -> Map<String, Value> {
+    let mut credential = Map::new();
+    credential.insert(
+        "@type".to_string(),
+        json!("EducationalOccupationalCredential"),
+    );
+    credential.insert("name".to_string(), json!(name));
+    credential.insert("credentialCategory".to_string(), json!(category));
+    credential
+}
+
+/// Skill names in the order the formatted resume lists them, without
+/// duplicates.
+fn skill_names(resume: &Resume) -> Vec<&str> {
+    let mut names: Vec<&str> = vec![];
+    for (_, skills) in resume.skills.categories() {
+        for skill in skills {
+            if !names
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&skill.name))
+            {
+                names.push(&skill.name);
+            }
+        }
+    }
+    names
+}
+
+fn insert_text(object: &mut Map<String, Value>, key: &str, value: &str) {
+    if !value.trim().is_empty() {
+        object.insert(key.to_string(), json!(value));
+    }
+}
+
+fn insert_date(object: &mut Map<String, Value>, key: &str, date: Option<NaiveDate>) {
+    if let Some(date) = date {
+        object.insert(key.to_string(), json!(iso_month(date)));
+    }
+}
+
+fn insert_list(object: &mut Map<String, Value>, key: &str, values: impl Iterator<Item = Value>) {
+    let values: Vec<Value> = values.collect();
+    if !values.is_empty() {
+        object.insert(key.to_string(), Value::Array(values));
+    }
+}
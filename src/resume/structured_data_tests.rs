@@ -0,0 +1,298 @@
+use crate::resume::formatter::{FormattingOptions, OutputFormat, ResumeFormatter};
+use crate::resume::model::{
+    Certification, Education, Experience, Location, Profile, ProfileTranslation, Resume,
+};
+use crate::resume::structured_data::{escape_html, json_ld, json_ld_script};
+use crate::resume::test_support::{date, education, experience, skills, strings};
+use pretty_assertions::assert_eq;
+use regex::Regex;
+use serde_json::{json, Value};
+
+fn job(company: &str, title: &str, current: bool) -> Experience {
+    Experience {
+        location: Some("Berlin".to_string()),
+        start_date: if current {
+            date(2021, 4)
+        } else {
+            date(2017, 9)
+        },
+        end_date: if current { None } else { date(2021, 3) },
+        current,
+        achievements: strings(&["Cut p99 latency by 40%"]),
+        ..experience(title, company)
+    }
+}
+
+fn create_test_resume() -> Resume {
+    let mut resume = Resume::new(Profile {
+        name: "Jane Smith".to_string(),
+        title: "Staff Engineer".to_string(),
+        email: "jane@example.com".to_string(),
+        phone: "+49 30 1234".to_string(),
+        location: Location {
+            city: "Berlin".to_string(),
+            country: "Germany".to_string(),
+        },
+        linkedin: "https://linkedin.com/in/jane".to_string(),
+        github: "https://github.com/jane".to_string(),
+        website: "https://jane.dev".to_string(),
+        summary: "Builds payment systems.".to_string(),
+        ..Profile::default()
+    });
+    resume
+        .experiences
+        .push(job("Acme GmbH", "Staff Engineer", true));
+    resume
+        .experiences
+        .push(job("Initech", "Backend Engineer", false));
+    resume.education.push(Education {
+        start_date: date(2015, 10),
+        end_date: date(2017, 8),
+        ..education("TU Berlin", "MSc", "Computer Science")
+    });
+    resume.certifications.push(Certification {
+        name: "CKA".to_string(),
+        issuer: "CNCF".to_string(),
+        date_obtained: date(2022, 5),
+        expiry_date: date(2025, 5),
+        credential_id: Some("LF-1".to_string()),
+        url: None,
+    });
+    resume.skills.technical = skills(&["Rust", "Kubernetes"]);
+    resume.skills.tools = skills(&["rust", "Terraform"]);
+    resume.skills.soft = skills(&["Mentoring"]);
+    resume
+}
+
+fn render_html(resume: &Resume, options: FormattingOptions) -> String {
+    ResumeFormatter::with_options(options)
+        .format(resume, OutputFormat::HTML)
+        .unwrap()
+        .content
+}
+
+/// Parses the JSON-LD block back out of an HTML page.
+fn parse_json_ld(html: &str) -> Value {
+    let script_re =
+        Regex::new(r#"(?s)<script type="application/ld\+json">\n(.*?)\n</script>"#).unwrap();
+    let scripts: Vec<&str> = script_re
+        .captures_iter(html)
+        .map(|caps| caps.get(1).unwrap().as_str())
+        .collect();
+    assert_eq!(scripts.len(), 1);
+    serde_json::from_str(scripts[0]).unwrap()
+}
+
+#[test]
+fn test_json_ld_round_trip() {
+    let resume = create_test_resume();
+    let html = render_html(&resume, FormattingOptions::default());
+
+    let person = parse_json_ld(&html);
+    assert_eq!(person, json_ld(&resume));
+    assert_eq!(person["@context"], "https://schema.org");
+    assert_eq!(person["@type"], "Person");
+    assert_eq!(person["name"], "Jane Smith");
+    assert_eq!(person["jobTitle"], "Staff Engineer");
+    assert_eq!(person["email"], "jane@example.com");
+    assert_eq!(
+        person["address"],
+        json!({"@type": "PostalAddress", "addressLocality": "Berlin", "addressCountry": "Germany"})
+    );
+    assert_eq!(
+        person["sameAs"],
+        json!(["https://linkedin.com/in/jane", "https://github.com/jane"])
+    );
+    // Only current roles are employers
+    assert_eq!(
+        person["worksFor"],
+        json!([{"@type": "Organization", "name": "Acme GmbH"}])
+    );
+    assert_eq!(
+        person["alumniOf"],
+        json!([{"@type": "EducationalOrganization", "name": "TU Berlin"}])
+    );
+    assert_eq!(
+        person["hasCredential"],
+        json!([
+            {
+                "@type": "EducationalOccupationalCredential",
+                "name": "MSc, Computer Science",
+                "credentialCategory": "degree",
+                "recognizedBy": {"@type": "EducationalOrganization", "name": "TU Berlin"},
+                "dateCreated": "2017-08"
+            },
+            {
+                "@type": "EducationalOccupationalCredential",
+                "name": "CKA",
+                "credentialCategory": "certification",
+                "identifier": "LF-1",
+                "recognizedBy": {"@type": "Organization", "name": "CNCF"},
+                "dateCreated": "2022-05",
+                "expires": "2025-05"
+            }
+        ])
+    );
+    assert_eq!(
+        person["knowsAbout"],
+        json!(["Rust", "Kubernetes", "Mentoring", "Terraform"])
+    );
+}
+
+#[test]
+fn test_json_ld_omits_missing_fields() {
+    let resume = Resume::new(Profile {
+        name: "Jane Smith".to_string(),
+        ..Profile::default()
+    });
+
+    assert_eq!(
+        json_ld(&resume),
+        json!({"@context": "https://schema.org", "@type": "Person", "name": "Jane Smith"})
+    );
+}
+
+#[test]
+fn test_json_ld_cannot_close_script() {
+    let mut resume = create_test_resume();
+    resume.profile.summary = "Wrote </script><script>alert(1)</script> filters".to_string();
+
+    let script = json_ld_script(&resume);
+    assert_eq!(script.matches("</script>").count(), 1);
+    assert!(script.ends_with("</script>\n"));
+    assert_eq!(
+        parse_json_ld(&script)["description"],
+        "Wrote </script><script>alert(1)</script> filters"
+    );
+}
+
+#[test]
+fn test_structured_data_follows_hidden_content() {
+    let mut options = FormattingOptions {
+        include_contact_info: false,
+        ..FormattingOptions::default()
+    };
+    options
+        .section_options
+        .insert("certifications".to_string(), false);
+
+    let html = render_html(&create_test_resume(), options);
+    let person = parse_json_ld(&html);
+    assert_eq!(person.get("email"), None);
+    assert_eq!(person.get("sameAs"), None);
+    assert_eq!(person["hasCredential"].as_array().unwrap().len(), 1);
+    assert!(!html.contains("jane@example.com"));
+    assert!(!html.contains("LF-1"));
+}
+
+#[test]
+fn test_structured_data_is_translated() {
+    let mut resume = create_test_resume();
+    resume.profile.translations.insert(
+        "de".to_string(),
+        ProfileTranslation {
+            title: Some("Staff-Ingenieurin".to_string()),
+            summary: None,
+        },
+    );
+
+    let html = render_html(&resume, FormattingOptions::for_locale("de").unwrap());
+    assert_eq!(parse_json_ld(&html)["jobTitle"], "Staff-Ingenieurin");
+}
+
+#[test]
+fn test_h_resume_marks_up_rendered_content() {
+    let html = render_html(&create_test_resume(), FormattingOptions::default());
+
+    assert_eq!(html.matches("<div class=\"h-resume\">").count(), 1);
+    assert!(!html.contains("<data"));
+    assert!(html.contains(
+        "<div class=\"p-contact h-card\">\n<h1 class=\"p-name\">Jane Smith</h1>\n\
+         <h2 class=\"p-job-title\">Staff Engineer</h2>\n"
+    ));
+    assert!(
+        html.contains("<a class=\"u-email\" href=\"mailto:jane@example.com\">jane@example.com</a>")
+    );
+    assert!(html.contains("<span class=\"p-tel\">+49 30 1234</span>"));
+    assert!(html.contains(
+        "<span class=\"p-locality\">Berlin</span>, <span class=\"p-country-name\">Germany</span>"
+    ));
+    assert!(html.contains("<div class=\"p-summary\">\n<p>Builds payment systems.</p>\n</div>"));
+
+    assert_eq!(
+        html.matches("<div class=\"p-experience h-event\">").count(),
+        2
+    );
+    assert!(html.contains(
+        "<h3><span class=\"p-name\">Staff Engineer</span> at <span class=\"p-location h-card\">\
+         <span class=\"p-name p-org\">Acme GmbH</span></span></h3>"
+    ));
+    assert!(html.contains(
+        "<p><em><time class=\"dt-start\" datetime=\"2021-04\">April 2021</time> - Present</em></p>"
+    ));
+    assert!(html.contains(
+        "<time class=\"dt-start\" datetime=\"2017-09\">September 2017</time> - \
+         <time class=\"dt-end\" datetime=\"2021-03\">March 2021</time>"
+    ));
+
+    assert!(html.contains(
+        "<div class=\"p-education h-event\">\n<h3 class=\"p-name\">MSc in Computer Science</h3>\n\
+         <p class=\"p-location h-card\"><strong class=\"p-name\">TU Berlin</strong></p>\n"
+    ));
+
+    assert_eq!(
+        Regex::new(r#"<span class="p-skill">([^<]*)</span>"#)
+            .unwrap()
+            .captures_iter(&html)
+            .map(|caps| caps[1].to_string())
+            .collect::<Vec<_>>(),
+        ["Rust", "Kubernetes", "Mentoring", "rust", "Terraform"]
+    );
+}
+
+#[test]
+fn test_h_resume_follows_hidden_content() {
+    let mut options = FormattingOptions {
+        include_contact_info: false,
+        ..FormattingOptions::default()
+    };
+    options.section_options.insert("skills".to_string(), false);
+
+    let html = render_html(&create_test_resume(), options);
+    assert!(html.contains("<h1 class=\"p-name\">Jane Smith</h1>"));
+    assert!(!html.contains("u-email"));
+    assert!(!html.contains("p-tel"));
+    assert!(!html.contains("p-skill"));
+}
+
+#[test]
+fn test_knows_about_lists_rendered_skills() {
+    let mut resume = create_test_resume();
+    resume.skills.other = skills(&["Public speaking"]);
+
+    let html = render_html(&resume, FormattingOptions::default());
+    assert!(html.contains("<span class=\"p-skill\">Public speaking</span>"));
+    assert_eq!(
+        parse_json_ld(&html)["knowsAbout"],
+        json!([
+            "Rust",
+            "Kubernetes",
+            "Mentoring",
+            "Terraform",
+            "Public speaking"
+        ])
+    );
+
+    let mut options = FormattingOptions::default();
+    options.section_options.insert("skills".to_string(), false);
+    let hidden = render_html(&resume, options);
+    assert_eq!(parse_json_ld(&hidden).get("knowsAbout"), None);
+}
+
+#[test]
+fn test_escape_html() {
+    assert_eq!(
+        escape_html(r#"R&D "core" <team>"#),
+        "R&amp;D &quot;core&quot; &lt;team&gt;"
+    );
+}
@@ -0,0 +1,350 @@
+use crate::resume::model::Resume;
+use crate::resume::pdf_import::PdfTextExtractor;
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Debug, Clone)]
+pub struct StuffingThresholds {
+    /// Share of the resume's words one keyword may take up, from 0.0 to 1.0.
+    pub max_keyword_density: f32,
+    /// Keywords used fewer times than this are never flagged, so a short
+    /// resume isn't penalized for mentioning its main skill three times.
+    pub min_repetitions: usize,
+    /// How many times the same skill list may appear before it is flagged.
+    pub max_identical_lists: usize,
+    /// Lists shorter than this are ignored when looking for repeated lists.
+    pub min_list_length: usize,
+    /// A sentence split into at least this many comma-separated items...
+    pub keyword_block_min_items: usize,
+    /// ...averaging no more than this many words per item is a keyword block.
+    pub keyword_block_max_words_per_item: f32,
+    /// Text smaller than this, in points, is treated as hidden.
+    pub min_font_size: f32,
+}
+
+impl Default for StuffingThresholds {
+    fn default() -> Self {
+        Self {
+            max_keyword_density: 0.04,
+            min_repetitions: 5,
+            max_identical_lists: 1,
+            min_list_length: 3,
+            keyword_block_min_items: 6,
+            keyword_block_max_words_per_item: 2.0,
+            min_font_size: 6.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StuffingKind {
+    HighDensity,
+    RepeatedSkillList,
+    KeywordBlock,
+    HiddenText,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StuffingIssue {
+    pub kind: StuffingKind,
+    /// The keyword, list or text that was flagged.
+    pub subject: String,
+    /// Why it was flagged, suitable for showing to the user.
+    pub explanation: String,
+}
+
+pub struct KeywordStuffingDetector {
+    thresholds: StuffingThresholds,
+    inline_style_re: Regex,
+    style_block_re: Regex,
+    style_rule_re: Regex,
+    white_re: Regex,
+    font_size_re: Regex,
+}
+
+impl Default for KeywordStuffingDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeywordStuffingDetector {
+    pub fn new() -> Self {
+        Self::with_thresholds(StuffingThresholds::default())
+    }
+
+    pub fn with_thresholds(thresholds: StuffingThresholds) -> Self {
+        Self {
+            thresholds,
+            inline_style_re: Regex::new(
+                r#"(?is)<([a-z0-9]+)\b[^>]*\bstyle\s*=\s*["']([^"']*)["'][^>]*>([^<]*)"#,
+            )
+            .unwrap(),
+            style_block_re: Regex::new(r"(?is)<style[^>]*>(.*?)</style>").unwrap(),
+            style_rule_re: Regex::new(r"(?s)([^{}]+)\{([^}]*)\}").unwrap(),
+            white_re: Regex::new(
+                r"^(?:white|#fff|#ffffff|rgba?\(\s*255\s*,\s*255\s*,\s*255\s*(?:,\s*[0-9.]+\s*)?\))$",
+            )
+            .unwrap(),
+            font_size_re: Regex::new(r"^([0-9]*\.?[0-9]+)\s*(px|pt|em|rem|%)?$").unwrap(),
+        }
+    }
+
+    pub fn thresholds(&self) -> &StuffingThresholds {
+        &self.thresholds
+    }
+
+    pub fn analyze_resume(&self, resume: &Resume) -> Vec<StuffingIssue> {
+        let mut issues = self.find_dense_keywords(resume);
+        issues.extend(self.find_repeated_lists(resume));
+        issues.extend(self.find_keyword_blocks(resume));
+        issues
+    }
+
+    fn find_dense_keywords(&self, resume: &Resume) -> Vec<StuffingIssue> {
+        let counts = resume.count_keywords();
+        let total_words: usize = counts.values().sum();
+        if total_words == 0 {
+            return vec![];
+        }
+
+        let mut dense: Vec<(&String, usize)> = counts
+            .iter()
+            .map(|(keyword, count)| (keyword, *count))
+            .filter(|(_, count)| {
+                *count >= self.thresholds.min_repetitions
+                    && *count as f32 / total_words as f32 > self.thresholds.max_keyword_density
+            })
+            .collect();
+        dense.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+        dense
+            .into_iter()
+            .map(|(keyword, count)| StuffingIssue {
+                kind: StuffingKind::HighDensity,
+                subject: keyword.clone(),
+                explanation: format!(
+                    "\"{}\" appears {} times in {} words ({:.1}%), above the {:.1}% limit; ATS systems may treat this as keyword stuffing",
+                    keyword,
+                    count,
+                    total_words,
+                    count as f32 / total_words as f32 * 100.0,
+                    self.thresholds.max_keyword_density * 100.0
+                ),
+            })
+            .collect()
+    }
+
+    fn find_repeated_lists(&self, resume: &Resume) -> Vec<StuffingIssue> {
+        let mut lists: Vec<(String, Vec<&String>)> = vec![];
+        for exp in &resume.experiences {
+            lists.push((
+                format!("{} at {}", exp.title, exp.company),
+                exp.technologies.iter().collect(),
+            ));
+        }
+        for project in &resume.projects {
+            lists.push((project.name.clone(), project.technologies.iter().collect()));
+        }
+        let skills = &resume.skills;
+        for (category, list) in [
+            ("technical skills", &skills.technical),
+            ("soft skills", &skills.soft),
+            ("tools", &skills.tools),
+            ("other skills", &skills.other),
+        ] {
+            lists.push((
+                category.to_string(),
+                list.iter().map(|skill| &skill.name).collect(),
+            ));
+        }
+
+        let mut groups: Vec<(BTreeSet<String>, Vec<String>)> = vec![];
+        for (location, list) in lists {
+            let set: BTreeSet<String> = list.iter().map(|item| item.to_lowercase()).collect();
+            if set.len() < self.thresholds.min_list_length {
+                continue;
+            }
+            match groups.iter_mut().find(|(existing, _)| *existing == set) {
+                Some((_, locations)) => locations.push(location),
+                None => groups.push((set, vec![location])),
+            }
+        }
+
+        groups
+            .into_iter()
+            .filter(|(_, locations)| locations.len() > self.thresholds.max_identical_lists)
+            .map(|(set, locations)| {
+                let subject = set.into_iter().collect::<Vec<_>>().join(", ");
+                StuffingIssue {
+                    kind: StuffingKind::RepeatedSkillList,
+                    explanation: format!(
+                        "The same list ({}) appears {} times, in {}; repeating identical lists pads keyword counts without adding information",
+                        subject,
+                        locations.len(),
+                        locations.join(", ")
+                    ),
+                    subject,
+                }
+            })
+            .collect()
+    }
+
+    fn find_keyword_blocks(&self, resume: &Resume) -> Vec<StuffingIssue> {
+        let mut texts: Vec<(String, &String)> =
+            vec![("Summary".to_string(), &resume.profile.summary)];
+        for exp in &resume.experiences {
+            let role = format!("{} at {}", exp.title, exp.company);
+            texts.push((role.clone(), &exp.description));
+            texts.extend(exp.achievements.iter().map(|a| (role.clone(), a)));
+        }
+        for project in &resume.projects {
+            texts.push((project.name.clone(), &project.description));
+            texts.extend(project.highlights.iter().map(|h| (project.name.clone(), h)));
+        }
+
+        texts
+            .into_iter()
+            .filter(|(_, text)| self.is_keyword_block(text))
+            .map(|(location, text)| StuffingIssue {
+                kind: StuffingKind::KeywordBlock,
+                subject: text.clone(),
+                explanation: format!(
+                    "{} contains a bare list of {} keywords without context; show how you used them instead, or move them to the skills section",
+                    location,
+                    Self::list_items(text).len()
+                ),
+            })
+            .collect()
+    }
+
+    fn list_items(text: &str) -> Vec<&str> {
+        text.split([',', ';', '|', '•', '/'])
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .collect()
+    }
+
+    fn is_keyword_block(&self, text: &str) -> bool {
+        let items = Self::list_items(text);
+        if items.len() < self.thresholds.keyword_block_min_items {
+            return false;
+        }
+        let words: usize = items
+            .iter()
+            .map(|item| item.split_whitespace().count())
+            .sum();
+        words as f32 / items.len() as f32 <= self.thresholds.keyword_block_max_words_per_item
+    }
+
+    /// Looks for text styled to be invisible to a human reader: white text,
+    /// tiny fonts, `display: none`, `visibility: hidden` and zero opacity.
+    pub fn analyze_html(&self, html: &str) -> Vec<StuffingIssue> {
+        let mut issues = vec![];
+
+        for caps in self.inline_style_re.captures_iter(html) {
+            let text = caps[3].trim();
+            if let Some(reason) = self.hidden_style(&caps[2]) {
+                issues.push(Self::hidden_text_issue(
+                    if text.is_empty() { &caps[1] } else { text },
+                    &reason,
+                ));
+            }
+        }
+
+        for block in self.style_block_re.captures_iter(html) {
+            for rule in self.style_rule_re.captures_iter(&block[1]) {
+                if let Some(reason) = self.hidden_style(&rule[2]) {
+                    issues.push(Self::hidden_text_issue(
+                        &format!("elements matching \"{}\"", rule[1].trim()),
+                        &reason,
+                    ));
+                }
+            }
+        }
+
+        issues
+    }
+
+    fn hidden_style(&self, declarations: &str) -> Option<String> {
+        let declarations = declarations.to_lowercase();
+        let properties: HashMap<&str, &str> = declarations
+            .split(';')
+            .filter_map(|declaration| declaration.split_once(':'))
+            .map(|(property, value)| (property.trim(), value.trim()))
+            .collect();
+
+        if let Some(color) = properties.get("color") {
+            if self.white_re.is_match(color) {
+                return Some("white text".to_string());
+            }
+        }
+        if properties.get("display") == Some(&"none") {
+            return Some("display: none".to_string());
+        }
+        if properties.get("visibility") == Some(&"hidden") {
+            return Some("visibility: hidden".to_string());
+        }
+        if let Some(opacity) = properties.get("opacity") {
+            if opacity.parse::<f32>().is_ok_and(|opacity| opacity < 0.1) {
+                return Some(format!("opacity {}", opacity));
+            }
+        }
+        if let Some(size) = properties.get("font-size") {
+            if let Some(caps) = self.font_size_re.captures(size) {
+                let value: f32 = caps[1].parse().unwrap_or(f32::MAX);
+                // Relative sizes are taken against the browser default of
+                // 12pt (16px); a bare number is only valid CSS when it's 0
+                let points = match caps.get(2).map(|unit| unit.as_str()) {
+                    Some("px") => value * 0.75,
+                    Some("pt") => value,
+                    Some("em") | Some("rem") => value * 12.0,
+                    Some(_) => value / 100.0 * 12.0,
+                    None if value == 0.0 => 0.0,
+                    None => f32::MAX,
+                };
+                if points < self.thresholds.min_font_size {
+                    return Some(format!("font size {}", size));
+                }
+            }
+        }
+        None
+    }
+
+    /// Looks for text drawn in invisible render mode, in white, or in a tiny
+    /// font in each page's content stream.
+    pub fn analyze_pdf(&self, bytes: &[u8]) -> Result<Vec<StuffingIssue>, String> {
+        let mut issues = vec![];
+
+        for (page_number, runs) in PdfTextExtractor::new().text_runs(bytes)? {
+            for run in runs {
+                let reason = if run.invisible {
+                    "invisible text render mode".to_string()
+                } else if run.white {
+                    "white text".to_string()
+                } else if run.size < self.thresholds.min_font_size {
+                    format!("font size {}pt", (run.size * 10.0).round() / 10.0)
+                } else {
+                    continue;
+                };
+                issues.push(Self::hidden_text_issue(
+                    run.text.trim(),
+                    &format!("{} on page {}", reason, page_number),
+                ));
+            }
+        }
+
+        Ok(issues)
+    }
+
+    fn hidden_text_issue(subject: &str, reason: &str) -> StuffingIssue {
+        StuffingIssue {
+            kind: StuffingKind::HiddenText,
+            subject: subject.to_string(),
+            explanation: format!(
+                "\"{}\" is hidden from human readers ({}); ATS vendors treat hidden keywords as manipulation and may reject the resume",
+                subject, reason
+            ),
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use crate::resume::model::{Experience, Profile, Resume};
+use crate::resume::optimizer::ResumeOptimizer;
+use crate::resume::stuffing::*;
+use crate::resume::test_support::{experience, pdf_with_operations, profile, skills, strings};
+use lopdf::content::Operation;
+use lopdf::Object;
+use pretty_assertions::assert_eq;
+
+fn create_test_resume(summary: &str) -> Resume {
+    let mut resume = Resume::new(Profile {
+        summary: summary.to_string(),
+        ..profile()
+    });
+    resume.experiences.push(Experience {
+        achievements: strings(&["Shipped a billing service used by thousands of customers"]),
+        technologies: strings(&["Rust", "Postgres"]),
+        ..experience("Backend Engineer", "Company")
+    });
+    resume
+}
+
+fn kinds(issues: &[StuffingIssue]) -> Vec<StuffingKind> {
+    issues.iter().map(|issue| issue.kind).collect()
+}
+
+#[test]
+fn test_density_depends_on_resume_length() {
+    let detector = KeywordStuffingDetector::new();
+
+    let short = create_test_resume(
+        "Python developer writing Python, Python tools and Python apps in Python.",
+    );
+    let issues = detector.analyze_resume(&short);
+    assert_eq!(kinds(&issues), vec![StuffingKind::HighDensity]);
+    assert_eq!(issues[0].subject, "python");
+    assert!(issues[0].explanation.contains("appears 5 times"));
+
+    let mut long = short.clone();
+    long.experiences[0].description = "Designed and operated data pipelines, mentored engineers, \
+        ran incident reviews, negotiated vendor contracts, planned quarterly roadmaps, \
+        reduced infrastructure costs, improved onboarding documentation, partnered with \
+        product managers, hired a platform team, wrote design documents, led migrations \
+        between cloud providers, introduced tracing across every service we owned \
+        while keeping deployment frequency high and customer facing latency low. "
+        .repeat(3);
+    assert_eq!(detector.analyze_resume(&long), vec![]);
+}
+
+#[test]
+fn test_configurable_density_threshold() {
+    let resume = create_test_resume(
+        "Python developer writing Python, Python tools and Python apps in Python.",
+    );
+    let detector = KeywordStuffingDetector::with_thresholds(StuffingThresholds {
+        max_keyword_density: 0.5,
+        ..StuffingThresholds::default()
+    });
+    assert!(detector.analyze_resume(&resume).is_empty());
+}
+
+#[test]
+fn test_repeated_skill_lists() {
+    let mut resume = create_test_resume("");
+    let stack = ["Rust", "Kafka", "Postgres", "Kubernetes"];
+    resume.experiences[0].technologies = strings(&stack);
+    resume.experiences.push(Experience {
+        technologies: strings(&["kafka", "Kubernetes", "Postgres", "Rust"]),
+        ..experience("Platform Engineer", "Company")
+    });
+    resume.skills.technical = skills(&stack);
+
+    let issues = KeywordStuffingDetector::new().analyze_resume(&resume);
+    assert_eq!(kinds(&issues), vec![StuffingKind::RepeatedSkillList]);
+    assert_eq!(issues[0].subject, "kafka, kubernetes, postgres, rust");
+    assert!(issues[0].explanation.contains("appears 3 times"));
+}
+
+#[test]
+fn test_keyword_block_without_context() {
+    let resume = create_test_resume(
+        "Java, Spring, AWS, Docker, Kubernetes, Terraform, React, Node.js, GraphQL",
+    );
+
+    let issues = KeywordStuffingDetector::new().analyze_resume(&resume);
+    assert_eq!(kinds(&issues), vec![StuffingKind::KeywordBlock]);
+    assert!(issues[0]
+        .explanation
+        .starts_with("Summary contains a bare list of 9 keywords"));
+}
+
+#[test]
+fn test_list_with_context_is_not_a_block() {
+    let resume = create_test_resume(
+        "Built APIs in Java, deployed them on AWS, automated infrastructure with Terraform, \
+         wrote dashboards in React, tuned Postgres queries, and mentored two interns",
+    );
+    assert!(KeywordStuffingDetector::new()
+        .analyze_resume(&resume)
+        .is_empty());
+}
+
+#[test]
+fn test_hidden_text_in_html() {
+    let html = r#"<html><head><style>
+        h1 { color: #333; }
+        .ats { font-size: 1px; }
+    </style></head><body>
+        <p style="color: #FFFFFF">Kubernetes Terraform Kafka</p>
+        <span style="display:none">Python</span>
+        <p style="color: #333; font-size: 12px">Visible</p>
+    </body></html>"#;
+
+    let issues = KeywordStuffingDetector::new().analyze_html(html);
+    let subjects: Vec<&str> = issues.iter().map(|issue| issue.subject.as_str()).collect();
+    assert_eq!(
+        subjects,
+        vec![
+            "Kubernetes Terraform Kafka",
+            "Python",
+            "elements matching \".ats\"",
+        ]
+    );
+    assert!(issues
+        .iter()
+        .all(|issue| issue.kind == StuffingKind::HiddenText));
+    assert!(issues[0].explanation.contains("white text"));
+}
+
+#[test]
+fn test_hidden_font_sizes_in_any_unit() {
+    let detector = KeywordStuffingDetector::new();
+    for size in ["0", "0.05em", "0.1rem", "5%", "1px", "2pt"] {
+        let html = format!(r#"<span style="font-size: {}">Kafka</span>"#, size);
+        assert_eq!(detector.analyze_html(&html).len(), 1, "font-size: {}", size);
+    }
+    for size in ["1em", "0.9rem", "100%", "12px", "inherit", "12"] {
+        let html = format!(r#"<span style="font-size: {}">Kafka</span>"#, size);
+        assert!(
+            detector.analyze_html(&html).is_empty(),
+            "font-size: {}",
+            size
+        );
+    }
+}
+
+#[test]
+fn test_generated_html_is_clean() {
+    use crate::resume::formatter::{OutputFormat, ResumeFormatter};
+
+    let html = ResumeFormatter::new()
+        .format(&create_test_resume("Backend engineer"), OutputFormat::HTML)
+        .unwrap()
+        .content;
+    assert!(KeywordStuffingDetector::new()
+        .analyze_html(&html)
+        .is_empty());
+}
+
+#[test]
+fn test_hidden_text_in_pdf() {
+    let text = |s: &str| Operation::new("Tj", vec![Object::string_literal(s)]);
+    let bytes = pdf_with_operations(vec![
+        Operation::new("BT", vec![]),
+        Operation::new("Tf", vec!["F1".into(), 11.into()]),
+        text("Jane Smith"),
+        Operation::new("rg", vec![1.into(), 1.into(), 1.into()]),
+        text("Kubernetes Kafka"),
+        Operation::new("rg", vec![0.into(), 0.into(), 0.into()]),
+        Operation::new("Tr", vec![3.into()]),
+        text("Terraform"),
+        Operation::new("Tr", vec![0.into()]),
+        Operation::new("Tf", vec!["F1".into(), 2.into()]),
+        text("GraphQL"),
+        Operation::new("ET", vec![]),
+    ]);
+
+    let issues = KeywordStuffingDetector::new().analyze_pdf(&bytes).unwrap();
+    let subjects: Vec<&str> = issues.iter().map(|issue| issue.subject.as_str()).collect();
+    assert_eq!(subjects, vec!["Kubernetes Kafka", "Terraform", "GraphQL"]);
+    assert!(issues[1]
+        .explanation
+        .contains("invisible text render mode on page 1"));
+}
+
+#[test]
+fn test_pdf_font_size_follows_text_matrix() {
+    let bytes = pdf_with_operations(vec![
+        Operation::new("BT", vec![]),
+        Operation::new("Tf", vec!["F1".into(), 1.into()]),
+        Operation::new(
+            "Tm",
+            vec![
+                12.into(),
+                0.into(),
+                0.into(),
+                12.into(),
+                72.into(),
+                700.into(),
+            ],
+        ),
+        Operation::new("Tj", vec![Object::string_literal("Senior Rust Engineer")]),
+        Operation::new("ET", vec![]),
+    ]);
+
+    assert!(KeywordStuffingDetector::new()
+        .analyze_pdf(&bytes)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_pdf_fill_color_restored_by_graphics_state() {
+    let bytes = pdf_with_operations(vec![
+        Operation::new("q", vec![]),
+        Operation::new("g", vec![1.into()]),
+        Operation::new("re", vec![0.into(), 0.into(), 612.into(), 792.into()]),
+        Operation::new("f", vec![]),
+        Operation::new("Q", vec![]),
+        Operation::new("BT", vec![]),
+        Operation::new("Tf", vec!["F1".into(), 11.into()]),
+        Operation::new("Tj", vec![Object::string_literal("Jane Smith")]),
+        Operation::new("ET", vec![]),
+    ]);
+
+    assert!(KeywordStuffingDetector::new()
+        .analyze_pdf(&bytes)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_tiny_text_scaled_down_by_ctm() {
+    let bytes = pdf_with_operations(vec![
+        Operation::new(
+            "cm",
+            vec![
+                0.1.into(),
+                0.into(),
+                0.into(),
+                0.1.into(),
+                0.into(),
+                0.into(),
+            ],
+        ),
+        Operation::new("BT", vec![]),
+        Operation::new("Tf", vec!["F1".into(), 11.into()]),
+        Operation::new("Tj", vec![Object::string_literal("Kubernetes")]),
+        Operation::new("ET", vec![]),
+    ]);
+
+    let issues = KeywordStuffingDetector::new().analyze_pdf(&bytes).unwrap();
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].explanation.contains("font size 1.1pt on page 1"));
+}
+
+#[test]
+fn test_invalid_pdf() {
+    assert!(KeywordStuffingDetector::new()
+        .analyze_pdf(b"not a pdf")
+        .is_err());
+}
+
+#[test]
+fn test_optimizer_explains_stuffing() {
+    let optimizer = ResumeOptimizer::new();
+    let resume = create_test_resume(
+        "Python developer writing Python, Python tools and Python apps in Python.",
+    );
+
+    let result = optimizer.optimize(&resume, "Python developer");
+    assert_eq!(result.overused_keywords, vec!["python".to_string()]);
+    assert_eq!(result.stuffing_issues.len(), 1);
+    assert!(result
+        .suggestions
+        .iter()
+        .any(|s| s.contains("may treat this as keyword stuffing")));
+}
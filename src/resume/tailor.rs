@@ -0,0 +1,355 @@
+use crate::resume::model::{Experience, Project, Resume};
+use crate::resume::optimizer::ResumeOptimizer;
+use chrono::{NaiveDate, Utc};
+use regex::Regex;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+const STOP_WORDS: [&str; 24] = [
+    "and", "the", "for", "with", "you", "our", "are", "will", "your", "this", "that", "from",
+    "have", "has", "who", "can", "all", "into", "about", "their", "they", "was", "were", "not",
+];
+
+#[derive(Debug, Clone)]
+pub struct TailoringOptions {
+    /// Roles that ended more than this many years ago may be collapsed or hidden.
+    pub older_than_years: u32,
+    /// Number of projects kept, most relevant first.
+    pub max_projects: usize,
+    /// Achievements left on an older role after it is collapsed.
+    pub collapsed_achievements: usize,
+}
+
+impl Default for TailoringOptions {
+    fn default() -> Self {
+        Self {
+            older_than_years: 10,
+            max_projects: 3,
+            collapsed_achievements: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Reordered,
+    Collapsed,
+    Hidden,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TailoringChange {
+    pub kind: ChangeKind,
+    pub section: String,
+    pub description: String,
+}
+
+impl fmt::Display for TailoringChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{:?}] {}: {}",
+            self.kind, self.section, self.description
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TailoringResult {
+    /// A copy of the input resume; the original is never modified.
+    pub resume: Resume,
+    pub changes: Vec<TailoringChange>,
+}
+
+impl TailoringResult {
+    /// Human readable list of what was moved, collapsed or hidden.
+    pub fn summary(&self) -> String {
+        if self.changes.is_empty() {
+            return "No changes were needed for this job description.\n".to_string();
+        }
+
+        let mut summary = format!("{} change(s) made:\n", self.changes.len());
+        for change in &self.changes {
+            summary.push_str(&format!("- {}\n", change));
+        }
+        summary
+    }
+}
+
+/// Produces a copy of a resume focused on one job description. Entries are
+/// only reordered, shortened or hidden; nothing is added, so the tailored
+/// resume never claims skills the original doesn't.
+pub struct ResumeTailor {
+    optimizer: ResumeOptimizer,
+    options: TailoringOptions,
+}
+
+impl Default for ResumeTailor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResumeTailor {
+    pub fn new() -> Self {
+        Self::with_options(TailoringOptions::default())
+    }
+
+    pub fn with_options(options: TailoringOptions) -> Self {
+        Self {
+            optimizer: ResumeOptimizer::new(),
+            options,
+        }
+    }
+
+    pub fn tailor(&self, resume: &Resume, job_description: &str) -> TailoringResult {
+        self.tailor_as_of(resume, job_description, Utc::now().date_naive())
+    }
+
+    pub fn tailor_as_of(
+        &self,
+        resume: &Resume,
+        job_description: &str,
+        today: NaiveDate,
+    ) -> TailoringResult {
+        let scorer = RelevanceScorer::new(self.optimizer.extract_keywords(job_description));
+        let mut tailored = resume.clone();
+        let mut changes = Vec::new();
+
+        self.tailor_skills(&mut tailored, &scorer, &mut changes);
+        self.tailor_experiences(&mut tailored, &scorer, today, &mut changes);
+        self.tailor_projects(&mut tailored, &scorer, &mut changes);
+
+        TailoringResult {
+            resume: tailored,
+            changes,
+        }
+    }
+
+    fn tailor_skills(
+        &self,
+        resume: &mut Resume,
+        scorer: &RelevanceScorer,
+        changes: &mut Vec<TailoringChange>,
+    ) {
+        let skills = &mut resume.skills;
+        let categories = [
+            ("technical", &mut skills.technical),
+            ("soft", &mut skills.soft),
+            ("tools", &mut skills.tools),
+            ("languages", &mut skills.languages),
+            ("other", &mut skills.other),
+        ];
+
+        for (category, list) in categories {
+            if !sort_by_relevance(list, |skill| scorer.score(&skill.name)) {
+                continue;
+            }
+            let leading: Vec<&str> = list
+                .iter()
+                .take_while(|skill| scorer.score(&skill.name) > 0)
+                .take(3)
+                .map(|skill| skill.name.as_str())
+                .collect();
+            changes.push(TailoringChange {
+                kind: ChangeKind::Reordered,
+                section: "Skills".to_string(),
+                description: format!(
+                    "Moved {} to the front of {} skills",
+                    leading.join(", "),
+                    category
+                ),
+            });
+        }
+    }
+
+    fn tailor_experiences(
+        &self,
+        resume: &mut Resume,
+        scorer: &RelevanceScorer,
+        today: NaiveDate,
+        changes: &mut Vec<TailoringChange>,
+    ) {
+        let scores: Vec<usize> = resume
+            .experiences
+            .iter()
+            .map(|exp| scorer.score(&experience_text(exp)))
+            .collect();
+        let max_score = scores.iter().copied().max().unwrap_or(0);
+        let cutoff_days = i64::from(self.options.older_than_years) * 365;
+
+        let mut kept = Vec::new();
+        for (mut exp, score) in resume.experiences.drain(..).zip(scores) {
+            let is_older = !exp.current
+                && exp
+                    .end_date
+                    .is_some_and(|end| (today - end).num_days() > cutoff_days);
+            let role = format!("{} at {}", exp.title, exp.company);
+
+            if is_older && score == 0 && max_score > 0 {
+                changes.push(TailoringChange {
+                    kind: ChangeKind::Hidden,
+                    section: "Experience".to_string(),
+                    description: format!("Hid {}, an older role unrelated to the job", role),
+                });
+                continue;
+            }
+
+            let order = relevance_order(&exp.achievements, |achievement| scorer.score(achievement));
+            let reordered = order
+                .iter()
+                .enumerate()
+                .any(|(position, index)| position != *index);
+            exp.retain_achievements(&order);
+
+            if is_older
+                && score * 4 < max_score
+                && exp.achievements.len() > self.options.collapsed_achievements
+            {
+                let kept: Vec<usize> = (0..self.options.collapsed_achievements).collect();
+                exp.retain_achievements(&kept);
+                changes.push(TailoringChange {
+                    kind: ChangeKind::Collapsed,
+                    section: "Experience".to_string(),
+                    description: format!(
+                        "Collapsed {} to its {} most relevant achievement(s)",
+                        role, self.options.collapsed_achievements
+                    ),
+                });
+            } else if reordered {
+                changes.push(TailoringChange {
+                    kind: ChangeKind::Reordered,
+                    section: "Experience".to_string(),
+                    description: format!("Put the most relevant achievements first for {}", role),
+                });
+            }
+            kept.push(exp);
+        }
+        resume.experiences = kept;
+    }
+
+    fn tailor_projects(
+        &self,
+        resume: &mut Resume,
+        scorer: &RelevanceScorer,
+        changes: &mut Vec<TailoringChange>,
+    ) {
+        let relevance = |project: &Project| {
+            scorer.score(&format!(
+                "{} {} {} {}",
+                project.name,
+                project.description,
+                project.technologies.join(" "),
+                project.highlights.join(" ")
+            ))
+        };
+        // Nothing to rank by, so the original selection stands
+        if resume
+            .projects
+            .iter()
+            .all(|project| relevance(project) == 0)
+        {
+            return;
+        }
+        let reordered = sort_by_relevance(&mut resume.projects, relevance);
+
+        if resume.projects.len() > self.options.max_projects {
+            for project in resume.projects.drain(self.options.max_projects..) {
+                changes.push(TailoringChange {
+                    kind: ChangeKind::Hidden,
+                    section: "Projects".to_string(),
+                    description: format!(
+                        "Hid {}, which is less relevant than the others",
+                        project.name
+                    ),
+                });
+            }
+        }
+
+        if reordered {
+            let order: Vec<&str> = resume.projects.iter().map(|p| p.name.as_str()).collect();
+            changes.push(TailoringChange {
+                kind: ChangeKind::Reordered,
+                section: "Projects".to_string(),
+                description: format!("Ordered projects by relevance: {}", order.join(", ")),
+            });
+        }
+    }
+}
+
+/// Scores text by the importance of the job keywords it contains.
+pub(crate) struct RelevanceScorer {
+    keywords: HashMap<String, usize>,
+    word_re: Regex,
+}
+
+impl RelevanceScorer {
+    fn new(job_keywords: HashMap<String, usize>) -> Self {
+        let keywords = job_keywords
+            .into_iter()
+            .map(|(keyword, importance)| (keyword.to_lowercase(), importance))
+            .filter(|(keyword, _)| !STOP_WORDS.contains(&keyword.as_str()))
+            .collect();
+
+        Self {
+            keywords,
+            word_re: Regex::new(r"\b[a-zA-Z0-9][\w-]*\b").unwrap(),
+        }
+    }
+
+    pub(crate) fn for_job(job_description: &str) -> Self {
+        Self::new(ResumeOptimizer::new().extract_keywords(job_description))
+    }
+
+    pub(crate) fn score(&self, text: &str) -> usize {
+        let text = text.to_lowercase();
+        let words: HashSet<&str> = self.word_re.find_iter(&text).map(|m| m.as_str()).collect();
+
+        self.keywords
+            .iter()
+            .filter(|(keyword, _)| {
+                if keyword.contains(' ') {
+                    text.contains(keyword.as_str())
+                } else {
+                    words.contains(keyword.as_str())
+                }
+            })
+            .map(|(_, importance)| importance)
+            .sum()
+    }
+}
+
+fn experience_text(exp: &Experience) -> String {
+    format!(
+        "{} {} {} {}",
+        exp.title,
+        exp.description,
+        exp.achievements.join(" "),
+        exp.technologies.join(" ")
+    )
+}
+
+/// Indexes of `items` from most to least relevant; ties keep their
+/// original order.
+fn relevance_order<T>(items: &[T], score: impl Fn(&T) -> usize) -> Vec<usize> {
+    let mut scored: Vec<(usize, usize)> = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| (score(item), index))
+        .collect();
+    scored.sort_by_key(|(score, index)| (Reverse(*score), *index));
+    scored.into_iter().map(|(_, index)| index).collect()
+}
+
+/// Stable sort with the highest scores first. Returns whether the order changed.
+fn sort_by_relevance<T>(items: &mut Vec<T>, score: impl Fn(&T) -> usize) -> bool {
+    let order = relevance_order(items, score);
+    let changed = order
+        .iter()
+        .enumerate()
+        .any(|(position, index)| position != *index);
+    let mut slots: Vec<Option<T>> = items.drain(..).map(Some).collect();
+    items.extend(order.iter().filter_map(|index| slots[*index].take()));
+    changed
+}
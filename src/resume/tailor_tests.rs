@@ -0,0 +1,207 @@
+use crate::resume::model::{Experience, Profile, Project, Resume};
+use crate::resume::tailor::*;
+use crate::resume::test_support::{date, empty_resume, experience, skills, strings};
+use pretty_assertions::assert_eq;
+use std::collections::{HashMap, HashSet};
+
+const JOB: &str = "Backend Engineer. We build Rust services on Kubernetes and Postgres.";
+
+fn job(title: &str, end_year: Option<i32>, achievements: &[&str]) -> Experience {
+    Experience {
+        end_date: end_year.and_then(|year| date(year, 12)),
+        current: end_year.is_none(),
+        achievements: strings(achievements),
+        ..experience(title, "Company")
+    }
+}
+
+fn project(name: &str, technologies: &[&str]) -> Project {
+    Project {
+        name: name.to_string(),
+        description: "".to_string(),
+        url: None,
+        github: None,
+        technologies: strings(technologies),
+        start_date: None,
+        end_date: None,
+        highlights: vec![],
+        tags: vec![],
+        translations: HashMap::new(),
+    }
+}
+
+fn create_test_resume() -> Resume {
+    let mut resume = empty_resume();
+    resume.skills.technical = skills(&["Java", "Postgres", "Rust"]);
+    resume.experiences = vec![
+        job(
+            "Software Engineer",
+            None,
+            &["Organized team offsites", "Migrated services to Rust"],
+        ),
+        job(
+            "QA Analyst",
+            Some(2010),
+            &[
+                "Wrote Perl reports",
+                "Tuned Postgres queries",
+                "Maintained the CI server",
+            ],
+        ),
+        job("Barista", Some(2008), &["Made coffee"]),
+    ];
+    resume.experiences[0].description = "Built Rust services on Kubernetes".to_string();
+    resume.projects = vec![
+        project("Blog", &["Hugo"]),
+        project("Operator", &["Rust", "Kubernetes"]),
+        project("Game", &["C++"]),
+        project("Dashboard", &["Postgres"]),
+    ];
+    resume
+}
+
+#[test]
+fn test_skills_and_achievements_reordered() {
+    let result =
+        ResumeTailor::new().tailor_as_of(&create_test_resume(), JOB, date(2024, 1).unwrap());
+    let resume = result.resume;
+
+    let skills: Vec<&str> = resume
+        .skills
+        .technical
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(skills, vec!["Rust", "Postgres", "Java"]);
+    assert_eq!(
+        resume.experiences[0].achievements[0],
+        "Migrated services to Rust"
+    );
+}
+
+#[test]
+fn test_older_entries_collapsed_or_hidden() {
+    let result =
+        ResumeTailor::new().tailor_as_of(&create_test_resume(), JOB, date(2024, 1).unwrap());
+
+    let titles: Vec<&str> = result
+        .resume
+        .experiences
+        .iter()
+        .map(|e| e.title.as_str())
+        .collect();
+    assert_eq!(titles, vec!["Software Engineer", "QA Analyst"]);
+    assert_eq!(
+        result.resume.experiences[1].achievements,
+        vec!["Tuned Postgres queries".to_string()]
+    );
+    assert!(result.changes.contains(&TailoringChange {
+        kind: ChangeKind::Hidden,
+        section: "Experience".to_string(),
+        description: "Hid Barista at Company, an older role unrelated to the job".to_string(),
+    }));
+    assert!(result
+        .changes
+        .iter()
+        .any(|c| c.kind == ChangeKind::Collapsed));
+}
+
+#[test]
+fn test_recent_entries_are_kept() {
+    let result =
+        ResumeTailor::new().tailor_as_of(&create_test_resume(), JOB, date(2012, 1).unwrap());
+
+    assert_eq!(result.resume.experiences.len(), 3);
+    assert_eq!(result.resume.experiences[1].achievements.len(), 3);
+}
+
+#[test]
+fn test_most_relevant_projects_kept() {
+    let tailor = ResumeTailor::with_options(TailoringOptions {
+        max_projects: 2,
+        ..TailoringOptions::default()
+    });
+    let result = tailor.tailor_as_of(&create_test_resume(), JOB, date(2024, 1).unwrap());
+
+    let projects: Vec<&str> = result
+        .resume
+        .projects
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect();
+    assert_eq!(projects, vec!["Operator", "Dashboard"]);
+    assert_eq!(
+        result
+            .changes
+            .iter()
+            .filter(|c| c.section == "Projects" && c.kind == ChangeKind::Hidden)
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn test_projects_kept_when_none_relevant() {
+    let tailor = ResumeTailor::with_options(TailoringOptions {
+        max_projects: 2,
+        ..TailoringOptions::default()
+    });
+    let mut resume = create_test_resume();
+    resume.projects = vec![
+        project("Sourdough Tracker", &["Swift"]),
+        project("Bird Counter", &["Kotlin"]),
+        project("Chess Clock", &["Elm"]),
+    ];
+
+    let result = tailor.tailor_as_of(&resume, JOB, date(2024, 1).unwrap());
+
+    assert_eq!(result.resume.projects, resume.projects);
+    assert!(result.changes.iter().all(|c| c.section != "Projects"));
+}
+
+#[test]
+fn test_never_adds_skills() {
+    let original = create_test_resume();
+    let result = ResumeTailor::new().tailor_as_of(
+        &original,
+        "Go, Terraform, Kafka and Rust required",
+        date(2024, 1).unwrap(),
+    );
+
+    let before: HashSet<&str> = original
+        .skills
+        .technical
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    let after: HashSet<&str> = result
+        .resume
+        .skills
+        .technical
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(before, after);
+    assert!(!result.resume.contains_keyword("Kafka"));
+}
+
+#[test]
+fn test_summary() {
+    let result =
+        ResumeTailor::new().tailor_as_of(&create_test_resume(), JOB, date(2024, 1).unwrap());
+    let summary = result.summary();
+
+    assert!(summary.starts_with(&format!("{} change(s) made:", result.changes.len())));
+    assert!(summary
+        .contains("- [Reordered] Skills: Moved Rust, Postgres to the front of technical skills"));
+
+    let unchanged = ResumeTailor::new().tailor_as_of(
+        &Resume::new(Profile::default()),
+        JOB,
+        date(2024, 1).unwrap(),
+    );
+    assert_eq!(
+        unchanged.summary(),
+        "No changes were needed for this job description.\n"
+    );
+}
@@ -0,0 +1,155 @@
+//! Fixtures shared by the `*_tests.rs` modules. Tests start from these and
+//! override the fields they care about with struct update syntax.
+
+use crate::resume::model::{Education, Experience, Location, Profile, Resume, Skill};
+use chrono::NaiveDate;
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Document, Object, Stream};
+use std::collections::HashMap;
+
+/// The first day of the given month.
+pub fn date(year: i32, month: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month, 1)
+}
+
+pub fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|item| item.to_string()).collect()
+}
+
+pub fn skill(name: &str) -> Skill {
+    Skill {
+        name: name.to_string(),
+        level: None,
+        years: None,
+        tags: vec![],
+    }
+}
+
+pub fn skills(names: &[&str]) -> Vec<Skill> {
+    names.iter().map(|name| skill(name)).collect()
+}
+
+/// A past role without dates, bullets or technologies.
+pub fn experience(title: &str, company: &str) -> Experience {
+    Experience {
+        company: company.to_string(),
+        title: title.to_string(),
+        location: None,
+        start_date: None,
+        end_date: None,
+        current: false,
+        description: "".to_string(),
+        achievements: vec![],
+        technologies: vec![],
+        tags: vec![],
+        achievement_tags: HashMap::new(),
+        translations: HashMap::new(),
+    }
+}
+
+/// A degree without dates, GPA or courses.
+pub fn education(institution: &str, degree: &str, field_of_study: &str) -> Education {
+    Education {
+        institution: institution.to_string(),
+        degree: degree.to_string(),
+        field_of_study: field_of_study.to_string(),
+        location: None,
+        start_date: None,
+        end_date: None,
+        current: false,
+        gpa: None,
+        gpa_scale: None,
+        courses: vec![],
+        achievements: vec![],
+        description: "".to_string(),
+        translations: HashMap::new(),
+    }
+}
+
+pub fn profile() -> Profile {
+    Profile {
+        name: "John Doe".to_string(),
+        email: "john@example.com".to_string(),
+        ..Profile::default()
+    }
+}
+
+/// A resume with contact details and nothing else.
+pub fn empty_resume() -> Resume {
+    Resume::new(profile())
+}
+
+/// A resume with one current role, a degree and a couple of skills, enough
+/// for every section of the formatted output to show up.
+pub fn sample_resume() -> Resume {
+    let mut resume = Resume::new(Profile {
+        name: "Jane Smith".to_string(),
+        title: "Software Engineer".to_string(),
+        email: "jane@example.com".to_string(),
+        phone: "+1 555 123 4567".to_string(),
+        location: Location {
+            city: "Austin".to_string(),
+            country: "USA".to_string(),
+        },
+        summary: "Builds payment systems & mentors engineers.".to_string(),
+        ..Profile::default()
+    });
+    resume.experiences.push(Experience {
+        location: Some("Remote".to_string()),
+        start_date: date(2020, 3),
+        current: true,
+        description: "Owns the billing platform.".to_string(),
+        achievements: strings(&["Led the billing migration", "Cut latency by 40%"]),
+        technologies: strings(&["Rust", "Postgres"]),
+        ..experience("Senior Engineer", "Acme Corp")
+    });
+    resume.education.push(Education {
+        start_date: date(2012, 9),
+        end_date: date(2016, 6),
+        ..education("MIT", "BSc", "Computer Science")
+    });
+    resume.skills.technical = skills(&["Rust", "SQL"]);
+    resume
+}
+
+/// A one-page letter-size PDF whose content stream is `operations`, with
+/// Helvetica available as `F1`.
+pub fn pdf_with_operations(operations: Vec<Operation>) -> Vec<u8> {
+    let mut document = Document::with_version("1.5");
+    let pages_id = document.new_object_id();
+    let font_id = document.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Helvetica",
+        "Encoding" => "WinAnsiEncoding",
+    });
+    let resources_id = document.add_object(dictionary! {
+        "Font" => dictionary! { "F1" => font_id },
+    });
+    let content = Content { operations };
+    let content_id = document.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+    let page_id = document.add_object(dictionary! {
+        "Type" => "Page",
+        "Parent" => pages_id,
+        "Contents" => content_id,
+    });
+    document.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => vec![page_id.into()],
+            "Count" => 1,
+            "Resources" => resources_id,
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+        }),
+    );
+    let catalog_id = document.add_object(dictionary! {
+        "Type" => "Catalog",
+        "Pages" => pages_id,
+    });
+    document.trailer.set("Root", catalog_id);
+
+    let mut bytes = Vec::new();
+    document.save_to(&mut bytes).unwrap();
+    bytes
+}
@@ -0,0 +1,238 @@
+use crate::resume::model::Resume;
+use regex::Regex;
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Seniority {
+    Intern,
+    Junior,
+    Mid,
+    Senior,
+    Lead,
+    Staff,
+    Principal,
+}
+
+impl Seniority {
+    fn from_token(token: &str) -> Option<Seniority> {
+        match token {
+            "intern" | "internship" => Some(Self::Intern),
+            "junior" | "jr" | "entry" | "graduate" | "associate" | "i" => Some(Self::Junior),
+            "mid" | "intermediate" | "ii" => Some(Self::Mid),
+            "senior" | "sr" | "iii" => Some(Self::Senior),
+            "lead" | "tech-lead" => Some(Self::Lead),
+            "staff" | "iv" => Some(Self::Staff),
+            "principal" | "distinguished" | "v" => Some(Self::Principal),
+            _ => None,
+        }
+    }
+
+    fn rank(&self) -> i32 {
+        *self as i32
+    }
+
+    /// The level as written in suggestions, e.g. "senior".
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Intern => "intern",
+            Self::Junior => "junior",
+            Self::Mid => "mid",
+            Self::Senior => "senior",
+            Self::Lead => "lead",
+            Self::Staff => "staff",
+            Self::Principal => "principal",
+        }
+    }
+}
+
+/// A job title reduced to its seniority and a set of canonical role words, so
+/// "Sr. Software Dev" and "Senior Software Engineer" compare as equal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedTitle {
+    pub seniority: Seniority,
+    pub words: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TitleAlignment {
+    pub target_title: String,
+    pub target_seniority: Seniority,
+    pub resume_title: String,
+    /// Similarity between the resume headline and the target, from 0.0 to 1.0.
+    pub similarity: f32,
+    /// The resume title (headline or past role) closest to the target.
+    pub closest_title: String,
+    pub closest_similarity: f32,
+    pub seniority_gap: i32,
+}
+
+const ROLE_NOUNS: [&str; 20] = [
+    "engineer",
+    "manager",
+    "analyst",
+    "designer",
+    "scientist",
+    "architect",
+    "lead",
+    "director",
+    "specialist",
+    "consultant",
+    "administrator",
+    "coordinator",
+    "officer",
+    "researcher",
+    "intern",
+    "head",
+    "technician",
+    "strategist",
+    "writer",
+    "owner",
+];
+
+const FILLER_WORDS: [&str; 8] = ["a", "an", "the", "of", "and", "for", "to", "-"];
+
+pub fn normalize_title(title: &str) -> NormalizedTitle {
+    let lower = title.to_lowercase();
+    let lower = lower
+        .replace("front-end", "frontend")
+        .replace("front end", "frontend")
+        .replace("back-end", "backend")
+        .replace("back end", "backend")
+        .replace("full-stack", "fullstack")
+        .replace("full stack", "fullstack")
+        .replace("tech lead", "tech-lead");
+
+    let token_re = Regex::new(r"[a-z0-9+#]+(?:-[a-z]+)?").unwrap();
+    let mut seniority = None;
+    let mut words = BTreeSet::new();
+
+    for token in token_re.find_iter(&lower) {
+        let token = token.as_str();
+        if let Some(level) = Seniority::from_token(token) {
+            seniority = seniority.max(Some(level));
+            if level != Seniority::Lead {
+                continue;
+            }
+        }
+        if FILLER_WORDS.contains(&token) {
+            continue;
+        }
+
+        let canonical: &[&str] = match token {
+            "developer" | "dev" | "programmer" | "eng" | "engineering" => &["engineer"],
+            "swe" => &["software", "engineer"],
+            "sde" => &["software", "engineer"],
+            "sw" => &["software"],
+            "mgr" => &["manager"],
+            "ml" => &["machine", "learning"],
+            "tech-lead" => &["lead"],
+            _ => &[],
+        };
+        if canonical.is_empty() {
+            words.insert(token.to_string());
+        } else {
+            words.extend(canonical.iter().map(|word| word.to_string()));
+        }
+    }
+
+    NormalizedTitle {
+        seniority: seniority.unwrap_or(Seniority::Mid),
+        words,
+    }
+}
+
+/// Dice similarity of the role words, reduced by 10% per seniority level apart.
+pub fn title_similarity(a: &str, b: &str) -> f32 {
+    let a = normalize_title(a);
+    let b = normalize_title(b);
+    if a.words.is_empty() || b.words.is_empty() {
+        return 0.0;
+    }
+
+    let common = a.words.intersection(&b.words).count();
+    let dice = 2.0 * common as f32 / (a.words.len() + b.words.len()) as f32;
+    let gap = (a.seniority.rank() - b.seniority.rank()).abs().min(3);
+
+    dice * (1.0 - 0.1 * gap as f32)
+}
+
+pub fn extract_job_title(job_description: &str) -> Option<String> {
+    let labeled_re =
+        Regex::new(r"(?im)^\s*(?:job\s+title|position|role|title)\s*[:\-–]\s*(.+?)\s*$").unwrap();
+    if let Some(caps) = labeled_re.captures(job_description) {
+        return Some(clean_title(&caps[1]));
+    }
+
+    let sentence_re = Regex::new(
+        r"(?i)\b(?:looking for|hiring|seeking|searching for|in need of|join us as)\s+(?:an?\s+|our\s+(?:next\s+)?)?(.+?)(?:\s+(?:to|who|with|that|for|in|at|on)\b|[.,;:!()\n]|$)",
+    )
+    .unwrap();
+    for caps in sentence_re.captures_iter(job_description) {
+        let candidate = clean_title(&caps[1]);
+        if is_title_like(&candidate) {
+            return Some(candidate);
+        }
+    }
+
+    let first_line = job_description
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())?;
+    let first_sentence = first_line
+        .split(['.', '!', '|', '('])
+        .next()
+        .unwrap_or(first_line);
+    let candidate = clean_title(first_sentence);
+    is_title_like(&candidate).then_some(candidate)
+}
+
+fn clean_title(text: &str) -> String {
+    text.trim()
+        .trim_matches(|c: char| !c.is_alphanumeric() && c != '+' && c != '#')
+        .to_string()
+}
+
+fn is_title_like(candidate: &str) -> bool {
+    let word_count = candidate.split_whitespace().count();
+    let normalized = normalize_title(candidate);
+    (1..=6).contains(&word_count)
+        && normalized
+            .words
+            .iter()
+            .any(|word| ROLE_NOUNS.contains(&word.as_str()))
+}
+
+pub fn analyze_title_alignment(resume: &Resume, job_description: &str) -> Option<TitleAlignment> {
+    let target_title = extract_job_title(job_description)?;
+    let target = normalize_title(&target_title);
+
+    let similarity = title_similarity(&resume.profile.title, &target_title);
+
+    let (closest_title, closest_similarity) = std::iter::once(&resume.profile.title)
+        .chain(resume.experiences.iter().map(|exp| &exp.title))
+        .filter(|title| !title.is_empty())
+        .map(|title| (title.clone(), title_similarity(title, &target_title)))
+        .fold((String::new(), 0.0), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        });
+
+    let seniority_gap = if resume.profile.title.is_empty() {
+        0
+    } else {
+        normalize_title(&resume.profile.title).seniority.rank() - target.seniority.rank()
+    };
+
+    Some(TitleAlignment {
+        target_seniority: target.seniority,
+        resume_title: resume.profile.title.clone(),
+        similarity,
+        closest_title,
+        closest_similarity,
+        seniority_gap,
+        target_title,
+    })
+}
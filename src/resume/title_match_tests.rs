@@ -0,0 +1,143 @@
+use crate::resume::model::{Profile, Resume};
+use crate::resume::optimizer::ResumeOptimizer;
+use crate::resume::test_support::{experience, profile};
+use crate::resume::title_match::*;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+fn create_test_resume(headline: &str, past_titles: &[&str]) -> Resume {
+    let mut resume = Resume::new(Profile {
+        title: headline.to_string(),
+        ..profile()
+    });
+    for title in past_titles {
+        resume.experiences.push(experience(title, "Company"));
+    }
+    resume
+}
+
+#[rstest]
+#[case("Sr. Software Engineer", Seniority::Senior)]
+#[case("Senior Backend Developer", Seniority::Senior)]
+#[case("Software Engineer II", Seniority::Mid)]
+#[case("Staff Engineer", Seniority::Staff)]
+#[case("Lead Data Scientist", Seniority::Lead)]
+#[case("Jr. Web Developer", Seniority::Junior)]
+#[case("Product Manager", Seniority::Mid)]
+fn test_seniority_normalization(#[case] title: &str, #[case] expected: Seniority) {
+    assert_eq!(normalize_title(title).seniority, expected);
+}
+
+#[test]
+fn test_role_word_normalization() {
+    assert_eq!(
+        normalize_title("Sr. Software Dev").words,
+        normalize_title("Senior Software Engineer").words
+    );
+    assert_eq!(
+        normalize_title("Front-End Developer").words,
+        normalize_title("Frontend Engineer").words
+    );
+}
+
+#[test]
+fn test_title_similarity() {
+    assert_eq!(
+        title_similarity("Senior Software Engineer", "Sr. Software Developer"),
+        1.0
+    );
+    assert!(title_similarity("Software Engineer", "Senior Software Engineer") < 1.0);
+    assert!(title_similarity("Software Engineer", "Senior Software Engineer") > 0.8);
+    assert!(title_similarity("Marketing Manager", "Backend Engineer") < 0.1);
+    assert_eq!(title_similarity("", "Backend Engineer"), 0.0);
+}
+
+#[rstest]
+#[case(
+    "Job Title: Senior Rust Engineer\nWe build databases.",
+    "Senior Rust Engineer"
+)]
+#[case(
+    "Acme Corp is looking for a Staff Platform Engineer to join our team.",
+    "Staff Platform Engineer"
+)]
+#[case("Data Analyst (Remote)\nAbout us: ...", "Data Analyst")]
+#[case(
+    "We're hiring an Engineering Manager who loves mentoring.",
+    "Engineering Manager"
+)]
+fn test_extract_job_title(#[case] job: &str, #[case] expected: &str) {
+    assert_eq!(extract_job_title(job).as_deref(), Some(expected));
+}
+
+#[test]
+fn test_extract_job_title_without_title() {
+    assert_eq!(
+        extract_job_title("We value curiosity and kindness above all."),
+        None
+    );
+}
+
+#[test]
+fn test_alignment_picks_closest_past_role() {
+    let resume = create_test_resume("Full Stack Developer", &["Data Engineer", "Web Developer"]);
+    let alignment = analyze_title_alignment(&resume, "Job Title: Senior Data Engineer").unwrap();
+
+    assert_eq!(alignment.target_title, "Senior Data Engineer");
+    assert_eq!(alignment.target_seniority, Seniority::Senior);
+    assert_eq!(alignment.closest_title, "Data Engineer");
+    assert!(alignment.closest_similarity > alignment.similarity);
+    assert_eq!(alignment.seniority_gap, -1);
+}
+
+#[test]
+fn test_optimizer_suggests_headline_change() {
+    let optimizer = ResumeOptimizer::new();
+    let resume = create_test_resume("Marketing Specialist", &["Data Engineer"]);
+
+    let result = optimizer.optimize(&resume, "Job Title: Data Engineer\nSQL and Python.");
+
+    assert!(result.title_alignment.is_some());
+    assert!(result.score_breakdown.contains_key("title"));
+    assert!(result.suggestions.iter().any(|s| s.contains(
+        "Adjust your headline \"Marketing Specialist\" to match the job title \"Data Engineer\"; your role as \"Data Engineer\" is a closer match"
+    )));
+}
+
+#[test]
+fn test_optimizer_no_headline_suggestion_for_aligned_title() {
+    let optimizer = ResumeOptimizer::new();
+    let resume = create_test_resume("Sr. Data Engineer", &[]);
+
+    let result = optimizer.optimize(&resume, "Job Title: Senior Data Engineer");
+
+    assert_eq!(result.score_breakdown.get("title"), Some(&100));
+    assert!(result.suggestions.iter().all(|s| !s.contains("headline")));
+}
+
+#[test]
+fn test_optimizer_names_seniority_gap() {
+    let optimizer = ResumeOptimizer::new();
+    let resume = create_test_resume("Junior Data Engineer", &[]);
+
+    let result = optimizer.optimize(&resume, "Job Title: Staff Data Engineer");
+
+    assert!(result.suggestions.contains(
+        &"The job is at staff level, which is more senior than your headline suggests".to_string()
+    ));
+}
+
+#[test]
+fn test_title_mismatch_lowers_score() {
+    let optimizer = ResumeOptimizer::new();
+    let job = "Job Title: Data Engineer\nSQL and Python.";
+
+    let aligned = optimizer.optimize(&create_test_resume("Data Engineer", &[]), job);
+    let mismatched = optimizer.optimize(&create_test_resume("Marketing Specialist", &[]), job);
+
+    assert_eq!(
+        aligned.score_breakdown.get("keywords"),
+        mismatched.score_breakdown.get("keywords")
+    );
+    assert!(mismatched.score < aligned.score);
+}
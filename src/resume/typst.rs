@@ -0,0 +1,148 @@
+use crate::resume::page_fit::PageMetrics;
+
+/// A built-in Typst template: page, text and heading settings placed before
+/// the resume content.
+///
+/// Every template defines the same functions, so the document body is the
+/// same whichever template is chosen:
+/// - `#entry[title][organization][dates]` starts an entry
+/// - `#detail[text]` adds a secondary line, e.g. a location
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TypstTemplate {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Page margin used unless the `margin` custom option is set.
+    pub margin: &'static str,
+    /// Text capacity of a page with the default margin, for length estimates.
+    pub page: PageMetrics,
+    pub preamble: &'static str,
+}
+
+const TEMPLATES: [TypstTemplate; 3] = [
+    TypstTemplate {
+        name: "modern",
+        description: "Sans-serif type with ruled, colored section headings",
+        margin: "0.75in",
+        page: PageMetrics {
+            lines_per_page: 52.0,
+            chars_per_line: 98,
+        },
+        preamble: r#"#set text(font: ("Inter", "Helvetica", "Arial", "Libertinus Serif"), size: 10.5pt)
+#set par(justify: false)
+#let accent = rgb(31, 78, 121)
+#show heading.where(level: 1): it => align(center, text(size: 22pt, weight: "bold", it.body))
+#show heading.where(level: 2): it => block(above: 1.2em, below: 0.6em, width: 100%)[
+  #text(fill: accent, size: 12pt, weight: "bold", it.body)
+  #v(-0.5em)
+  #line(length: 100%, stroke: 0.6pt + accent)
+]
+#let entry(title, organization, dates) = block(above: 0.8em, below: 0.4em)[
+  *#title*#if organization != [] [ | #organization] #h(1fr) #text(fill: gray, dates)
+]
+#let detail(body) = block(above: 0.3em, below: 0.3em, text(size: 9.5pt, body))
+"#,
+    },
+    TypstTemplate {
+        name: "classic",
+        description: "Serif type with plain section headings",
+        margin: "1in",
+        page: PageMetrics {
+            lines_per_page: 47.0,
+            chars_per_line: 88,
+        },
+        preamble: r#"#set text(font: ("Libertinus Serif", "Linux Libertine"), size: 11pt)
+#set par(justify: true)
+#show heading.where(level: 1): it => align(center, text(size: 20pt, weight: "bold", it.body))
+#show heading.where(level: 2): it => block(above: 1.2em, below: 0.6em, text(size: 13pt, weight: "bold", it.body))
+#let entry(title, organization, dates) = block(above: 0.8em, below: 0.4em)[
+  *#title*#if organization != [] [, #organization] #h(1fr) #emph(dates)
+]
+#let detail(body) = block(above: 0.3em, below: 0.3em, emph(body))
+"#,
+    },
+    TypstTemplate {
+        name: "minimal",
+        description: "Compact layout that fits more on a page",
+        margin: "0.6in",
+        page: PageMetrics {
+            lines_per_page: 60.0,
+            chars_per_line: 110,
+        },
+        preamble: r#"#set text(font: ("Libertinus Serif", "Linux Libertine"), size: 10pt)
+#set par(justify: false)
+#show heading.where(level: 1): it => text(size: 16pt, weight: "bold", it.body)
+#show heading.where(level: 2): it => block(above: 0.9em, below: 0.4em, text(size: 10pt, weight: "bold", upper(it.body)))
+#let entry(title, organization, dates) = block(above: 0.5em, below: 0.3em)[
+  *#title*#if organization != [] [, #organization] #h(1fr) #dates
+]
+#let detail(body) = block(above: 0.2em, below: 0.2em, body)
+"#,
+    },
+];
+
+impl TypstTemplate {
+    pub fn builtin() -> &'static [TypstTemplate] {
+        &TEMPLATES
+    }
+
+    pub fn find(name: &str) -> Option<&'static TypstTemplate> {
+        TEMPLATES
+            .iter()
+            .find(|template| template.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Document metadata, page setup and the template's preamble. `lang`
+    /// is an ISO 639-1 code used for hyphenation and quotes.
+    pub fn header(
+        &self,
+        title: &str,
+        author: &str,
+        lang: &str,
+        paper: &str,
+        margin: &str,
+    ) -> String {
+        format!(
+            "#set document(title: \"{}\", author: \"{}\")\n#set page(paper: \"{}\", margin: {})\n#set text(lang: \"{}\")\n{}\n",
+            escape_string(title),
+            escape_string(author),
+            escape_string(paper),
+            margin,
+            escape_string(lang),
+            self.preamble
+        )
+    }
+}
+
+/// Escapes text so Typst prints it literally as markup.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for (index, c) in text.chars().enumerate() {
+        let special = matches!(
+            c,
+            '\\' | '#' | '*' | '_' | '`' | '$' | '<' | '>' | '@' | '[' | ']' | '~' | '/'
+        );
+        // List, enumeration and heading markers only count at the start
+        let leading = index == 0 && matches!(c, '-' | '+' | '=');
+        if special || leading {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Escapes text for a Typst string literal.
+pub fn escape_string(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Whether `value` is a Typst length in an absolute or font-relative unit,
+/// e.g. "0.75in" or "2cm".
+pub fn is_length(value: &str) -> bool {
+    ["pt", "mm", "cm", "in", "em"].iter().any(|unit| {
+        value
+            .strip_suffix(unit)
+            .and_then(|number| number.parse::<f64>().ok())
+            .is_some_and(|number| number.is_finite() && number >= 0.0)
+    })
+}
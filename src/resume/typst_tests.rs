@@ -0,0 +1,206 @@
+use crate::resume::formatter::{FormattingOptions, OutputFormat, ResumeFormatter};
+use crate::resume::model::{Experience, Profile, Publication, Resume};
+use crate::resume::test_support::{date, experience, skills, strings};
+use crate::resume::typst::{escape, escape_string, is_length, TypstTemplate};
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use std::collections::HashMap;
+
+fn create_test_resume() -> Resume {
+    let mut resume = Resume::new(Profile {
+        name: "Jane \"JS\" Smith".to_string(),
+        title: "Backend Engineer".to_string(),
+        email: "jane@example.com".to_string(),
+        github: "https://github.com/jane".to_string(),
+        summary: "Ships *fast* APIs in C# and Rust.\n\nMentors engineers.".to_string(),
+        ..Profile::default()
+    });
+    resume.experiences.push(Experience {
+        location: Some("Berlin".to_string()),
+        start_date: date(2020, 3),
+        current: true,
+        achievements: strings(&["- Cut costs by $2M", "Wrote <docs> for snake_case APIs"]),
+        technologies: strings(&["Rust"]),
+        ..experience("Senior Engineer", "Acme [EU]")
+    });
+    resume.skills.technical = skills(&["C#"]);
+    resume.publications.push(Publication {
+        title: "Consensus at Scale".to_string(),
+        publisher: "ACM".to_string(),
+        published_date: date(2022, 7),
+        authors: vec![],
+        url: None,
+        description: "".to_string(),
+        translations: HashMap::new(),
+    });
+    resume
+}
+
+fn render(options: FormattingOptions) -> Result<String, String> {
+    ResumeFormatter::with_options(options)
+        .format(&create_test_resume(), OutputFormat::Typst)
+        .map(|result| result.content)
+}
+
+fn with_custom(key: &str, value: &str) -> FormattingOptions {
+    let mut options = FormattingOptions::default();
+    options
+        .custom_options
+        .insert(key.to_string(), value.to_string());
+    options
+}
+
+#[rstest]
+#[case("#1 @home", r"\#1 \@home")]
+#[case("*bold* _em_", r"\*bold\* \_em\_")]
+#[case("$5 <tag>", r"\$5 \<tag\>")]
+#[case("[x] `code`", r"\[x\] \`code\`")]
+#[case("a // b ~ c", r"a \/\/ b \~ c")]
+#[case(r"C:\tools", r"C:\\tools")]
+#[case("- item", r"\- item")]
+#[case("= Heading", r"\= Heading")]
+#[case("2020 - 2021", "2020 - 2021")]
+fn test_escape(#[case] text: &str, #[case] expected: &str) {
+    assert_eq!(escape(text), expected);
+}
+
+#[test]
+fn test_escape_string() {
+    assert_eq!(escape_string(r#"say "hi" \o/"#), r#"say \"hi\" \\o/"#);
+}
+
+#[rstest]
+#[case("0.75in", true)]
+#[case("2cm", true)]
+#[case("12pt", true)]
+#[case("1.5em", true)]
+#[case("2", false)]
+#[case("-1in", false)]
+#[case("2cm); #panic(", false)]
+fn test_is_length(#[case] value: &str, #[case] expected: bool) {
+    assert_eq!(is_length(value), expected);
+}
+
+#[test]
+fn test_document_structure() {
+    let content = render(FormattingOptions::default()).unwrap();
+
+    assert!(content.starts_with(
+        "#set document(title: \"Jane \\\"JS\\\" Smith - Resume\", author: \"Jane \\\"JS\\\" Smith\")\n\
+         #set page(paper: \"us-letter\", margin: 0.75in)\n"
+    ));
+    assert!(content.contains("#let entry(title, organization, dates)"));
+    assert!(content.contains("\n= Jane \"JS\" Smith\n"));
+    assert!(content.contains(
+        "#align(center)[Backend Engineer \\\n  jane\\@example.com | #link(\"https://github.com/jane\")[https:\\/\\/github.com\\/jane]]\n"
+    ));
+
+    let headings: Vec<&str> = content
+        .lines()
+        .filter_map(|line| line.strip_prefix("== "))
+        .collect();
+    assert_eq!(
+        headings,
+        vec![
+            "Professional Summary",
+            "Work Experience",
+            "Skills",
+            "Publications"
+        ]
+    );
+}
+
+#[test]
+fn test_content_is_escaped() {
+    let content = render(FormattingOptions::default()).unwrap();
+
+    assert!(content.contains("Ships \\*fast\\* APIs in C\\# and Rust.\n\nMentors engineers.\n\n"));
+    assert!(content.contains("#entry[Senior Engineer][Acme \\[EU\\]][March 2020 - Present]\n"));
+    assert!(content.contains("#detail[Berlin]\n"));
+    assert!(content.contains("- \\- Cut costs by \\$2M\n"));
+    assert!(content.contains("- Wrote \\<docs\\> for snake\\_case APIs\n"));
+    assert!(content.contains("*Technical Skills:* C\\#\n"));
+    assert!(content.contains("#entry[Consensus at Scale][ACM][July 2022]\n"));
+}
+
+#[rstest]
+#[case("modern", "#let accent = rgb(31, 78, 121)", "0.75in")]
+#[case("classic", "#set par(justify: true)", "1in")]
+#[case("minimal", "upper(it.body)", "0.6in")]
+fn test_template_selection(#[case] template: &str, #[case] marker: &str, #[case] margin: &str) {
+    let content = render(FormattingOptions {
+        template: template.to_string(),
+        ..FormattingOptions::default()
+    })
+    .unwrap();
+
+    assert!(content.contains(marker));
+    assert!(content.contains(&format!("margin: {})", margin)));
+    assert!(content.contains(TypstTemplate::find(template).unwrap().preamble));
+}
+
+#[test]
+fn test_page_options() {
+    let mut options = with_custom("page_size", "a4");
+    options
+        .custom_options
+        .insert("margin".to_string(), "2cm".to_string());
+
+    let content = render(options).unwrap();
+    assert!(content.contains("#set page(paper: \"a4\", margin: 2cm)\n"));
+}
+
+#[rstest]
+#[case("letter", "us-letter")]
+#[case("A5", "a5")]
+#[case("us-legal", "us-legal")]
+fn test_page_size_takes_typst_name(#[case] name: &str, #[case] paper: &str) {
+    let content = render(with_custom("page_size", name)).unwrap();
+    assert!(content.contains(&format!("#set page(paper: \"{}\",", paper)));
+}
+
+#[test]
+fn test_page_options_change_estimate() {
+    let pages = |options| {
+        ResumeFormatter::with_options(options)
+            .format(&create_test_resume(), OutputFormat::Typst)
+            .unwrap()
+            .pages
+            .unwrap()
+    };
+    let default = pages(FormattingOptions::default());
+
+    assert!(pages(with_custom("page_size", "a5")) > default);
+    assert!(pages(with_custom("margin", "2in")) > default);
+    assert!(pages(with_custom("margin", "0.5in")) < default);
+}
+
+#[rstest]
+#[case("page_size", "a4\")", "Invalid page size 'a4\")'")]
+#[case("page_size", "tabloid", "Invalid page size 'tabloid'")]
+#[case("margin", "wide", "Invalid page margin 'wide'")]
+fn test_invalid_page_options(#[case] key: &str, #[case] value: &str, #[case] expected: &str) {
+    assert_eq!(render(with_custom(key, value)).unwrap_err(), expected);
+}
+
+#[test]
+fn test_template_without_typst_equivalent() {
+    let mut formatter = ResumeFormatter::with_options(FormattingOptions {
+        template: "custom".to_string(),
+        ..FormattingOptions::default()
+    });
+    formatter
+        .load_template_from_file("custom", std::path::Path::new("src/templates/modern.md"))
+        .unwrap();
+
+    let result = formatter
+        .format(&create_test_resume(), OutputFormat::Typst)
+        .unwrap();
+    assert_eq!(result.format.extension(), "typ");
+    assert!(result
+        .content
+        .contains(TypstTemplate::find("classic").unwrap().preamble));
+    assert!(result
+        .warnings
+        .contains(&"No Typst template for 'custom', using 'classic'".to_string()));
+}